@@ -0,0 +1,536 @@
+//! Reading of the legacy Feather V1 format.
+//!
+//! Feather V1 predates the Arrow IPC file format (Feather V2). A file starts and ends with the
+//! `FEA1` magic and stores every column as a single contiguous array, described by a flatbuffers
+//! `CTable` written just before the trailing magic:
+//!
+//! ```text
+//! FEA1 | column data | CTable | CTable length (i32) | FEA1
+//! ```
+//!
+//! The `CTable` schema is small and fixed, so it is decoded here without generated code.
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::super::ARROW_MAGIC_V1;
+use super::OutOfSpecKind;
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, IntegerType, TimeUnit};
+use crate::offset::Offset;
+use crate::record_batch::RecordBatchT;
+use crate::types::NativeType;
+
+/// Buffers of a column are padded to this many bytes.
+const ALIGNMENT: usize = 8;
+
+// Values of the `Type` enum of the Feather V1 metadata.
+const BOOL: i8 = 0;
+const INT8: i8 = 1;
+const INT16: i8 = 2;
+const INT32: i8 = 3;
+const INT64: i8 = 4;
+const UINT8: i8 = 5;
+const UINT16: i8 = 6;
+const UINT32: i8 = 7;
+const UINT64: i8 = 8;
+const FLOAT: i8 = 9;
+const DOUBLE: i8 = 10;
+const UTF8: i8 = 11;
+const BINARY: i8 = 12;
+const LARGE_UTF8: i8 = 17;
+const LARGE_BINARY: i8 = 18;
+
+/// Metadata of a Feather V1 file.
+#[derive(Debug, Clone)]
+pub struct FeatherV1Metadata {
+    /// The schema of the file.
+    pub schema: ArrowSchemaRef,
+    /// The number of rows in the file.
+    pub num_rows: usize,
+    columns: Vec<ColumnMeta>,
+}
+
+#[derive(Debug, Clone)]
+struct ColumnMeta {
+    values: ArrayMeta,
+    /// The dictionary of a categorical column.
+    levels: Option<ArrayMeta>,
+}
+
+/// A `PrimitiveArray` table of the Feather V1 metadata.
+#[derive(Debug, Clone)]
+struct ArrayMeta {
+    physical_type: i8,
+    offset: u64,
+    length: usize,
+    null_count: usize,
+    total_bytes: usize,
+}
+
+/// Returns whether `reader` holds a Feather V1 file. The position of the reader is preserved.
+pub fn is_feather_v1<R: Read + Seek>(reader: &mut R) -> PolarsResult<bool> {
+    let position = reader.stream_position()?;
+    let end = reader.seek(SeekFrom::End(0))?;
+
+    let mut is_v1 = false;
+    // Leading magic, metadata length and trailing magic.
+    if end >= 12 {
+        let mut magic = [0u8; 4];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut magic)?;
+        if magic == ARROW_MAGIC_V1 {
+            reader.seek(SeekFrom::End(-4))?;
+            reader.read_exact(&mut magic)?;
+            is_v1 = magic == ARROW_MAGIC_V1;
+        }
+    }
+
+    reader.seek(SeekFrom::Start(position))?;
+    Ok(is_v1)
+}
+
+/// Read the metadata of a Feather V1 file.
+pub fn read_feather_v1_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FeatherV1Metadata> {
+    let end = reader.seek(SeekFrom::End(-8))?;
+
+    let mut metadata_len = [0u8; 4];
+    reader.read_exact(&mut metadata_len)?;
+    let metadata_len: usize = i32::from_le_bytes(metadata_len)
+        .try_into()
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))?;
+
+    let start = end
+        .checked_sub(metadata_len as u64)
+        .filter(|start| *start >= ARROW_MAGIC_V1.len() as u64)
+        .ok_or_else(|| polars_err!(oos = OutOfSpecKind::InvalidFooter))?;
+
+    let mut metadata = vec![0; metadata_len];
+    reader.seek(SeekFrom::Start(start))?;
+    reader.read_exact(&mut metadata)?;
+
+    deserialize_metadata(&metadata)
+}
+
+/// Read the columns in `projection` (all columns if `None`) of a Feather V1 file.
+///
+/// Only the byte ranges of the projected columns are read.
+pub fn read_feather_v1<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FeatherV1Metadata,
+    projection: Option<&[usize]>,
+) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
+    let projection = match projection {
+        Some(projection) => projection.to_vec(),
+        None => (0..metadata.columns.len()).collect(),
+    };
+
+    let mut schema = ArrowSchema::with_capacity(projection.len());
+    let mut arrays = Vec::with_capacity(projection.len());
+    for i in projection {
+        let (name, field) = metadata
+            .schema
+            .get_at_index(i)
+            .ok_or_else(|| polars_err!(oob = i, metadata.columns.len()))?;
+        let column = &metadata.columns[i];
+
+        let array = match (&field.dtype, &column.levels) {
+            (ArrowDataType::Dictionary(key_type, values_type, _), Some(levels)) => {
+                let keys = read_array(reader, &column.values, column.values.physical_dtype()?)?;
+                let values = read_array(reader, levels, values_type.as_ref().clone())?;
+                read_dictionary(field.dtype.clone(), *key_type, keys, values)?
+            },
+            (dtype, _) => read_array(reader, &column.values, dtype.clone())?,
+        };
+
+        schema.insert(name.clone(), field.clone());
+        arrays.push(array);
+    }
+
+    RecordBatchT::try_new(metadata.num_rows, Arc::new(schema), arrays)
+}
+
+fn read_dictionary(
+    dtype: ArrowDataType,
+    key_type: IntegerType,
+    keys: Box<dyn Array>,
+    values: Box<dyn Array>,
+) -> PolarsResult<Box<dyn Array>> {
+    fn new<K: DictionaryKey>(
+        dtype: ArrowDataType,
+        keys: Box<dyn Array>,
+        values: Box<dyn Array>,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let keys = keys
+            .as_any()
+            .downcast_ref::<PrimitiveArray<K>>()
+            .unwrap()
+            .clone();
+        Ok(DictionaryArray::<K>::try_new(dtype, keys, values)?.boxed())
+    }
+
+    match key_type {
+        IntegerType::Int8 => new::<i8>(dtype, keys, values),
+        IntegerType::Int16 => new::<i16>(dtype, keys, values),
+        IntegerType::Int32 => new::<i32>(dtype, keys, values),
+        IntegerType::Int64 => new::<i64>(dtype, keys, values),
+        _ => unreachable!(),
+    }
+}
+
+/// Read the buffers of `meta` and decode them as an array of `dtype`, whose physical type must
+/// match the physical type of `meta`.
+fn read_array<R: Read + Seek>(
+    reader: &mut R,
+    meta: &ArrayMeta,
+    dtype: ArrowDataType,
+) -> PolarsResult<Box<dyn Array>> {
+    let mut bytes = vec![0; meta.total_bytes];
+    reader.seek(SeekFrom::Start(meta.offset))?;
+    reader.read_exact(&mut bytes)?;
+
+    let length = meta.length;
+    let mut offset = 0;
+
+    let validity = if meta.null_count > 0 {
+        let num_bytes = length.div_ceil(8);
+        let validity = Bitmap::try_new(get(&bytes, 0, num_bytes)?.to_vec(), length)?;
+        offset = num_bytes.next_multiple_of(ALIGNMENT);
+        Some(validity)
+    } else {
+        None
+    };
+
+    Ok(match meta.physical_type {
+        BOOL => {
+            let values = get(&bytes, offset, length.div_ceil(8))?.to_vec();
+            BooleanArray::try_new(dtype, Bitmap::try_new(values, length)?, validity)?.boxed()
+        },
+        INT8 => read_primitive::<i8>(&bytes, offset, length, dtype, validity)?,
+        INT16 => read_primitive::<i16>(&bytes, offset, length, dtype, validity)?,
+        INT32 => read_primitive::<i32>(&bytes, offset, length, dtype, validity)?,
+        INT64 => read_primitive::<i64>(&bytes, offset, length, dtype, validity)?,
+        UINT8 => read_primitive::<u8>(&bytes, offset, length, dtype, validity)?,
+        UINT16 => read_primitive::<u16>(&bytes, offset, length, dtype, validity)?,
+        UINT32 => read_primitive::<u32>(&bytes, offset, length, dtype, validity)?,
+        UINT64 => read_primitive::<u64>(&bytes, offset, length, dtype, validity)?,
+        FLOAT => read_primitive::<f32>(&bytes, offset, length, dtype, validity)?,
+        DOUBLE => read_primitive::<f64>(&bytes, offset, length, dtype, validity)?,
+        UTF8 | BINARY => {
+            let (offsets, values) = read_var_len::<i32>(&bytes, offset, length)?;
+            if meta.physical_type == UTF8 {
+                Utf8Array::try_new(dtype, offsets.try_into()?, values.into(), validity)?.boxed()
+            } else {
+                BinaryArray::try_new(dtype, offsets.try_into()?, values.into(), validity)?.boxed()
+            }
+        },
+        LARGE_UTF8 | LARGE_BINARY => {
+            let (offsets, values) = read_var_len::<i64>(&bytes, offset, length)?;
+            if meta.physical_type == LARGE_UTF8 {
+                Utf8Array::try_new(dtype, offsets.try_into()?, values.into(), validity)?.boxed()
+            } else {
+                BinaryArray::try_new(dtype, offsets.try_into()?, values.into(), validity)?.boxed()
+            }
+        },
+        _ => unreachable!(),
+    })
+}
+
+fn read_primitive<T: NativeType>(
+    bytes: &[u8],
+    offset: usize,
+    length: usize,
+    dtype: ArrowDataType,
+    validity: Option<Bitmap>,
+) -> PolarsResult<Box<dyn Array>> {
+    let values = get(bytes, offset, length * size_of::<T>())?;
+    let values = bytemuck::pod_collect_to_vec::<u8, T>(values);
+    Ok(PrimitiveArray::<T>::try_new(dtype, values.into(), validity)?.boxed())
+}
+
+/// Read the offsets and values of a variable-length array.
+fn read_var_len<O: Offset>(
+    bytes: &[u8],
+    offset: usize,
+    length: usize,
+) -> PolarsResult<(Vec<O>, Vec<u8>)> {
+    let offsets_len = (length + 1) * size_of::<O>();
+    let offsets = bytemuck::pod_collect_to_vec::<u8, O>(get(bytes, offset, offsets_len)?);
+
+    let values_start = offset + offsets_len.next_multiple_of(ALIGNMENT);
+    let values_len = offsets.last().unwrap().to_usize();
+    let values = get(bytes, values_start, values_len)?.to_vec();
+    Ok((offsets, values))
+}
+
+fn get(bytes: &[u8], offset: usize, length: usize) -> PolarsResult<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or_else(|| polars_err!(oos = "Feather V1 column data is out of bounds"))
+}
+
+fn to_usize(value: i64) -> PolarsResult<usize> {
+    value
+        .try_into()
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))
+}
+
+impl ArrayMeta {
+    fn try_new(table: Table<'_>) -> PolarsResult<Self> {
+        // `encoding`, slot 1, is either PLAIN or a DICTIONARY encoding that no writer implements.
+        if table.scalar::<1, i8>(1, 0)? != 0 {
+            polars_bail!(nyi = "Feather V1 dictionary encoded arrays");
+        }
+        let meta = Self {
+            physical_type: table.scalar::<1, i8>(0, 0)?,
+            offset: to_usize(table.scalar::<8, i64>(2, 0)?)? as u64,
+            length: to_usize(table.scalar::<8, i64>(3, 0)?)?,
+            null_count: to_usize(table.scalar::<8, i64>(4, 0)?)?,
+            total_bytes: to_usize(table.scalar::<8, i64>(5, 0)?)?,
+        };
+        meta.physical_dtype()?;
+        Ok(meta)
+    }
+
+    fn physical_dtype(&self) -> PolarsResult<ArrowDataType> {
+        Ok(match self.physical_type {
+            BOOL => ArrowDataType::Boolean,
+            INT8 => ArrowDataType::Int8,
+            INT16 => ArrowDataType::Int16,
+            INT32 => ArrowDataType::Int32,
+            INT64 => ArrowDataType::Int64,
+            UINT8 => ArrowDataType::UInt8,
+            UINT16 => ArrowDataType::UInt16,
+            UINT32 => ArrowDataType::UInt32,
+            UINT64 => ArrowDataType::UInt64,
+            FLOAT => ArrowDataType::Float32,
+            DOUBLE => ArrowDataType::Float64,
+            UTF8 => ArrowDataType::Utf8,
+            BINARY => ArrowDataType::Binary,
+            LARGE_UTF8 => ArrowDataType::LargeUtf8,
+            LARGE_BINARY => ArrowDataType::LargeBinary,
+            t => polars_bail!(oos = format!("invalid Feather V1 array type {t}")),
+        })
+    }
+
+    fn key_type(&self) -> PolarsResult<IntegerType> {
+        Ok(match self.physical_type {
+            INT8 => IntegerType::Int8,
+            INT16 => IntegerType::Int16,
+            INT32 => IntegerType::Int32,
+            INT64 => IntegerType::Int64,
+            t => polars_bail!(oos = format!("invalid Feather V1 category code type {t}")),
+        })
+    }
+}
+
+fn deserialize_metadata(bytes: &[u8]) -> PolarsResult<FeatherV1Metadata> {
+    let table = Table::root(bytes)?;
+    // `CTable` slots: description, num_rows, columns, version, metadata.
+    let num_rows = to_usize(table.scalar::<8, i64>(1, 0)?)?;
+
+    let mut schema = ArrowSchema::default();
+    let mut columns = vec![];
+    for column in table.tables(2)? {
+        // `Column` slots: name, values, metadata type, metadata, user_metadata.
+        let name = PlSmallStr::from_str(column.str(0)?.unwrap_or_default());
+        let values = ArrayMeta::try_new(column.table(1)?.ok_or_else(|| {
+            polars_err!(oos = format!("Feather V1 column '{name}' has no values"))
+        })?)?;
+        let type_metadata = || {
+            column.table(3)?.ok_or_else(|| {
+                polars_err!(oos = format!("Feather V1 column '{name}' has no type metadata"))
+            })
+        };
+
+        let mut levels = None;
+        let dtype = match column.scalar::<1, u8>(2, 0)? {
+            0 => values.physical_dtype()?,
+            // CategoryMetadata: levels, ordered.
+            1 => {
+                let metadata = type_metadata()?;
+                let meta = ArrayMeta::try_new(metadata.table(0)?.ok_or_else(|| {
+                    polars_err!(oos = format!("Feather V1 column '{name}' has no levels"))
+                })?)?;
+                let dtype = ArrowDataType::Dictionary(
+                    values.key_type()?,
+                    Box::new(meta.physical_dtype()?),
+                    metadata.scalar::<1, u8>(1, 0)? != 0,
+                );
+                levels = Some(meta);
+                dtype
+            },
+            // TimestampMetadata: unit, timezone.
+            2 => {
+                let metadata = type_metadata()?;
+                let unit = time_unit(metadata.scalar::<1, i8>(0, 0)?)?;
+                let timezone = metadata.str(1)?.map(PlSmallStr::from_str);
+                ArrowDataType::Timestamp(unit, timezone)
+            },
+            // DateMetadata.
+            3 => ArrowDataType::Date32,
+            // TimeMetadata: unit.
+            4 => {
+                let unit = time_unit(type_metadata()?.scalar::<1, i8>(0, 0)?)?;
+                if values.physical_type == INT32 {
+                    ArrowDataType::Time32(unit)
+                } else {
+                    ArrowDataType::Time64(unit)
+                }
+            },
+            t => polars_bail!(oos = format!("invalid Feather V1 type metadata {t}")),
+        };
+
+        if values.length != num_rows {
+            polars_bail!(
+                oos = format!(
+                    "Feather V1 column '{name}' has {} rows, expected {num_rows}",
+                    values.length
+                )
+            );
+        }
+
+        schema.insert(name.clone(), Field::new(name, dtype, true));
+        columns.push(ColumnMeta { values, levels });
+    }
+
+    Ok(FeatherV1Metadata {
+        schema: Arc::new(schema),
+        num_rows,
+        columns,
+    })
+}
+
+fn time_unit(unit: i8) -> PolarsResult<TimeUnit> {
+    Ok(match unit {
+        0 => TimeUnit::Second,
+        1 => TimeUnit::Millisecond,
+        2 => TimeUnit::Microsecond,
+        3 => TimeUnit::Nanosecond,
+        u => polars_bail!(oos = format!("invalid Feather V1 time unit {u}")),
+    })
+}
+
+/// A flatbuffers table.
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Table<'a> {
+    fn root(bytes: &'a [u8]) -> PolarsResult<Self> {
+        Ok(Self {
+            bytes,
+            position: read_uoffset(bytes, 0)?,
+        })
+    }
+
+    /// The position of the field in `slot`, or `None` if the field is absent.
+    fn field(&self, slot: usize) -> PolarsResult<Option<usize>> {
+        let vtable = i32::from_le_bytes(read(self.bytes, self.position)?);
+        let vtable = self
+            .position
+            .checked_add_signed(-(vtable as isize))
+            .ok_or_else(out_of_bounds)?;
+        let vtable_len = u16::from_le_bytes(read(self.bytes, vtable)?) as usize;
+
+        let entry = 4 + 2 * slot;
+        if entry + 2 > vtable_len {
+            return Ok(None);
+        }
+        let offset = u16::from_le_bytes(read(self.bytes, vtable + entry)?) as usize;
+        Ok((offset != 0).then_some(self.position + offset))
+    }
+
+    fn scalar<const N: usize, T: FromLeBytes<N>>(
+        &self,
+        slot: usize,
+        default: T,
+    ) -> PolarsResult<T> {
+        Ok(match self.field(slot)? {
+            Some(position) => T::from_le_bytes(read(self.bytes, position)?),
+            None => default,
+        })
+    }
+
+    fn table(&self, slot: usize) -> PolarsResult<Option<Self>> {
+        self.field(slot)?
+            .map(|position| {
+                Ok(Self {
+                    bytes: self.bytes,
+                    position: read_uoffset(self.bytes, position)?,
+                })
+            })
+            .transpose()
+    }
+
+    fn tables(&self, slot: usize) -> PolarsResult<Vec<Self>> {
+        let Some(position) = self.field(slot)? else {
+            return Ok(vec![]);
+        };
+        let vector = read_uoffset(self.bytes, position)?;
+        let len = u32::from_le_bytes(read(self.bytes, vector)?) as usize;
+        (0..len)
+            .map(|i| {
+                Ok(Self {
+                    bytes: self.bytes,
+                    position: read_uoffset(self.bytes, vector + 4 + 4 * i)?,
+                })
+            })
+            .collect()
+    }
+
+    fn str(&self, slot: usize) -> PolarsResult<Option<&'a str>> {
+        self.field(slot)?
+            .map(|position| {
+                let string = read_uoffset(self.bytes, position)?;
+                let len = u32::from_le_bytes(read(self.bytes, string)?) as usize;
+                let bytes = self
+                    .bytes
+                    .get(string + 4..string + 4 + len)
+                    .ok_or_else(out_of_bounds)?;
+                std::str::from_utf8(bytes)
+                    .map_err(|_| polars_err!(oos = "Feather V1 metadata contains invalid utf8"))
+            })
+            .transpose()
+    }
+}
+
+trait FromLeBytes<const N: usize> {
+    fn from_le_bytes(bytes: [u8; N]) -> Self;
+}
+
+macro_rules! impl_from_le_bytes {
+    ($($t:ty, $n:literal),*) => {
+        $(impl FromLeBytes<$n> for $t {
+            fn from_le_bytes(bytes: [u8; $n]) -> Self {
+                <$t>::from_le_bytes(bytes)
+            }
+        })*
+    };
+}
+
+impl_from_le_bytes!(i8, 1, u8, 1, i64, 8);
+
+fn read<const N: usize>(bytes: &[u8], position: usize) -> PolarsResult<[u8; N]> {
+    bytes
+        .get(position..position + N)
+        .map(|bytes| bytes.try_into().unwrap())
+        .ok_or_else(out_of_bounds)
+}
+
+/// Follow the `uoffset` stored at `position`.
+fn read_uoffset(bytes: &[u8], position: usize) -> PolarsResult<usize> {
+    Ok(position + u32::from_le_bytes(read(bytes, position)?) as usize)
+}
+
+fn out_of_bounds() -> polars_error::PolarsError {
+    polars_err!(oos = "Feather V1 metadata is out of bounds")
+}
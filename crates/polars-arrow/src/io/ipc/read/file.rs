@@ -145,8 +145,13 @@ pub(super) fn decode_footer_len(footer: [u8; 10], end: u64) -> PolarsResult<(u64
     let footer_len = i32::from_le_bytes(footer[..4].try_into().unwrap());
 
     if footer[4..] != ARROW_MAGIC_V2 {
-        if footer[..4] == ARROW_MAGIC_V1 {
-            polars_bail!(ComputeError: "feather v1 not supported");
+        // Feather V1 files are framed by `FEA1` and don't contain an Arrow IPC footer.
+        if footer[6..] == ARROW_MAGIC_V1 {
+            polars_bail!(
+                ComputeError:
+                "Feather V1 files can only be read with `read_ipc`, not scanned; convert the file \
+                to Arrow IPC (Feather V2) to scan it"
+            );
         }
         return Err(polars_err!(oos = OutOfSpecKind::InvalidFooter));
     }
@@ -10,6 +10,7 @@ mod array;
 mod common;
 mod deserialize;
 mod error;
+mod feather_v1;
 pub(crate) mod file;
 #[cfg(feature = "io_flight")]
 mod flight;
@@ -21,6 +22,7 @@ mod stream;
 pub(crate) use common::first_dict_field;
 pub use common::{ProjectionInfo, prepare_projection};
 pub use error::OutOfSpecKind;
+pub use feather_v1::{FeatherV1Metadata, is_feather_v1, read_feather_v1, read_feather_v1_metadata};
 pub use file::{
     FileMetadata, deserialize_footer, get_row_count, get_row_count_from_blocks, read_batch,
     read_file_dictionaries, read_file_metadata,
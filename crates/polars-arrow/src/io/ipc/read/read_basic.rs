@@ -10,6 +10,45 @@ use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
 use crate::types::NativeType;
 
+/// The length prefix of a buffer in a compressed body that marks the buffer as stored
+/// uncompressed, e.g. because compression would not have made it smaller.
+const UNCOMPRESSED_LENGTH: i64 = -1;
+
+/// Decompress the body of a compressed buffer (without its length prefix) into `out_slice`.
+fn decompress(
+    compression: Compression,
+    length_prefix: i64,
+    input: &[u8],
+    out_slice: &mut [u8],
+    type_name: &'static str,
+) -> PolarsResult<()> {
+    if length_prefix == UNCOMPRESSED_LENGTH {
+        let Some(input) = input.get(..out_slice.len()) else {
+            polars_bail!(
+                oos = OutOfSpecKind::InvalidBuffer {
+                    length: out_slice.len(),
+                    type_name,
+                    required_number_of_bytes: out_slice.len(),
+                    buffer_length: input.len(),
+                }
+            );
+        };
+        out_slice.copy_from_slice(input);
+        return Ok(());
+    }
+
+    let compression = compression
+        .codec()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
+
+    match compression {
+        arrow_format::ipc::CompressionType::Lz4Frame => {
+            compression::decompress_lz4(input, out_slice)
+        },
+        arrow_format::ipc::CompressionType::Zstd => compression::decompress_zstd(input, out_slice),
+    }
+}
+
 fn read_swapped<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     length: usize,
@@ -121,27 +160,25 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
         .take(buffer_length as u64)
         .read_to_end(scratch)?;
 
-    let length = output_length
-        .unwrap_or_else(|| i64::from_le_bytes(scratch[..8].try_into().unwrap()) as usize);
+    let length_prefix = i64::from_le_bytes(scratch[..8].try_into().unwrap());
+    let length = match output_length {
+        Some(length) => length,
+        None if length_prefix == UNCOMPRESSED_LENGTH => (scratch.len() - 8) / size_of::<T>(),
+        None => length_prefix as usize,
+    };
 
     // It is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
     let mut buffer = vec![T::default(); length];
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
-
-    let compression = compression
-        .codec()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
-
-    match compression {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&scratch[8..], out_slice)?;
-        },
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], out_slice)?;
-        },
-    }
+    decompress(
+        compression,
+        length_prefix,
+        &scratch[8..],
+        out_slice,
+        std::any::type_name::<T>(),
+    )?;
     Ok(buffer)
 }
 
@@ -279,18 +316,8 @@ fn read_compressed_bitmap<R: Read + Seek>(
     scratch.try_reserve(bytes)?;
     reader.by_ref().take(bytes as u64).read_to_end(scratch)?;
 
-    let compression = compression
-        .codec()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
-
-    match compression {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&scratch[8..], &mut buffer)?;
-        },
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], &mut buffer)?;
-        },
-    }
+    let length_prefix = i64::from_le_bytes(scratch[..8].try_into().unwrap());
+    decompress(compression, length_prefix, &scratch[8..], &mut buffer, "u8")?;
     Ok(buffer)
 }
 
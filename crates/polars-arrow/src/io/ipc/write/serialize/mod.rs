@@ -201,6 +201,30 @@ fn pad_buffer_to_64(buffer: &mut Vec<u8>, length: usize) {
     }
 }
 
+/// Writes `bytes` compressed with `compression` to `arrow_data`, prefixed with their uncompressed
+/// length.
+///
+/// If compression doesn't make the buffer smaller, it is stored uncompressed with a length prefix
+/// of `-1`, as allowed by the Arrow IPC format.
+fn write_compressed_bytes(bytes: &[u8], arrow_data: &mut Vec<u8>, compression: Compression) {
+    let start = arrow_data.len();
+    arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
+    match compression {
+        Compression::LZ4 => {
+            compression::compress_lz4(bytes, arrow_data).unwrap();
+        },
+        Compression::ZSTD => {
+            compression::compress_zstd(bytes, arrow_data).unwrap();
+        },
+    }
+
+    if arrow_data.len() - start - 8 >= bytes.len() {
+        arrow_data.truncate(start);
+        arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+        arrow_data.extend_from_slice(bytes);
+    }
+}
+
 /// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
 fn write_bytes(
     bytes: &[u8],
@@ -211,15 +235,7 @@ fn write_bytes(
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        write_compressed_bytes(bytes, arrow_data, compression);
     } else {
         arrow_data.extend_from_slice(bytes);
     };
@@ -313,15 +329,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .map(|x| T::to_be_bytes(&x))
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
-    arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        },
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        },
-    }
+    write_compressed_bytes(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -342,15 +350,7 @@ fn _write_compressed_buffer<T: NativeType>(
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        write_compressed_bytes(bytes, arrow_data, compression);
     } else {
         todo!()
     }
@@ -57,7 +57,7 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::super::assert_allclose;
+    use super::super::{EWMOptions, assert_allclose};
     use super::*;
     const ALPHA: f64 = 0.5;
     const EPS: f64 = 1e-15;
@@ -162,4 +162,14 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_options_decay() {
+        let options = EWMOptions::default();
+        assert_eq!(options.and_alpha(0.25).alpha, 0.25);
+        assert_eq!(options.and_com(0.0).alpha, 1.0);
+        assert_eq!(options.and_com(1.0).alpha, ALPHA);
+        assert_eq!(options.and_span(3).alpha, ALPHA);
+        assert!((options.and_half_life(1.0).alpha - ALPHA).abs() < EPS);
+    }
 }
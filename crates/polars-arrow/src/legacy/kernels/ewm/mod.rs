@@ -43,6 +43,10 @@ impl Hash for EWMOptions {
 }
 
 impl EWMOptions {
+    pub fn and_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
     pub fn and_min_periods(mut self, min_periods: usize) -> Self {
         self.min_periods = min_periods;
         self
@@ -62,7 +66,7 @@ impl EWMOptions {
         self
     }
     pub fn and_com(mut self, com: f64) -> Self {
-        assert!(com > 0.0);
+        assert!(com >= 0.0);
         self.alpha = 1.0 / (1.0 + com);
         self
     }
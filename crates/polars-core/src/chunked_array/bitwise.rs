@@ -122,14 +122,14 @@ impl BitXor for &BooleanChunked {
                         rhs.rename(self.name().clone());
                         rhs
                     },
-                    None => &self.new_from_index(0, rhs.len()) | rhs,
+                    None => &self.new_from_index(0, rhs.len()) ^ rhs,
                 };
             },
             (_, 1) => {
                 return match rhs.get(0) {
                     Some(true) => self.not(),
                     Some(false) => self.clone(),
-                    None => self | &rhs.new_from_index(0, self.len()),
+                    None => self ^ &rhs.new_from_index(0, self.len()),
                 };
             },
             _ => {},
@@ -188,6 +188,24 @@ impl BitAnd for BooleanChunked {
     }
 }
 
+impl BooleanChunked {
+    /// Kleene (three-valued) "and", as in SQL.
+    ///
+    /// A null is an unknown value, so `null & false = false` while `null & true = null`. This is
+    /// what the `&` operator does.
+    pub fn and_kleene(&self, rhs: &BooleanChunked) -> BooleanChunked {
+        self & rhs
+    }
+
+    /// Kleene (three-valued) "or", as in SQL.
+    ///
+    /// A null is an unknown value, so `null | true = true` while `null | false = null`. This is
+    /// what the `|` operator does.
+    pub fn or_kleene(&self, rhs: &BooleanChunked) -> BooleanChunked {
+        self | rhs
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -202,4 +220,24 @@ mod test {
         assert_eq!((&a).bitor(&b).null_count(), 1);
         assert_eq!((&a).bitxor(&b).null_count(), 1);
     }
+    #[test]
+    fn test_kleene_methods() {
+        let a = BooleanChunked::new(
+            PlSmallStr::EMPTY,
+            [Some(true), Some(false), None, None, None],
+        );
+        let b = BooleanChunked::new(
+            PlSmallStr::EMPTY,
+            [None, None, Some(true), Some(false), None],
+        );
+
+        assert_eq!(
+            Vec::from(&a.and_kleene(&b)),
+            &[None, Some(false), None, Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(&a.or_kleene(&b)),
+            &[Some(true), None, Some(true), None, None]
+        );
+    }
 }
@@ -338,6 +338,22 @@ impl ChunkCast for StringChunked {
                     Series::try_from((self.name().clone(), result))
                 },
             },
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => {
+                let ret = Int64Chunked::from_iter_options(
+                    self.name().clone(),
+                    self.iter()
+                        .map(|opt_s| opt_s.and_then(super::temporal::parse_time64ns)),
+                )
+                .into_time()
+                .into_series();
+
+                if options.is_strict() && self.null_count() != ret.null_count() {
+                    handle_casting_failures(&self.clone().into_series(), &ret)?;
+                }
+
+                Ok(ret)
+            },
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
@@ -1400,7 +1400,24 @@ mod test {
         assert_eq!(Vec::from(&c), &[Some(true), Some(true), Some(true)]);
 
         let c = &a | &falses;
-        assert_eq!(Vec::from(&c), &[Some(true), Some(false), None])
+        assert_eq!(Vec::from(&c), &[Some(true), Some(false), None]);
+
+        let c = &a & &trues;
+        assert_eq!(Vec::from(&c), &[Some(true), Some(false), None]);
+
+        let c = &a & &falses;
+        assert_eq!(Vec::from(&c), &[Some(false), Some(false), Some(false)]);
+
+        // Broadcasting a null scalar follows the same three-valued logic.
+        let null = BooleanChunked::full_null(PlSmallStr::EMPTY, 1);
+        let c = &a & &null;
+        assert_eq!(Vec::from(&c), &[None, Some(false), None]);
+        let c = &null | &a;
+        assert_eq!(Vec::from(&c), &[Some(true), None, None]);
+        let c = &a ^ &null;
+        assert_eq!(Vec::from(&c), &[None, None, None]);
+        let c = &null ^ &a;
+        assert_eq!(Vec::from(&c), &[None, None, None]);
     }
 
     #[test]
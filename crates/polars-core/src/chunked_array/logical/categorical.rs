@@ -190,6 +190,25 @@ impl<T: PolarsCategoricalType> CategoricalChunked<T> {
         Ok(unsafe { Self::from_cats_and_dtype_unchecked(phys, dtype) })
     }
 
+    /// Re-encode `other` in the categories of `self`, so that the result can be appended to or
+    /// compared with `self`.
+    ///
+    /// For a `Categorical` the strings of `other` that are missing are added to the categories of
+    /// `self`. For an `Enum` it is an error if `other` contains strings outside of the enum.
+    pub fn merge_categories(&self, other: &Self) -> PolarsResult<Self> {
+        if self.dtype() == other.dtype() {
+            return Ok(other.clone());
+        }
+        let out =
+            Self::from_str_iter(other.name().clone(), self.dtype().clone(), other.iter_str())?;
+        polars_ensure!(
+            out.null_count() == other.null_count(),
+            ComputeError: "cannot merge categories: values of {} are not in {}",
+            other.dtype(), self.dtype()
+        );
+        Ok(out)
+    }
+
     pub fn to_arrow(&self, compat_level: CompatLevel) -> DictionaryArray<T::Native> {
         let keys = self.physical().rechunk();
         let keys = keys.downcast_as_array();
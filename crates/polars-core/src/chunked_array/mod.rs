@@ -1026,6 +1026,41 @@ pub(crate) mod test {
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
 
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_merge_categories() -> PolarsResult<()> {
+        let cat = |namespace: &str, values: &[Option<&str>]| {
+            let cats =
+                Categories::new(PlSmallStr::EMPTY, namespace.into(), CategoricalPhysical::U8);
+            StringChunked::new(PlSmallStr::EMPTY, values)
+                .cast(&DataType::from_categories(cats))
+                .unwrap()
+        };
+        let a = cat("merge_a", &[Some("x"), Some("y")]);
+        let b = cat("merge_b", &[Some("y"), None, Some("z")]);
+        {
+            let _guard = crate::string_cache::with_string_cache(false);
+            assert!(a.clone().append(&b).is_err());
+        }
+        assert_eq!(a.clone().append(&b)?.len(), 5);
+        let (a, b) = (a.cat8()?, b.cat8()?);
+
+        let merged = a.merge_categories(b)?;
+        assert_eq!(merged.dtype(), a.dtype());
+        let mut a = a.clone();
+        a.append(&merged)?;
+        assert_eq!(
+            a.iter_str().collect::<Vec<_>>(),
+            &[Some("x"), Some("y"), Some("y"), None, Some("z")]
+        );
+
+        let fcats = FrozenCategories::new(["x", "y"])?;
+        let e = StringChunked::new(PlSmallStr::EMPTY, &[Some("x")])
+            .cast(&DataType::from_frozen_categories(fcats))?;
+        assert!(e.cat8()?.merge_categories(b).is_err());
+        Ok(())
+    }
+
     #[test]
     #[ignore]
     fn test_shrink_to_fit() {
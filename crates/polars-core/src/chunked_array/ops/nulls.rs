@@ -5,15 +5,17 @@ use crate::chunked_array::flags::StatisticsFlags;
 
 impl<T: PolarsDataType> ChunkedArray<T> {
     /// Get a mask of the null values.
+    ///
+    /// Chunks without nulls share a static zeroed buffer, the validity of the other chunks is
+    /// inverted.
     pub fn is_null(&self) -> BooleanChunked {
-        if !self.has_nulls() {
-            return BooleanChunked::full(self.name().clone(), false, self.len());
-        }
         // dispatch to non-generic function
         is_null(self.name().clone(), &self.chunks)
     }
 
     /// Get a mask of the valid values.
+    ///
+    /// Chunks with nulls reuse their validity without copying.
     pub fn is_not_null(&self) -> BooleanChunked {
         if self.null_count() == 0 {
             return BooleanChunked::full(self.name().clone(), true, self.len());
@@ -33,10 +35,10 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
 pub fn is_not_null(name: PlSmallStr, chunks: &[ArrayRef]) -> BooleanChunked {
     let chunks = chunks.iter().map(|arr| {
-        let bitmap = arr
-            .validity()
-            .cloned()
-            .unwrap_or_else(|| !(&Bitmap::new_zeroed(arr.len())));
+        let bitmap = match arr.validity() {
+            Some(validity) if validity.unset_bits() > 0 => validity.clone(),
+            _ => Bitmap::new_with_value(true, arr.len()),
+        };
         BooleanArray::from_data_default(bitmap, None)
     });
     BooleanChunked::from_chunk_iter(name, chunks)
@@ -44,10 +46,15 @@ pub fn is_not_null(name: PlSmallStr, chunks: &[ArrayRef]) -> BooleanChunked {
 
 pub fn is_null(name: PlSmallStr, chunks: &[ArrayRef]) -> BooleanChunked {
     let chunks = chunks.iter().map(|arr| {
-        let bitmap = arr
-            .validity()
-            .map(|bitmap| !bitmap)
-            .unwrap_or_else(|| Bitmap::new_zeroed(arr.len()));
+        let bitmap = match arr.validity() {
+            Some(validity) if validity.unset_bits() > 0 => {
+                let mut bitmap = !validity;
+                // SAFETY: the nulls of the validity are exactly the set bits of its inverse.
+                unsafe { bitmap.update_bit_count(validity.unset_bits()) };
+                bitmap
+            },
+            _ => Bitmap::new_zeroed(arr.len()),
+        };
         BooleanArray::from_data_default(bitmap, None)
     });
     BooleanChunked::from_chunk_iter(name, chunks)
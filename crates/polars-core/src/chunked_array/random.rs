@@ -238,6 +238,49 @@ impl DataFrame {
             None => Ok(self.clear()),
         }
     }
+
+    /// Sample a fraction between 0.0-1.0 of the rows of every group of `by`, so that each group
+    /// keeps its share of the rows in the sample.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn sample_frac_stratified(
+        &self,
+        by: &[PlSmallStr],
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let gb = self.group_by_stable(by.iter().cloned())?;
+        let groups = gb.get_groups();
+
+        // Every group gets its own seed drawn from this generator, so a fixed seed gives a
+        // reproducible sample.
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mut idx = Vec::with_capacity((self.height() as f64 * frac) as usize);
+        for g in groups.iter() {
+            let len = g.len();
+            let n = (len as f64 * frac) as usize;
+            ensure_shape(n, len, with_replacement)?;
+
+            let group_seed = Some(rng.random());
+            let group_idx = match with_replacement {
+                true => create_rand_index_with_replacement(n, len, group_seed),
+                false => create_rand_index_no_replacement(n, len, group_seed, shuffle),
+            };
+            let group_idx = group_idx.into_no_null_iter();
+            match g {
+                GroupsIndicator::Idx((_, rows)) => idx.extend(group_idx.map(|i| rows[i as usize])),
+                GroupsIndicator::Slice([first, _]) => idx.extend(group_idx.map(|i| first + i)),
+            }
+        }
+        if shuffle {
+            idx.shuffle(&mut rng);
+        }
+
+        let idx = IdxCa::new_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -391,4 +434,41 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_frac_stratified() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "a", "b", "b", "c", "a", "b", "b"],
+            "v" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        ]?;
+        let by = [PlSmallStr::from_static("g")];
+
+        let out = df.sample_frac_stratified(&by, 0.5, false, false, Some(0))?;
+        // Every group keeps half of its rows, rounded down.
+        let out_g = out.column("g")?.str()?;
+        let count = |name| out_g.into_no_null_iter().filter(|g| *g == name).count();
+        assert_eq!((count("a"), count("b"), count("c")), (2, 2, 0));
+
+        // Sampled rows are kept intact.
+        let g = df.column("g")?.str()?;
+        let out_v = out.column("v")?.i32()?;
+        for (og, v) in out_g.into_no_null_iter().zip(out_v.into_no_null_iter()) {
+            assert_eq!(g.get(v as usize), Some(og));
+        }
+
+        // A fixed seed gives the same sample.
+        let again = df.sample_frac_stratified(&by, 0.5, false, false, Some(0))?;
+        assert!(out.equals(&again));
+
+        assert!(
+            df.sample_frac_stratified(&by, 1.5, false, false, Some(0))
+                .is_err()
+        );
+        assert_eq!(
+            df.sample_frac_stratified(&by, 1.5, true, true, Some(0))?
+                .height(),
+            7 + 6 + 1
+        );
+        Ok(())
+    }
 }
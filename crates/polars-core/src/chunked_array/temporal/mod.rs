@@ -19,6 +19,8 @@ use chrono_tz::Tz;
 #[cfg(feature = "timezones")]
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "dtype-time")]
+pub(crate) use time::parse_time64ns;
+#[cfg(feature = "dtype-time")]
 pub use time::time_to_time64ns;
 
 pub use self::conversion::*;
@@ -17,6 +17,14 @@ pub fn time_to_time64ns(time: &NaiveTime) -> i64 {
         + time.nanosecond() as i64
 }
 
+/// Parse a time of day in one of the `%T%.f` (e.g. `12:30:05.123`) or `%H:%M` formats.
+pub(crate) fn parse_time64ns(s: &str) -> Option<i64> {
+    ["%T%.f", "%H:%M"]
+        .iter()
+        .find_map(|fmt| NaiveTime::parse_from_str(s, fmt).ok())
+        .map(|time| time_to_time64ns(&time))
+}
+
 impl TimeChunked {
     /// Convert from Time into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
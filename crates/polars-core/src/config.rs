@@ -1,3 +1,5 @@
+use std::sync::RwLock;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,8 +31,123 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+/// Global settings that take precedence over the corresponding environment variables.
+///
+/// Settings that are `None` fall back to their environment variable, and then to the default.
+/// Use [`set_config`] to change the settings of the process.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Maximum number of rows shown when formatting a `DataFrame`, negative to show all rows.
+    /// Falls back to `POLARS_FMT_MAX_ROWS`.
+    pub fmt_max_rows: Option<i64>,
+    /// Maximum number of columns shown when formatting a `DataFrame`, negative to show all
+    /// columns. Falls back to `POLARS_FMT_MAX_COLS`.
+    pub fmt_max_cols: Option<i64>,
+    /// Maximum number of characters of a string shown when formatting, negative for no limit.
+    /// Falls back to `POLARS_FMT_STR_LEN`.
+    pub fmt_str_len: Option<i64>,
+    /// Maximum number of list elements shown when formatting, negative for no limit. Falls back
+    /// to `POLARS_FMT_TABLE_CELL_LIST_LEN`.
+    pub fmt_list_len: Option<i64>,
+    /// Number of rows used to infer the schema of a CSV file if the reader doesn't set it. Falls
+    /// back to `POLARS_INFER_SCHEMA_LENGTH`.
+    pub infer_schema_length: Option<usize>,
+    /// Size of the thread pool. Falls back to `POLARS_MAX_THREADS`. Only has an effect if it is
+    /// set before the thread pool is first used.
+    pub max_threads: Option<usize>,
+    /// Number of rows per morsel in the streaming engine. Falls back to
+    /// `POLARS_IDEAL_MORSEL_SIZE`. Only has an effect if it is set before the streaming engine is
+    /// first used.
+    pub ideal_morsel_size: Option<usize>,
+    /// Log information about the execution of queries. Falls back to `POLARS_VERBOSE`.
+    pub verbose: Option<bool>,
+    /// Abort queries on the in-memory engine once more than this many bytes are allocated.
+    /// Requires [`TrackingAllocator`](crate::memory::TrackingAllocator) to be the global allocator.
+    pub memory_limit: Option<usize>,
+    /// Build sides of a hash join with fewer keys than this are hashed into a single map that is
+    /// shared by all probing threads. Falls back to `POLARS_JOIN_BROADCAST_THRESHOLD`.
+    pub join_broadcast_threshold: Option<usize>,
+}
+
+impl Config {
+    const fn new() -> Self {
+        Self {
+            fmt_max_rows: None,
+            fmt_max_cols: None,
+            fmt_str_len: None,
+            fmt_list_len: None,
+            infer_schema_length: None,
+            max_threads: None,
+            ideal_morsel_size: None,
+            verbose: None,
+            memory_limit: None,
+            join_broadcast_threshold: None,
+        }
+    }
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(Config::new());
+
+/// Replace the global [`Config`].
+pub fn set_config(config: Config) {
+    *CONFIG.write().unwrap() = config;
+}
+
+/// Get a copy of the global [`Config`].
+pub fn get_config() -> Config {
+    CONFIG.read().unwrap().clone()
+}
+
+/// Read a single setting of the global [`Config`].
+pub(crate) fn config_value<T>(f: impl FnOnce(&Config) -> Option<T>) -> Option<T> {
+    f(&CONFIG.read().unwrap())
+}
+
 pub fn verbose() -> bool {
-    std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
+    config_value(|c| c.verbose)
+        .unwrap_or_else(|| std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1")
+}
+
+/// Number of rows used to infer the schema of a CSV file by default.
+pub fn get_infer_schema_length() -> usize {
+    config_value(|c| c.infer_schema_length).unwrap_or_else(|| {
+        std::env::var("POLARS_INFER_SCHEMA_LENGTH")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(100)
+    })
+}
+
+/// Size of the global thread pool.
+pub fn get_max_threads() -> usize {
+    config_value(|c| c.max_threads).unwrap_or_else(|| {
+        std::env::var("POLARS_MAX_THREADS")
+            .map(|s| s.parse::<usize>().expect("integer"))
+            .unwrap_or_else(|_| {
+                std::thread::available_parallelism()
+                    .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
+                    .get()
+            })
+    })
+}
+
+/// Number of rows per morsel in the streaming engine.
+pub fn get_ideal_morsel_size() -> usize {
+    config_value(|c| c.ideal_morsel_size).unwrap_or_else(|| {
+        std::env::var("POLARS_IDEAL_MORSEL_SIZE")
+            .map(|m| m.parse().unwrap())
+            .unwrap_or(100_000)
+    })
+}
+
+/// Key count below which the build side of a hash join is not partitioned, if it is set.
+/// Invalid values of `POLARS_JOIN_BROADCAST_THRESHOLD` are ignored.
+pub fn get_join_broadcast_threshold() -> Option<usize> {
+    config_value(|c| c.join_broadcast_threshold).or_else(|| {
+        std::env::var("POLARS_JOIN_BROADCAST_THRESHOLD")
+            .ok()
+            .and_then(|s| s.parse().ok())
+    })
 }
 
 pub fn get_engine_affinity() -> String {
@@ -2,8 +2,10 @@
 use std::borrow::Cow;
 
 use arrow::types::PrimitiveType;
+use polars_compute::arithmetic::pl_num::PlNumArithmetic;
 use polars_compute::cast::SerPrimitive;
 use polars_error::feature_gated;
+use polars_utils::format_pl_smallstr;
 use polars_utils::total_ord::ToTotalOrd;
 
 use super::*;
@@ -930,35 +932,75 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    /// Add two values, treating `Null` as the identity so it can be used to fold rows.
+    ///
+    /// Values of different types are added in their supertype, see [`AnyValue::try_add`].
+    ///
+    /// # Panics
+    /// Panics if the values can't be added.
+    #[deprecated(
+        since = "0.50.0",
+        note = "use `AnyValue::try_add`, which returns an error instead of panicking"
+    )]
     #[must_use]
     pub fn add(&self, rhs: &AnyValue) -> AnyValue<'static> {
-        use AnyValue::*;
         match (self, rhs) {
-            (Null, r) => r.clone().into_static(),
-            (l, Null) => l.clone().into_static(),
-            (Int32(l), Int32(r)) => Int32(l + r),
-            (Int64(l), Int64(r)) => Int64(l + r),
-            (UInt32(l), UInt32(r)) => UInt32(l + r),
-            (UInt64(l), UInt64(r)) => UInt64(l + r),
-            (Float32(l), Float32(r)) => Float32(l + r),
-            (Float64(l), Float64(r)) => Float64(l + r),
-            #[cfg(feature = "dtype-duration")]
-            (Duration(l, lu), Duration(r, ru)) => {
-                if lu != ru {
-                    unimplemented!("adding durations with different units is not supported here");
-                }
+            (AnyValue::Null, r) => r.clone().into_static(),
+            (l, AnyValue::Null) => l.clone().into_static(),
+            (l, r) => l.try_add(r).unwrap(),
+        }
+    }
 
-                Duration(l + r, *lu)
-            },
-            #[cfg(feature = "dtype-decimal")]
-            (Decimal(l, ls), Decimal(r, rs)) => {
-                if ls != rs {
-                    unimplemented!("adding decimals with different scales is not supported here");
-                }
+    /// Add two values with the same semantics as adding two [`Series`]: numeric values are
+    /// promoted to their supertype and wrap on overflow, temporal types follow the rules of
+    /// temporal arithmetic, and `Null` propagates.
+    pub fn try_add(&self, rhs: &AnyValue) -> PolarsResult<AnyValue<'static>> {
+        self.arithmetic(rhs, ArithmeticOp::Add)
+    }
+
+    /// Subtract two values with the same semantics as subtracting two [`Series`].
+    pub fn try_sub(&self, rhs: &AnyValue) -> PolarsResult<AnyValue<'static>> {
+        self.arithmetic(rhs, ArithmeticOp::Sub)
+    }
+
+    /// Multiply two values with the same semantics as multiplying two [`Series`].
+    pub fn try_mul(&self, rhs: &AnyValue) -> PolarsResult<AnyValue<'static>> {
+        self.arithmetic(rhs, ArithmeticOp::Mul)
+    }
 
-                Decimal(l + r, *ls)
+    /// Divide two values with the same semantics as dividing two [`Series`]. Integer division
+    /// floors, and dividing an integer by zero gives `Null`.
+    pub fn try_div(&self, rhs: &AnyValue) -> PolarsResult<AnyValue<'static>> {
+        self.arithmetic(rhs, ArithmeticOp::Div)
+    }
+
+    fn arithmetic(&self, rhs: &AnyValue, op: ArithmeticOp) -> PolarsResult<AnyValue<'static>> {
+        use AnyValue::*;
+        match (self, rhs) {
+            (Null, _) | (_, Null) => Ok(Null),
+            (l, r) if l.is_primitive_numeric() && r.is_primitive_numeric() => {
+                numeric_arithmetic(l, r, op)
             },
-            _ => unimplemented!(),
+            (String(_) | StringOwned(_), String(_) | StringOwned(_)) if op == ArithmeticOp::Add => {
+                let (l, r) = (self.get_str().unwrap(), rhs.get_str().unwrap());
+                Ok(StringOwned(format_pl_smallstr!("{l}{r}")))
+            },
+            // The precision and scale rules of decimals live in the decimal kernels.
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(..), _) | (_, Decimal(..)) => {
+                let lhs =
+                    Series::from_any_values(PlSmallStr::EMPTY, std::slice::from_ref(self), true)?;
+                let rhs =
+                    Series::from_any_values(PlSmallStr::EMPTY, std::slice::from_ref(rhs), true)?;
+                let out = match op {
+                    ArithmeticOp::Add => &lhs + &rhs,
+                    ArithmeticOp::Sub => &lhs - &rhs,
+                    ArithmeticOp::Mul => &lhs * &rhs,
+                    ArithmeticOp::Div => &lhs / &rhs,
+                }?;
+                Ok(out.get(0)?.into_static())
+            },
+            (l, r) => temporal_arithmetic(l, r, op),
         }
     }
 
@@ -1646,6 +1688,169 @@ impl From<bool> for AnyValue<'static> {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl ArithmeticOp {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+        }
+    }
+
+    /// Apply the operation like the arithmetic kernels do, returning `None` for an integer
+    /// division by zero.
+    fn apply<T: PlNumArithmetic + IsFloat + Zero + PartialEq>(self, l: T, r: T) -> Option<T> {
+        Some(match self {
+            Self::Add => l.wrapping_add(r),
+            Self::Sub => l.wrapping_sub(r),
+            Self::Mul => l.wrapping_mul(r),
+            Self::Div if !T::is_float() && r == T::zero() => return None,
+            Self::Div => l.legacy_div(r),
+        })
+    }
+}
+
+fn numeric_arithmetic(
+    l: &AnyValue,
+    r: &AnyValue,
+    op: ArithmeticOp,
+) -> PolarsResult<AnyValue<'static>> {
+    use AnyValue::*;
+    let dtype = try_get_supertype(&l.dtype(), &r.dtype())?;
+    let cast = |av: &AnyValue| {
+        av.strict_cast(&dtype)
+            .map(AnyValue::into_static)
+            .ok_or_else(|| polars_err!(ComputeError: "cannot cast {} to {}", av, dtype))
+    };
+    let out = match (cast(l)?, cast(r)?) {
+        (Int8(l), Int8(r)) => op.apply(l, r).map(Int8),
+        (Int16(l), Int16(r)) => op.apply(l, r).map(Int16),
+        (Int32(l), Int32(r)) => op.apply(l, r).map(Int32),
+        (Int64(l), Int64(r)) => op.apply(l, r).map(Int64),
+        (Int128(l), Int128(r)) => op.apply(l, r).map(Int128),
+        (UInt8(l), UInt8(r)) => op.apply(l, r).map(UInt8),
+        (UInt16(l), UInt16(r)) => op.apply(l, r).map(UInt16),
+        (UInt32(l), UInt32(r)) => op.apply(l, r).map(UInt32),
+        (UInt64(l), UInt64(r)) => op.apply(l, r).map(UInt64),
+        (Float32(l), Float32(r)) => op.apply(l, r).map(Float32),
+        (Float64(l), Float64(r)) => op.apply(l, r).map(Float64),
+        _ => polars_bail!(op = op.name(), l.dtype(), r.dtype()),
+    };
+    Ok(out.unwrap_or(Null))
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn convert_time_unit(v: i64, from: TimeUnit, to: TimeUnit) -> i64 {
+    use TimeUnit::*;
+    match (from, to) {
+        (Nanoseconds, Microseconds) | (Microseconds, Milliseconds) => v / 1_000,
+        (Nanoseconds, Milliseconds) => v / 1_000_000,
+        (Microseconds, Nanoseconds) | (Milliseconds, Microseconds) => v.wrapping_mul(1_000),
+        (Milliseconds, Nanoseconds) => v.wrapping_mul(1_000_000),
+        _ => v,
+    }
+}
+
+/// Multiply or divide a duration by a number, like the duration kernels do.
+#[cfg(feature = "dtype-duration")]
+fn scale_duration(
+    v: i64,
+    tu: TimeUnit,
+    n: &AnyValue,
+    op: ArithmeticOp,
+) -> PolarsResult<AnyValue<'static>> {
+    let out = if n.is_float() {
+        let (v, n) = (v as f64, n.extract::<f64>().unwrap());
+        let out = if op == ArithmeticOp::Mul {
+            v * n
+        } else {
+            v / n
+        };
+        Some(out as i64)
+    } else {
+        let n = n
+            .extract::<i64>()
+            .ok_or_else(|| polars_err!(ComputeError: "cannot cast {} to i64", n))?;
+        op.apply(v, n)
+    };
+    Ok(out.map_or(AnyValue::Null, |v| AnyValue::Duration(v, tu)))
+}
+
+fn temporal_arithmetic(
+    l: &AnyValue,
+    r: &AnyValue,
+    op: ArithmeticOp,
+) -> PolarsResult<AnyValue<'static>> {
+    #[allow(unused_imports)]
+    use AnyValue::*;
+    #[allow(unused_imports)]
+    use ArithmeticOp as Op;
+    #[allow(unused_imports)]
+    use TimeUnit::*;
+    let out = match (l.as_borrowed(), r.as_borrowed(), op) {
+        #[cfg(feature = "dtype-duration")]
+        (Duration(l, lu), Duration(r, ru), Op::Add | Op::Sub) => {
+            let tu = get_time_units(&lu, &ru);
+            let (l, r) = (convert_time_unit(l, lu, tu), convert_time_unit(r, ru, tu));
+            Duration(op.apply(l, r).unwrap(), tu)
+        },
+        #[cfg(feature = "dtype-duration")]
+        (Duration(l, lu), Duration(r, ru), Op::Div) => {
+            Float64(l as f64 / convert_time_unit(r, ru, lu) as f64)
+        },
+        #[cfg(feature = "dtype-duration")]
+        (Duration(v, tu), n, Op::Mul | Op::Div) | (n, Duration(v, tu), Op::Mul)
+            if n.is_primitive_numeric() =>
+        {
+            return scale_duration(v, tu, &n, op);
+        },
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (Datetime(l, lu, tz), Duration(r, ru), Op::Add | Op::Sub)
+        | (Duration(r, ru), Datetime(l, lu, tz), Op::Add) => {
+            let tu = get_time_units(&lu, &ru);
+            let (l, r) = (convert_time_unit(l, lu, tu), convert_time_unit(r, ru, tu));
+            DatetimeOwned(
+                op.apply(l, r).unwrap(),
+                tu,
+                tz.map(|tz| Arc::new(tz.clone())),
+            )
+        },
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (Datetime(l, lu, ltz), Datetime(r, ru, rtz), Op::Sub) => {
+            polars_ensure!(
+                ltz == rtz,
+                InvalidOperation: "cannot subtract datetimes with different time zones"
+            );
+            let tu = get_time_units(&lu, &ru);
+            let (l, r) = (convert_time_unit(l, lu, tu), convert_time_unit(r, ru, tu));
+            Duration(l.wrapping_sub(r), tu)
+        },
+        // Dates are offset as millisecond datetimes.
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        (Date(d), Duration(v, tu), Op::Add | Op::Sub) | (Duration(v, tu), Date(d), Op::Add) => {
+            let ms = op
+                .apply(d as i64 * MS_IN_DAY, convert_time_unit(v, tu, Milliseconds))
+                .unwrap();
+            Date(ms.div_euclid(MS_IN_DAY) as i32)
+        },
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        (Date(l), Date(r), Op::Sub) => Duration((l as i64 - r as i64) * MS_IN_DAY, Milliseconds),
+        #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+        (Time(l), Time(r), Op::Sub) => Duration(l.wrapping_sub(r), Nanoseconds),
+        _ => polars_bail!(op = op.name(), l.dtype(), r.dtype()),
+    };
+    Ok(out.into_static())
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(feature = "dtype-categorical")]
@@ -1741,4 +1946,89 @@ mod test {
             assert_eq!(dt_p, dt);
         }
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-i8",
+        feature = "dtype-i16",
+        feature = "dtype-u8",
+        feature = "dtype-u16"
+    ))]
+    fn test_any_value_arithmetic() -> crate::prelude::PolarsResult<()> {
+        use super::AnyValue::*;
+
+        // Mixed widths and signedness are computed in the supertype.
+        assert_eq!(UInt8(200).try_add(&UInt16(100))?, UInt16(300));
+        assert_eq!(Int8(-1).try_add(&UInt32(3))?, Int64(2));
+        assert_eq!(Int32(1).try_add(&Float64(0.5))?, Float64(1.5));
+        assert_eq!(Int64(7).try_sub(&Int32(2))?, Int64(5));
+        assert_eq!(UInt8(3).try_mul(&Int8(-2))?, Int16(-6));
+        assert_eq!(UInt16(10).try_div(&UInt8(4))?, UInt16(2));
+        assert_eq!(Int32(7).try_div(&Int16(-2))?, Int32(-4));
+        assert_eq!(Float32(3.0).try_div(&Float32(2.0))?, Float32(1.5));
+
+        // Integers wrap on overflow and give null when divided by zero.
+        assert_eq!(UInt8(250).try_add(&UInt8(10))?, UInt8(4));
+        assert_eq!(UInt8(3).try_sub(&UInt16(5))?, UInt16(u16::MAX - 1));
+        assert_eq!(Int32(i32::MAX).try_mul(&Int32(2))?, Int32(-2));
+        assert_eq!(Int64(1).try_div(&Int8(0))?, Null);
+
+        assert_eq!(Int32(1).try_add(&Null)?, Null);
+        assert!(String("a").try_sub(&Int32(1)).is_err());
+        #[allow(deprecated)]
+        {
+            assert_eq!(Null.add(&Int16(3)), Int16(3));
+            assert_eq!(UInt8(1).add(&Int8(2)), Int16(3));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration",
+        feature = "dtype-time"
+    ))]
+    fn test_any_value_temporal_arithmetic() -> crate::prelude::PolarsResult<()> {
+        use super::AnyValue::*;
+        use crate::prelude::*;
+
+        let ms = TimeUnit::Milliseconds;
+        let us = TimeUnit::Microseconds;
+        assert_eq!(
+            Datetime(5_000, ms, None).try_sub(&Datetime(2_000, ms, None))?,
+            Duration(3_000, ms)
+        );
+        assert_eq!(
+            Datetime(5_000, ms, None).try_add(&Duration(1_000_000, us))?,
+            DatetimeOwned(6_000, ms, None)
+        );
+        assert!(
+            Datetime(5_000, ms, None)
+                .try_add(&Datetime(1, ms, None))
+                .is_err()
+        );
+
+        assert_eq!(
+            Duration(1, ms).try_add(&Duration(1_000, us))?,
+            Duration(2, ms)
+        );
+        assert_eq!(Duration(10, ms).try_mul(&Int32(3))?, Duration(30, ms));
+        assert_eq!(UInt8(2).try_mul(&Duration(10, ms))?, Duration(20, ms));
+        assert_eq!(Duration(10, ms).try_div(&Int64(4))?, Duration(2, ms));
+        assert_eq!(
+            Duration(10, ms).try_div(&Duration(4_000, us))?,
+            Float64(2.5)
+        );
+
+        assert_eq!(Date(1).try_add(&Duration(2 * MS_IN_DAY, ms))?, Date(3));
+        assert_eq!(Date(0).try_sub(&Duration(1, ms))?, Date(-1));
+        assert_eq!(Date(3).try_sub(&Date(1))?, Duration(2 * MS_IN_DAY, ms));
+        assert_eq!(
+            Time(5).try_sub(&Time(2))?,
+            Duration(3, TimeUnit::Nanoseconds)
+        );
+        Ok(())
+    }
 }
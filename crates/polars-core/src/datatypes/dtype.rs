@@ -982,6 +982,43 @@ impl DataType {
         }
     }
 
+    /// Same as [`DataType::matches_schema_type`], but (nested) Categoricals with different
+    /// categories match as well. In such a case Ok(true) is returned, because a cast is
+    /// necessary to remap the categories to the ones in the schema. This raises if the string
+    /// cache is disabled, see [`crate::string_cache`].
+    pub fn matches_schema_type_remap_categories(
+        &self,
+        schema_type: &DataType,
+    ) -> PolarsResult<bool> {
+        match (self, schema_type) {
+            (DataType::List(l), DataType::List(r)) => l.matches_schema_type_remap_categories(r),
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(l, sl), DataType::Array(r, sr)) if sl == sr => {
+                l.matches_schema_type_remap_categories(r)
+            },
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(l), DataType::Struct(r)) if l.len() == r.len() => {
+                let mut must_cast = false;
+                for (l, r) in l.iter().zip(r.iter()) {
+                    must_cast |= l.dtype.matches_schema_type_remap_categories(&r.dtype)?;
+                }
+                Ok(must_cast)
+            },
+            #[cfg(feature = "dtype-categorical")]
+            (DataType::Categorical(l, _), DataType::Categorical(r, _)) => {
+                if Arc::ptr_eq(l, r) {
+                    return Ok(false);
+                }
+                polars_ensure!(
+                    crate::string_cache::using_string_cache(),
+                    string_cache_mismatch
+                );
+                Ok(true)
+            },
+            _ => self.matches_schema_type(schema_type),
+        }
+    }
+
     #[inline]
     pub fn is_unknown(&self) -> bool {
         matches!(self, DataType::Unknown(_))
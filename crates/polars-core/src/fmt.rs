@@ -110,18 +110,31 @@ fn parse_env_var_limit(name: &str, default: usize) -> usize {
     )
 }
 
+/// Gets a limit from the global [`Config`], falling back to the environment variable `name`.
+fn get_limit(setting: fn(&Config) -> Option<i64>, name: &str, default: usize) -> usize {
+    match config_value(setting) {
+        Some(n) if n < 0 => usize::MAX,
+        Some(n) => n as usize,
+        None => parse_env_var_limit(name, default),
+    }
+}
+
 fn get_row_limit() -> usize {
-    parse_env_var_limit(FMT_MAX_ROWS, DEFAULT_ROW_LIMIT)
+    get_limit(|c| c.fmt_max_rows, FMT_MAX_ROWS, DEFAULT_ROW_LIMIT)
 }
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn get_col_limit() -> usize {
-    parse_env_var_limit(FMT_MAX_COLS, DEFAULT_COL_LIMIT)
+    get_limit(|c| c.fmt_max_cols, FMT_MAX_COLS, DEFAULT_COL_LIMIT)
 }
 fn get_str_len_limit() -> usize {
-    parse_env_var_limit(FMT_STR_LEN, DEFAULT_STR_LEN_LIMIT)
+    get_limit(|c| c.fmt_str_len, FMT_STR_LEN, DEFAULT_STR_LEN_LIMIT)
 }
 fn get_list_len_limit() -> usize {
-    parse_env_var_limit(FMT_TABLE_CELL_LIST_LEN, DEFAULT_LIST_LEN_LIMIT)
+    get_limit(
+        |c| c.fmt_list_len,
+        FMT_TABLE_CELL_LIST_LEN,
+        DEFAULT_LIST_LEN_LIMIT,
+    )
 }
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn get_ellipsis() -> &'static str {
@@ -1521,4 +1534,19 @@ ChunkedArray: 'name' [str]
             format!("{ca:?}")
         );
     }
+
+    #[test]
+    fn test_fmt_config_precedence() {
+        use super::get_limit;
+
+        // A variable only this test uses, so it doesn't race with the formatting of other tests.
+        const NAME: &str = "POLARS_TEST_FMT_CONFIG_PRECEDENCE";
+
+        unsafe { std::env::set_var(NAME, "-1") };
+        assert_eq!(get_limit(|_| None, NAME, 10), usize::MAX);
+        assert_eq!(get_limit(|_| Some(4), NAME, 10), 4);
+        assert_eq!(get_limit(|_| Some(-1), NAME, 10), usize::MAX);
+        unsafe { std::env::remove_var(NAME) };
+        assert_eq!(get_limit(|_| None, NAME, 10), 10);
+    }
 }
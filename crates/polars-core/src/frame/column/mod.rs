@@ -833,6 +833,15 @@ impl Column {
         unsafe { self.as_materialized_series().agg_sum(groups) }.into()
     }
 
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn agg_product(&self, groups: &GroupsType) -> Self {
+        // @scalar-opt
+        unsafe { self.as_materialized_series().agg_product(groups) }.into()
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
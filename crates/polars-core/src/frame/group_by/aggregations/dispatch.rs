@@ -175,6 +175,34 @@ impl Series {
         }
     }
 
+    /// Aggregate the product of every group.
+    ///
+    /// Integers smaller than 64 bits and booleans are first cast to `Int64` to prevent overflow,
+    /// like [`Series::product`].
+    #[doc(hidden)]
+    pub unsafe fn agg_product(&self, groups: &GroupsType) -> Series {
+        // Prevent a rechunk for every individual group.
+        let s = if groups.len() > 1 {
+            self.rechunk()
+        } else {
+            self.clone()
+        };
+
+        use DataType::*;
+        match s.dtype() {
+            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => {
+                s.cast(&Int64).unwrap().agg_product(groups)
+            },
+            Int64 => s.i64().unwrap().agg_product(groups),
+            UInt64 => s.u64().unwrap().agg_product(groups),
+            #[cfg(feature = "dtype-i128")]
+            Int128 => s.i128().unwrap().agg_product(groups),
+            Float32 => s.f32().unwrap().agg_product(groups),
+            Float64 => s.f64().unwrap().agg_product(groups),
+            _ => Series::full_null(PlSmallStr::EMPTY, groups.len(), s.dtype()),
+        }
+    }
+
     #[doc(hidden)]
     pub unsafe fn agg_median(&self, groups: &GroupsType) -> Series {
         // Prevent a rechunk for every individual group.
@@ -249,6 +277,15 @@ impl Series {
         match s.dtype() {
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
+            #[cfg(feature = "dtype-date")]
+            Date => (self
+                .to_physical_repr()
+                .agg_quantile(groups, quantile, method)
+                .cast(&Float64)
+                .unwrap()
+                * (MS_IN_DAY as f64))
+                .cast(&Datetime(TimeUnit::Milliseconds, None))
+                .unwrap(),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
                 let ca = s.to_physical_repr();
                 let physical_type = ca.dtype();
@@ -11,7 +11,7 @@ use arrow::legacy::kernels::take_agg::*;
 use arrow::legacy::trusted_len::TrustedLenPush;
 use arrow::types::NativeType;
 use num_traits::pow::Pow;
-use num_traits::{Bounded, Float, Num, NumCast, ToPrimitive, Zero};
+use num_traits::{Bounded, Float, Num, NumCast, One, ToPrimitive, Zero};
 use polars_compute::rolling::no_nulls::{
     MaxWindow, MeanWindow, MinWindow, MomentWindow, QuantileWindow, RollingAggWindowNoNulls,
     SumWindow,
@@ -651,6 +651,40 @@ where
             },
         }
     }
+
+    pub(crate) unsafe fn agg_product(&self, groups: &GroupsType) -> Series {
+        match groups {
+            GroupsType::Idx(groups) => {
+                let ca = self.rechunk();
+                let arr = ca.downcast_iter().next().unwrap();
+                let no_nulls = arr.null_count() == 0;
+                _agg_helper_idx_no_null::<T, _>(groups, |(first, idx)| {
+                    debug_assert!(idx.len() <= self.len());
+                    if idx.is_empty() {
+                        T::Native::one()
+                    } else if idx.len() == 1 {
+                        arr.get(first as usize).unwrap_or(T::Native::one())
+                    } else if no_nulls {
+                        take_agg_no_null_primitive_iter_unchecked(arr, idx2usize(idx), |a, b| a * b)
+                            .unwrap_or(T::Native::one())
+                    } else {
+                        take_agg_primitive_iter_unchecked(arr, idx2usize(idx), |a, b| a * b)
+                            .unwrap_or(T::Native::one())
+                    }
+                })
+            },
+            GroupsType::Slice { groups, .. } => {
+                _agg_helper_slice_no_null::<T, _>(groups, |[first, len]| {
+                    debug_assert!(len <= self.len() as IdxSize);
+                    let arr_group = _slice_from_offsets(self, first, len);
+                    arr_group
+                        .downcast_iter()
+                        .flat_map(|arr| arr.into_iter().flatten())
+                        .fold(T::Native::one(), |a, b| a * *b)
+                })
+            },
+        }
+    }
 }
 
 impl<T> SeriesWrap<ChunkedArray<T>>
@@ -799,22 +799,37 @@ impl<'a> GroupBy<'a> {
     }
 
     /// Apply a closure over the groups as a new [`DataFrame`] in parallel.
-    #[deprecated(since = "0.24.1", note = "use polars.lazy aggregations")]
+    ///
+    /// The groups are processed on the [`POOL`] and the outputs are concatenated in
+    /// group order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn top_2(df: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.group_by_stable(["group"])?.par_apply(|df| {
+    ///         let df = df.sort(["value"], SortMultipleOptions::new().with_order_descending(true))?;
+    ///         Ok(df.head(Some(2)))
+    ///     })
+    /// }
+    /// ```
     pub fn par_apply<F>(&self, f: F) -> PolarsResult<DataFrame>
     where
         F: Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
         let df = self.prepare_apply()?;
-        let dfs = self
-            .get_groups()
-            .par_iter()
-            .map(|g| {
-                // SAFETY:
-                // groups are in bounds
-                let sub_df = unsafe { take_df(&df, g) };
-                f(sub_df)
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        let dfs = POOL.install(|| {
+            self.get_groups()
+                .par_iter()
+                .map(|g| {
+                    // SAFETY:
+                    // groups are in bounds
+                    let sub_df = unsafe { take_df(&df, g) };
+                    f(sub_df)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
 
         let mut df = accumulate_dataframes_vertical(dfs)?;
         df.as_single_chunk_par();
@@ -873,6 +888,7 @@ pub enum GroupByMethod {
     First,
     Last,
     Sum,
+    Product,
     Groups,
     NUnique,
     Quantile(f64, QuantileMethod),
@@ -895,6 +911,7 @@ impl Display for GroupByMethod {
             First => "first",
             Last => "last",
             Sum => "sum",
+            Product => "product",
             Groups => "groups",
             NUnique => "n_unique",
             Quantile(_, _) => "quantile",
@@ -920,6 +937,7 @@ pub fn fmt_group_by_column(name: &str, method: GroupByMethod) -> PlSmallStr {
         First => format_pl_smallstr!("{name}_first"),
         Last => format_pl_smallstr!("{name}_last"),
         Sum => format_pl_smallstr!("{name}_sum"),
+        Product => format_pl_smallstr!("{name}_product"),
         Groups => PlSmallStr::from_static("groups"),
         NUnique => format_pl_smallstr!("{name}_n_unique"),
         Count { .. } => format_pl_smallstr!("{name}_count"),
@@ -1186,6 +1204,35 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_par_apply() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "b", "a"],
+            "int" => [1, 2, 3, 4, 5, 6]
+        ]?;
+
+        fn scale_by_group_size(df: DataFrame) -> PolarsResult<DataFrame> {
+            let n = df.height() as i32;
+            let scaled = df.column("int")?.i32()?.apply_values(|v| v * n);
+            df.hstack(&[scaled.into_column().with_name("scaled".into())])
+        }
+
+        let gb = df.group_by_stable(["g"])?;
+        let out = gb.par_apply(scale_by_group_size)?;
+        let expected = df![
+            "g" => ["a", "a", "a", "b", "b", "c"],
+            "int" => [1, 3, 6, 2, 5, 4],
+            "scaled" => [3, 9, 18, 4, 10, 4]
+        ]?;
+        assert!(out.equals(&expected));
+        assert!(out.equals(&gb.apply(scale_by_group_size)?));
+
+        let err = gb.par_apply(|df| polars_bail!(ComputeError: "failed on {}", df.height()));
+        assert!(err.is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
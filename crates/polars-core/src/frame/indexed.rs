@@ -0,0 +1,123 @@
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::DataFrame;
+use crate::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
+use crate::prelude::*;
+
+/// A [`DataFrame`] with a hash index on one of its columns, for repeated point lookups.
+///
+/// Created with [`DataFrame::create_index`]. Every lookup is a hash probe followed by a gather,
+/// instead of a filter that scans the whole column. The index is keyed on the row encoding of the
+/// values, so floats compare like in a join: `-0.0` equals `0.0` and all NaNs are equal.
+#[derive(Clone)]
+pub struct IndexedDataFrame {
+    df: DataFrame,
+    column: PlSmallStr,
+    dtype: DataType,
+    rows: PlHashMap<Vec<u8>, Vec<IdxSize>>,
+}
+
+/// Row-encodes `s`, so that equal values have equal keys.
+fn encode_keys(s: &Series) -> PolarsResult<BinaryOffsetChunked> {
+    _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &[s.clone().into_column()])
+}
+
+impl DataFrame {
+    /// Build a hash index on `column` that can answer repeated point lookups.
+    ///
+    /// Null values are not indexed.
+    pub fn create_index(&self, column: &str) -> PolarsResult<IndexedDataFrame> {
+        let c = self.column(column)?;
+        polars_ensure!(
+            !c.dtype().is_nested() && !c.dtype().is_object(),
+            InvalidOperation: "cannot create an index on a column of type {}", c.dtype()
+        );
+
+        let s = c.as_materialized_series();
+        let keys = encode_keys(s)?;
+        let mut rows = PlHashMap::<Vec<u8>, Vec<IdxSize>>::with_capacity(s.len());
+        for (i, (key, is_valid)) in keys
+            .into_no_null_iter()
+            .zip(s.is_not_null().into_no_null_iter())
+            .enumerate()
+        {
+            if !is_valid {
+                continue;
+            }
+            match rows.get_mut(key) {
+                Some(idx) => idx.push(i as IdxSize),
+                None => {
+                    rows.insert(key.to_vec(), vec![i as IdxSize]);
+                },
+            }
+        }
+
+        Ok(IndexedDataFrame {
+            df: self.clone(),
+            column: c.name().clone(),
+            dtype: c.dtype().clone(),
+            rows,
+        })
+    }
+}
+
+impl IndexedDataFrame {
+    /// The indexed [`DataFrame`].
+    pub fn df(&self) -> &DataFrame {
+        &self.df
+    }
+
+    /// The name of the indexed column.
+    pub fn column(&self) -> &PlSmallStr {
+        &self.column
+    }
+
+    /// The row indices where the indexed column equals `value`, in ascending order.
+    ///
+    /// `value` is cast to the type of the indexed column first.
+    pub fn lookup_idx(&self, value: AnyValue) -> PolarsResult<&[IdxSize]> {
+        if value.is_null() {
+            return Ok(&[]);
+        }
+        let s = Series::from_any_values(PlSmallStr::EMPTY, &[value], true)?;
+        let keys = self.probe_keys(&s)?;
+        Ok(self.get_idx(keys.get(0)))
+    }
+
+    /// The rows where the indexed column equals `value`.
+    pub fn lookup(&self, value: AnyValue) -> PolarsResult<DataFrame> {
+        let idx = self.lookup_idx(value)?;
+        // SAFETY: the indices were taken from the indexed frame.
+        Ok(unsafe { self.df.take_slice_unchecked(idx) })
+    }
+
+    /// The rows where the indexed column equals any of `values`, grouped in the order of
+    /// `values`. Values that occur multiple times produce their rows multiple times.
+    pub fn lookup_many(&self, values: &Series) -> PolarsResult<DataFrame> {
+        let keys = self.probe_keys(values)?;
+        let mut idx = Vec::new();
+        for (key, is_valid) in keys
+            .into_no_null_iter()
+            .zip(values.is_not_null().into_no_null_iter())
+        {
+            if is_valid {
+                idx.extend_from_slice(self.get_idx(Some(key)));
+            }
+        }
+        // SAFETY: the indices were taken from the indexed frame.
+        Ok(unsafe { self.df.take_slice_unchecked(&idx) })
+    }
+
+    /// Casts `values` to the type of the indexed column once and row-encodes them.
+    fn probe_keys(&self, values: &Series) -> PolarsResult<BinaryOffsetChunked> {
+        let values = values.strict_cast(&self.dtype)?;
+        encode_keys(&values)
+    }
+
+    fn get_idx(&self, key: Option<&[u8]>) -> &[IdxSize] {
+        key.and_then(|key| self.rows.get(key))
+            .map_or(&[], |idx| idx.as_slice())
+    }
+}
@@ -29,9 +29,12 @@ mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 pub(crate) mod horizontal;
+mod indexed;
+pub use indexed::IndexedDataFrame;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+mod unique_key;
 mod upstream_traits;
 mod validation;
 
@@ -178,11 +181,15 @@ pub struct DataFrame {
     /// A cached schema. This might not give correct results if the DataFrame was modified in place
     /// between schema and reading.
     cached_schema: OnceLock<SchemaRef>,
+
+    /// Columns whose combined values are unique per row. See [`DataFrame::unique_key`].
+    unique_key: Option<Arc<[PlSmallStr]>>,
 }
 
 impl DataFrame {
     pub fn clear_schema(&mut self) {
         self.cached_schema = OnceLock::new();
+        self.unique_key = None;
     }
 
     #[inline]
@@ -300,6 +307,7 @@ impl DataFrame {
             height,
             columns,
             cached_schema: OnceLock::new(),
+            unique_key: None,
         })
     }
 
@@ -385,6 +393,7 @@ impl DataFrame {
             height,
             columns: vec![],
             cached_schema: OnceLock::new(),
+            unique_key: None,
         }
     }
 
@@ -553,6 +562,7 @@ impl DataFrame {
             height,
             columns,
             cached_schema: OnceLock::new(),
+            unique_key: None,
         }
     }
 
@@ -753,7 +763,10 @@ impl DataFrame {
     /// [`DataFrame::set_height`] is called afterwards with the appropriate `height`.
     /// The caller must ensure that the cached schema is cleared if it modifies the schema by
     /// calling [`DataFrame::clear_schema`].
+    ///
+    /// This drops the unique key of the [`DataFrame`], as the columns may be modified.
     pub unsafe fn get_columns_mut(&mut self) -> &mut Vec<Column> {
+        self.unique_key = None;
         &mut self.columns
     }
 
@@ -1171,6 +1184,7 @@ impl DataFrame {
                 Ok(())
             })?;
         self.height += other.height;
+        self.unique_key = None;
         Ok(self)
     }
 
@@ -1199,6 +1213,7 @@ impl DataFrame {
                 Ok(())
             })?;
         self.height += other.height;
+        self.unique_key = None;
         Ok(self)
     }
 
@@ -1220,6 +1235,7 @@ impl DataFrame {
                     .expect("should not fail");
             });
         self.height += other.height;
+        self.unique_key = None;
     }
 
     /// Concatenate a [`DataFrame`] to this [`DataFrame`]
@@ -1236,6 +1252,7 @@ impl DataFrame {
                 left.append_owned(right).expect("should not fail");
             });
         self.height += other.height;
+        self.unique_key = None;
     }
 
     /// Extend the memory backed by this [`DataFrame`] with the values from `other`.
@@ -1441,6 +1458,76 @@ impl DataFrame {
         self.insert_column_no_name_check(index, column)
     }
 
+    /// Reorder the columns of this [`DataFrame`] in place. Every column must be given exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df: DataFrame = df!("a" => [1], "b" => [2], "c" => [3])?;
+    /// df.reorder_columns(["c", "a", "b"])?;
+    /// assert_eq!(df.get_column_names(), &["c", "a", "b"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn reorder_columns<I, S>(&mut self, names: I) -> PolarsResult<&mut Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let order = names
+            .into_iter()
+            .map(|name| self.check_name_to_idx(name.as_ref()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        self.reorder_columns_by_idx(&order)
+    }
+
+    /// Reorder the columns of this [`DataFrame`] in place, where `order[i]` is the current index
+    /// of the column that is moved to index `i`. Every column must be given exactly once.
+    pub fn reorder_columns_by_idx(&mut self, order: &[usize]) -> PolarsResult<&mut Self> {
+        let width = self.width();
+        polars_ensure!(
+            order.len() == width,
+            ShapeMismatch: "expected {} column indices to reorder a DataFrame with {} columns, got {}",
+            width, width, order.len(),
+        );
+        let mut seen = vec![false; width];
+        for &idx in order {
+            polars_ensure!(
+                idx < width,
+                OutOfBounds: "invalid column index: {} for a DataFrame with {} columns", idx, width
+            );
+            polars_ensure!(
+                !std::mem::replace(&mut seen[idx], true),
+                Duplicate: "column {:?} is given more than once", self.columns[idx].name()
+            );
+        }
+
+        let mut columns = std::mem::take(&mut self.columns)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        self.columns = order
+            .iter()
+            .map(|&idx| columns[idx].take().unwrap())
+            .collect();
+        self.clear_schema();
+        Ok(self)
+    }
+
+    /// Move the column at index `from` to index `to`, shifting the columns in between.
+    pub fn move_column(&mut self, from: usize, to: usize) -> PolarsResult<&mut Self> {
+        let width = self.width();
+        polars_ensure!(
+            from < width && to < width,
+            OutOfBounds: "unable to move column {} to {}, the DataFrame has only {} columns",
+            from, to, width,
+        );
+        let column = self.columns.remove(from);
+        self.columns.insert(to, column);
+        self.clear_schema();
+        Ok(self)
+    }
+
     fn add_column_by_search(&mut self, column: Column) -> PolarsResult<()> {
         if let Some(idx) = self.get_column_index(column.name().as_str()) {
             self.replace_column(idx, column)?;
@@ -1641,6 +1728,16 @@ impl DataFrame {
         self.columns.get(idx)
     }
 
+    /// Get a mutable reference to a [`Column`] by index.
+    ///
+    /// # Safety
+    /// The caller must ensure the length of the column does not change and that its name stays
+    /// unique in the [`DataFrame`].
+    pub unsafe fn select_at_idx_mut(&mut self, idx: usize) -> Option<&mut Column> {
+        self.clear_schema();
+        self.columns.get_mut(idx)
+    }
+
     /// Select column(s) from this [`DataFrame`] by range and return a new [`DataFrame`]
     ///
     /// # Examples
@@ -2030,8 +2127,12 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(indices.len(), new_col) })
     }
 
+    /// Take rows at `idx` without bounds checks. Use [`DataFrame::take`] for a checked
+    /// version.
+    ///
     /// # Safety
-    /// The indices must be in-bounds.
+    /// Every non-null index must be smaller than `self.height()`. An out-of-bounds index is
+    /// undefined behavior.
     pub unsafe fn take_unchecked(&self, idx: &IdxCa) -> Self {
         self.take_unchecked_impl(idx, true)
     }
@@ -2047,8 +2148,11 @@ impl DataFrame {
         unsafe { DataFrame::new_no_checks(idx.len(), cols) }
     }
 
+    /// Take rows at `idx` without bounds checks.
+    ///
     /// # Safety
-    /// The indices must be in-bounds.
+    /// Every non-null index must be smaller than `self.height()`. An out-of-bounds index is
+    /// undefined behavior.
     pub unsafe fn take_slice_unchecked(&self, idx: &[IdxSize]) -> Self {
         self.take_slice_unchecked_impl(idx, true)
     }
@@ -2094,6 +2198,31 @@ impl DataFrame {
         Ok(self)
     }
 
+    /// Rename all columns in the [`DataFrame`] by applying `f` to every column name.
+    ///
+    /// The [`DataFrame`] is left untouched if the new names are not unique.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &mut DataFrame) -> PolarsResult<&mut DataFrame> {
+    ///     df.rename_all(|name| format!("{name}_right").into())
+    /// }
+    /// ```
+    pub fn rename_all<F>(&mut self, mut f: F) -> PolarsResult<&mut Self>
+    where
+        F: FnMut(&str) -> PlSmallStr,
+    {
+        let names = self
+            .columns
+            .iter()
+            .map(|c| f(c.name().as_str()))
+            .collect::<Vec<_>>();
+        self._set_column_names_impl(&names)?;
+        Ok(self)
+    }
+
     /// Sort [`DataFrame`] in place.
     ///
     /// See [`DataFrame::sort`] for more instruction.
@@ -2417,6 +2546,39 @@ impl DataFrame {
         Ok(self)
     }
 
+    /// Replace the column at index `idx` with a [`Column`] of the same data type, keeping the
+    /// name of the replaced column. The schema of the [`DataFrame`] does not change.
+    pub fn replace_column_with_schema_check<C: IntoColumn>(
+        &mut self,
+        index: usize,
+        new_column: C,
+    ) -> PolarsResult<&mut Self> {
+        polars_ensure!(
+            index < self.width(),
+            OutOfBounds:
+            "unable to replace at index {}, the DataFrame has only {} columns",
+            index, self.width(),
+        );
+        let mut new_column = new_column.into_column();
+        polars_ensure!(
+            new_column.len() == self.height(),
+            ShapeMismatch:
+            "unable to replace a column, series length {} doesn't match the DataFrame height {}",
+            new_column.len(), self.height(),
+        );
+        let old_col = &mut self.columns[index];
+        polars_ensure!(
+            new_column.dtype() == old_col.dtype(),
+            SchemaMismatch:
+            "unable to replace column {:?} of type {} with a column of type {}",
+            old_col.name(), old_col.dtype(), new_column.dtype(),
+        );
+        new_column.rename(old_col.name().clone());
+        *old_col = new_column;
+        self.unique_key = None;
+        Ok(self)
+    }
+
     /// Apply a closure to a column. This is the recommended way to do in place modification.
     ///
     /// # Example
@@ -2537,6 +2699,7 @@ impl DataFrame {
                 self.clear_schema();
             }
         }
+        self.unique_key = None;
         Ok(self)
     }
 
@@ -2595,6 +2758,7 @@ impl DataFrame {
         let name = col.name().clone();
 
         let _ = mem::replace(col, f(col).map(|c| c.into_column())?);
+        self.unique_key = None;
 
         // make sure the name remains the same after applying the closure
         unsafe {
@@ -2929,6 +3093,29 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(self.height(), col) })
     }
 
+    /// Cast the columns in `dtypes` to their mapped [`DataType`], in parallel over the columns.
+    ///
+    /// If `strict` is set, values that cannot be cast raise an error instead of becoming null.
+    /// The error of a failing cast names the column it occurred in.
+    pub fn cast(&self, dtypes: &PlHashMap<&str, DataType>, strict: bool) -> PolarsResult<Self> {
+        for name in dtypes.keys() {
+            self.try_get_column_index(name)?;
+        }
+        let columns = self.try_apply_columns_par(&|c| match dtypes.get(c.name().as_str()) {
+            Some(dtype) => cast_column(c, dtype, strict),
+            None => Ok(c.clone()),
+        })?;
+        Ok(unsafe { DataFrame::new_no_checks(self.height(), columns) })
+    }
+
+    /// Cast all columns to `dtype`, in parallel over the columns.
+    ///
+    /// See [`DataFrame::cast`] for the meaning of `strict`.
+    pub fn cast_all(&self, dtype: &DataType, strict: bool) -> PolarsResult<Self> {
+        let columns = self.try_apply_columns_par(&|c| cast_column(c, dtype, strict))?;
+        Ok(unsafe { DataFrame::new_no_checks(self.height(), columns) })
+    }
+
     /// Pipe different functions/ closure operations that work on a DataFrame together.
     pub fn pipe<F, B>(self, f: F) -> PolarsResult<B>
     where
@@ -2953,6 +3140,52 @@ impl DataFrame {
         f(self, args)
     }
 
+    /// Fold the columns of the [`DataFrame`] from left to right into a single [`Series`].
+    ///
+    /// The first column is the initial accumulator. Returns `None` if the [`DataFrame`] has no
+    /// columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => [1, 2],
+    ///                         "b" => [3, 4],
+    ///                         "c" => [5, 6])?;
+    ///
+    /// let sum = df.fold(|acc, s| &acc + s)?.unwrap();
+    /// assert!(sum.equals(&Series::new("a".into(), [9, 12])));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn fold<F>(&self, f: F) -> PolarsResult<Option<Series>>
+    where
+        F: FnMut(Series, &Series) -> PolarsResult<Series>,
+    {
+        let mut columns = self.materialized_column_iter();
+        let Some(acc) = columns.next() else {
+            return Ok(None);
+        };
+        columns.try_fold(acc.clone(), f).map(Some)
+    }
+
+    /// Reduce the columns of the [`DataFrame`] into a single [`Series`] in parallel.
+    ///
+    /// The columns are combined pairwise in a tree, so `f` must be associative. The order of the
+    /// columns is maintained, so `f` does not need to be commutative. Returns `None` if the
+    /// [`DataFrame`] has no columns.
+    pub fn reduce_par<F>(&self, f: F) -> PolarsResult<Option<Series>>
+    where
+        F: Fn(Series, &Series) -> PolarsResult<Series> + Send + Sync,
+    {
+        POOL.install(|| {
+            self.columns
+                .par_iter()
+                .map(|c| Ok(c.as_materialized_series().clone()))
+                .try_reduce_with(|acc, s| f(acc, &s))
+                .transpose()
+        })
+    }
+
     /// Drop duplicate rows from a [`DataFrame`].
     /// *This fails when there is a column of type List in DataFrame*
     ///
@@ -3026,6 +3259,18 @@ impl DataFrame {
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<Self> {
         let names = subset.unwrap_or_else(|| self.get_column_names_owned());
+        for name in &names {
+            self.try_get_column_index(name)?;
+        }
+
+        // Every row is already distinct on a superset of the unique key.
+        if self.covers_unique_key(&names) {
+            return Ok(match slice {
+                Some((offset, len)) => self.slice(offset, len),
+                None => self.clone(),
+            });
+        }
+
         let mut df = self.clone();
         // take on multiple chunks is terrible
         df.as_single_chunk_par();
@@ -3470,8 +3715,19 @@ fn ensure_can_extend(left: &Column, right: &Column) -> PolarsResult<()> {
     Ok(())
 }
 
+fn cast_column(c: &Column, dtype: &DataType, strict: bool) -> PolarsResult<Column> {
+    if strict {
+        c.strict_cast(dtype)
+    } else {
+        c.cast(dtype)
+    }
+    .map_err(|e| e.context(format!("failed to cast column '{}'", c.name()).into()))
+}
+
 #[cfg(test)]
 mod test {
+    use polars_utils::format_pl_smallstr;
+
     use super::*;
 
     fn create_frame() -> DataFrame {
@@ -3492,6 +3748,71 @@ mod test {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_cast() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2],
+            "b" => ["1", "x"],
+            "c" => [1.5, 2.5]
+        )?;
+        let dtypes = PlHashMap::from_iter([("a", DataType::Float64), ("b", DataType::Int64)]);
+        let out = df.cast(&dtypes, false)?;
+        assert_eq!(
+            out.dtypes(),
+            &[DataType::Float64, DataType::Int64, DataType::Float64]
+        );
+        assert_eq!(Vec::from(out.column("b")?.i64()?), &[Some(1), None]);
+
+        let err = df.cast(&dtypes, true).unwrap_err();
+        assert!(err.to_string().contains("failed to cast column 'b'"));
+        let dtypes = PlHashMap::from_iter([("d", DataType::Int64)]);
+        assert!(df.cast(&dtypes, false).is_err());
+
+        let out = df.cast_all(&DataType::String, true)?;
+        assert!(out.dtypes().iter().all(|dt| dt == &DataType::String));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_all() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [1, 2],
+            "B" => [3, 4]
+        )?;
+        df.rename_all(|name| format!("x_{name}").into())?;
+        assert_eq!(df.get_column_names(), &["x_a", "x_B"]);
+
+        df.rename_all(|name| name.to_lowercase().into())?;
+        assert!(df.rename_all(|_| "y".into()).is_err());
+        assert_eq!(df.get_column_names(), &["x_a", "x_b"]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_fold_reduce() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [10, 20],
+            "b" => [1, 2],
+            "c" => [3, 4]
+        )?;
+        let out = df.fold(|acc, s| &acc - s)?.unwrap();
+        assert!(out.equals(&Series::new("a".into(), [6, 14])));
+
+        let columns = (0..100)
+            .map(|i| Column::new(format_pl_smallstr!("{i}"), [i, 2 * i]))
+            .collect::<Vec<_>>();
+        let df = DataFrame::new(columns)?;
+        let out = df.reduce_par(|acc, s| &acc + s)?.unwrap();
+        assert!(out.equals(&df.fold(|acc, s| &acc + s)?.unwrap()));
+        assert!(out.equals(&Series::new("0".into(), [4950, 9900])));
+
+        assert!(DataFrame::empty().fold(|acc, s| &acc + s)?.is_none());
+        assert!(DataFrame::empty().reduce_par(|acc, s| &acc + s)?.is_none());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_select() {
@@ -3650,6 +3971,50 @@ mod test {
         assert_eq!(df.height, 6)
     }
 
+    #[test]
+    fn test_reorder_and_move_columns() -> PolarsResult<()> {
+        let mut df = df!("a" => [1], "b" => [2], "c" => [3])?;
+
+        df.reorder_columns(["b", "c", "a"])?;
+        assert_eq!(df.get_column_names(), &["b", "c", "a"]);
+        assert_eq!(df.schema().index_of("a"), Some(2));
+
+        df.move_column(2, 0)?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        df.move_column(0, 2)?;
+        assert_eq!(df.get_column_names(), &["b", "c", "a"]);
+
+        assert!(df.reorder_columns(["a", "b"]).is_err());
+        assert!(df.reorder_columns_by_idx(&[0, 0, 1]).is_err());
+        assert!(df.reorder_columns_by_idx(&[0, 1, 3]).is_err());
+        assert!(df.move_column(0, 3).is_err());
+        assert_eq!(df.get_column_names(), &["b", "c", "a"]);
+
+        let c = unsafe { df.select_at_idx_mut(0) }.unwrap();
+        c.rename("d".into());
+        assert_eq!(df.get_column_names(), &["d", "c", "a"]);
+        assert_eq!(df.schema().index_of("d"), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_column_with_schema_check() -> PolarsResult<()> {
+        let mut df = df!("a" => [1, 2], "b" => ["x", "y"])?;
+        let schema = df.schema().clone();
+
+        df.replace_column_with_schema_check(0, Column::new("c".into(), [3, 4]))?;
+        assert_eq!(df.schema(), &schema);
+        assert_eq!(df.column("a")?, &Column::new("a".into(), [3, 4]));
+
+        let err = df.replace_column_with_schema_check(1, Column::new("b".into(), [1, 2]));
+        assert!(matches!(err, Err(PolarsError::SchemaMismatch(_))));
+        let err = df.replace_column_with_schema_check(0, Column::new("a".into(), [1]));
+        assert!(matches!(err, Err(PolarsError::ShapeMismatch(_))));
+        let err = df.replace_column_with_schema_check(2, Column::new("a".into(), [1, 2]));
+        assert!(matches!(err, Err(PolarsError::OutOfBounds(_))));
+        Ok(())
+    }
+
     #[test]
     fn test_replace_or_add() -> PolarsResult<()> {
         let mut df = df!(
@@ -3684,6 +4049,120 @@ mod test {
         assert!(out.equals(&expected));
     }
 
+    #[test]
+    fn test_create_index() -> PolarsResult<()> {
+        let df = df! {
+            "k" => [Some("a"), Some("b"), None, Some("a"), Some("c")],
+            "v" => [0, 1, 2, 3, 4]
+        }?;
+        let index = df.create_index("k")?;
+
+        let v = |df: DataFrame| -> PolarsResult<Vec<Option<i32>>> {
+            Ok(Vec::from(df.column("v")?.i32()?))
+        };
+        assert_eq!(v(index.lookup("a".into())?)?, [Some(0), Some(3)]);
+        assert_eq!(v(index.lookup("z".into())?)?, []);
+        assert_eq!(v(index.lookup(AnyValue::Null)?)?, []);
+
+        let values = Series::new("values".into(), [Some("c"), None, Some("a"), Some("c")]);
+        assert_eq!(
+            v(index.lookup_many(&values)?)?,
+            [Some(4), Some(0), Some(3), Some(4)]
+        );
+
+        // Lookup values are cast to the type of the indexed column.
+        let index = df.create_index("v")?;
+        assert_eq!(index.lookup_idx(AnyValue::Int64(2))?, &[2]);
+        assert!(index.lookup_idx("x".into()).is_err());
+
+        // Floats are keyed on their row encoding, so -0.0 equals 0.0 and NaNs are equal.
+        let df = df! {
+            "f" => [0.0, f64::NAN, -0.0, 1.5]
+        }?;
+        let index = df.create_index("f")?;
+        assert_eq!(index.lookup_idx(AnyValue::Float64(-0.0))?, &[0, 2]);
+        assert_eq!(index.lookup_idx(AnyValue::Float64(f64::NAN))?, &[1]);
+        assert_eq!(index.lookup_idx(AnyValue::Int32(0))?, &[0, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_unchecked_matches_take() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => ["x", "y", "z"]
+        }?;
+        let idx = IdxCa::new("idx".into(), [Some(2 as IdxSize), None, Some(0)]);
+        // SAFETY: all indices are smaller than the height of `df`.
+        let unchecked = unsafe { df.take_unchecked(&idx) };
+        assert!(unchecked.equals_missing(&df.take(&idx)?));
+
+        let s = df.column("a")?.as_materialized_series();
+        // SAFETY: all indices are smaller than the length of `s`.
+        unsafe {
+            assert_eq!(s.get_unchecked(2), s.get(2)?);
+            assert!(
+                s.take_slice_unchecked(&[2, 1])
+                    .equals_missing(&s.take_slice(&[2, 1])?)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "partition_by")]
+    fn test_partition_by_stable() -> PolarsResult<()> {
+        let df = df! {
+            "key1" => ["b", "a", "b", "a", "b"],
+            "key2" => [1, 1, 1, 2, 1],
+            "val" => [1, 2, 3, 4, 5]
+        }?;
+        let parts = df.partition_by_stable(["key1", "key2"], true)?;
+        assert_eq!(parts.len(), 3);
+        assert!(parts[0].equals(&df! {
+            "key1" => ["b", "b", "b"],
+            "key2" => [1, 1, 1],
+            "val" => [1, 3, 5]
+        }?));
+        assert!(parts[1].equals(&df! {"key1" => ["a"], "key2" => [1], "val" => [2]}?));
+        assert!(parts[2].equals(&df! {"key1" => ["a"], "key2" => [2], "val" => [4]}?));
+
+        let parts = df.partition_by(["key1", "key2"], false)?;
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|p| p.get_column_names() == ["val"]));
+        assert_eq!(parts.iter().map(|p| p.height()).sum::<usize>(), df.height());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_key() -> PolarsResult<()> {
+        let df = df! {
+            "id" => [1, 2, 3],
+            "x" => [1, 1, 2]
+        }?;
+
+        assert!(df.clone().with_unique_key(["x"]).is_err());
+        let mut df = df.with_unique_key(["id"])?;
+        assert_eq!(df.unique_key(), Some(&["id".into()][..]));
+        df.verify_integrity()?;
+
+        let out = df.unique_stable(None, UniqueKeepStrategy::First, None)?;
+        assert!(out.equals(&df));
+        let err = df
+            .unique_stable(
+                Some(&["id".to_string(), "does_not_exist".to_string()]),
+                UniqueKeepStrategy::First,
+                None,
+            )
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+
+        // Appending rows may break the key, so it is dropped.
+        df.vstack_mut(&df.clone())?;
+        assert!(df.unique_key().is_none());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-i8")]
     fn test_apply_result_schema() {
@@ -308,4 +308,24 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_keep_names_supertype() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2],
+            "b" => [1.5f64, 2.5],
+        ]?;
+
+        let out = df.transpose(Some("header"), None)?;
+        let expected = df![
+            "header" => ["a", "b"],
+            "column_0" => [1.0f64, 1.5],
+            "column_1" => [2.0f64, 2.5],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        // The header name may not clash with the generated column names.
+        assert!(df.transpose(Some("column_0"), None).is_err());
+        Ok(())
+    }
 }
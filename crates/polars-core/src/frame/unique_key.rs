@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::DataFrame;
+
+impl DataFrame {
+    /// The columns that are marked as a unique key of this [`DataFrame`], if any.
+    ///
+    /// A unique key guarantees that no two rows have equal values in all of the key columns. It
+    /// is set with [`DataFrame::with_unique_key`] and dropped by any operation that may break that
+    /// guarantee, such as appending rows or modifying columns in place.
+    pub fn unique_key(&self) -> Option<&[PlSmallStr]> {
+        self.unique_key.as_deref()
+    }
+
+    /// Mark `key` as a unique key of this [`DataFrame`] after verifying that it holds.
+    ///
+    /// Joins skip the uniqueness validation on a side whose join keys cover the unique key, and
+    /// `unique` returns the frame as-is when its subset covers the unique key.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn with_unique_key<I, S>(mut self, key: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.unique_key = Some(key.into_iter().map(Into::into).collect());
+        self.verify_integrity()?;
+        Ok(self)
+    }
+
+    /// Mark `key` as a unique key of this [`DataFrame`] without verifying that it holds.
+    ///
+    /// # Safety
+    /// The key columns must exist and no two rows may have equal values in all of them.
+    pub unsafe fn with_unique_key_unchecked(mut self, key: Arc<[PlSmallStr]>) -> Self {
+        self.unique_key = Some(key);
+        self
+    }
+
+    /// Drop the unique key of this [`DataFrame`].
+    pub fn clear_unique_key(&mut self) {
+        self.unique_key = None;
+    }
+
+    /// Check that the unique key of this [`DataFrame`] holds. Succeeds if no key is set.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn verify_integrity(&self) -> PolarsResult<()> {
+        let Some(key) = &self.unique_key else {
+            return Ok(());
+        };
+        polars_ensure!(
+            !key.is_empty(),
+            InvalidOperation: "a unique key must consist of at least one column"
+        );
+        let gb = self.group_by(key.iter().cloned())?;
+        let n_groups = gb.get_groups().len();
+        polars_ensure!(
+            n_groups == self.height(),
+            ComputeError:
+            "unique key {:?} is violated: found {} duplicate row(s)",
+            key, self.height() - n_groups
+        );
+        Ok(())
+    }
+
+    /// Whether `columns` cover the unique key, in which case their combined values are unique.
+    pub fn covers_unique_key(&self, columns: &[PlSmallStr]) -> bool {
+        self.unique_key
+            .as_ref()
+            .is_some_and(|key| key.iter().all(|k| columns.contains(k)))
+    }
+}
@@ -19,6 +19,7 @@ pub mod fmt;
 pub mod frame;
 pub mod functions;
 pub mod hashing;
+pub mod memory;
 mod named_from;
 pub mod prelude;
 #[cfg(feature = "random")]
@@ -28,6 +29,8 @@ pub mod schema;
 #[cfg(feature = "serde")]
 pub mod serde;
 pub mod series;
+#[cfg(feature = "dtype-categorical")]
+pub mod string_cache;
 pub mod testing;
 #[cfg(test)]
 mod tests;
@@ -51,15 +54,7 @@ pub static PROCESS_ID: LazyLock<u128> = LazyLock::new(|| {
 pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
     ThreadPoolBuilder::new()
-        .num_threads(
-            std::env::var("POLARS_MAX_THREADS")
-                .map(|s| s.parse::<usize>().expect("integer"))
-                .unwrap_or_else(|_| {
-                    std::thread::available_parallelism()
-                        .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
-                        .get()
-                }),
-        )
+        .num_threads(config::get_max_threads())
         .thread_name(move |i| format!("{thread_name}-{i}"))
         .build()
         .expect("could not spawn threads")
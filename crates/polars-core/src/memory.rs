@@ -0,0 +1,120 @@
+//! Tracking of the memory that is allocated by the process.
+//!
+//! Memory is only tracked if [`TrackingAllocator`] is installed as the global allocator:
+//!
+//! ```rust
+//! use polars_core::memory::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOC: TrackingAllocator = TrackingAllocator::new(std::alloc::System);
+//! ```
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use polars_error::{PolarsResult, polars_bail};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static TRACKING: AtomicBool = AtomicBool::new(false);
+
+/// A [`GlobalAlloc`] that keeps track of the number of allocated bytes and forwards the
+/// allocations to `A`.
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl<A> TrackingAllocator<A> {
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+#[inline]
+fn track_alloc(size: usize) {
+    if !TRACKING.load(Ordering::Relaxed) {
+        TRACKING.store(true, Ordering::Relaxed);
+    }
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+}
+
+#[inline]
+fn track_dealloc(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.inner.alloc(layout) };
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { self.inner.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.inner.dealloc(ptr, layout) };
+        track_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { self.inner.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            track_dealloc(layout.size());
+            track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The number of bytes that are currently allocated, or `None` if no [`TrackingAllocator`] is
+/// installed.
+pub fn allocated_bytes() -> Option<usize> {
+    TRACKING
+        .load(Ordering::Relaxed)
+        .then(|| ALLOCATED.load(Ordering::Relaxed))
+}
+
+/// Raise an error if more than `limit` bytes are allocated.
+pub fn check_memory_limit(limit: usize) -> PolarsResult<()> {
+    let Some(allocated) = allocated_bytes() else {
+        polars_bail!(
+            ComputeError:
+            "a memory limit was set, but memory is not tracked; install `polars_core::memory::TrackingAllocator` as the global allocator"
+        )
+    };
+    if allocated > limit {
+        polars_bail!(
+            ComputeError:
+            "memory limit of {limit} bytes exceeded: {allocated} bytes are allocated"
+        )
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tracking_allocator() {
+        let alloc = TrackingAllocator::new(System);
+        let layout = Layout::from_size_align(1 << 20, 8).unwrap();
+        unsafe {
+            let ptr = alloc.alloc(layout);
+            assert!(allocated_bytes().unwrap() >= 1 << 20);
+            assert!(check_memory_limit(1 << 19).is_err());
+
+            let ptr = alloc.realloc(ptr, layout, 1 << 21);
+            assert!(allocated_bytes().unwrap() >= 1 << 21);
+            alloc.dealloc(ptr, Layout::from_size_align(1 << 21, 8).unwrap());
+        }
+        assert!(check_memory_limit(1 << 19).is_ok());
+    }
+}
@@ -4,7 +4,7 @@ use arrow::bitmap::Bitmap;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::prelude::*;
-use crate::utils::try_get_supertype;
+use crate::utils::{SuperTypeOptions, try_get_supertype, try_get_supertype_with_options};
 
 pub mod iceberg;
 
@@ -24,6 +24,14 @@ pub trait SchemaExt {
 
     fn to_supertype(&mut self, other: &Schema) -> PolarsResult<bool>;
 
+    fn try_merge(&mut self, other: &Schema) -> PolarsResult<()>;
+
+    fn try_merge_with_options(
+        &mut self,
+        other: &Schema,
+        options: SuperTypeOptions,
+    ) -> PolarsResult<()>;
+
     /// Select fields using a bitmap.
     fn project_select(&self, select: &Bitmap) -> Self;
 }
@@ -95,6 +103,42 @@ impl SchemaExt for Schema {
         Ok(changed)
     }
 
+    /// Merge `other` into `self`, where fields that occur in both get the supertype of their
+    /// dtypes and keep their original index.
+    ///
+    /// Fields that occur in `other` but not `self` are appended, in order, to the end of `self`.
+    fn try_merge(&mut self, other: &Schema) -> PolarsResult<()> {
+        self.try_merge_with_options(other, SuperTypeOptions::default())
+    }
+
+    /// Same as [`SchemaExt::try_merge`], but resolves the dtypes of shared fields with the given
+    /// supertype `options`.
+    fn try_merge_with_options(
+        &mut self,
+        other: &Schema,
+        options: SuperTypeOptions,
+    ) -> PolarsResult<()> {
+        for (name, other_dtype) in other.iter() {
+            match self.get_mut(name) {
+                Some(dtype) => {
+                    let st = try_get_supertype_with_options(dtype, other_dtype, options).map_err(
+                        |_| {
+                            polars_err!(
+                                SchemaMismatch: "cannot merge field {:?} of type {} with type {}",
+                                name, dtype, other_dtype
+                            )
+                        },
+                    )?;
+                    *dtype = st;
+                },
+                None => {
+                    self.with_column(name.clone(), other_dtype.clone());
+                },
+            }
+        }
+        Ok(())
+    }
+
     fn project_select(&self, select: &Bitmap) -> Self {
         assert_eq!(self.len(), select.len());
         self.iter()
@@ -79,6 +79,41 @@ mod test {
         assert!(df.equals_missing(&out));
     }
 
+    #[test]
+    #[cfg(all(
+        feature = "dtype-categorical",
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration"
+    ))]
+    fn test_serde_df_bincode_dtypes() -> PolarsResult<()> {
+        use polars_utils::pl_serialize;
+
+        let mut df = sample_dataframe();
+        let cats = Series::new("cat".into(), &[Some("b"), None, Some("a")])
+            .cast(&DataType::from_categories(Categories::global()))?;
+        let date =
+            Series::new("date".into(), &[Some(1), None, Some(19000)]).cast(&DataType::Date)?;
+        let datetime = Series::new("datetime".into(), &[Some(1i64), Some(2), None]).cast(
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC)),
+        )?;
+        let duration = Series::new("duration".into(), &[Some(1i64), None, Some(3)])
+            .cast(&DataType::Duration(TimeUnit::Microseconds))?;
+        df.hstack_mut(&[cats.into(), date.into(), datetime.into(), duration.into()])?;
+        df.vstack_mut(&df.clone())?;
+
+        let bytes = pl_serialize::serialize_to_bytes::<_, false>(&df)?;
+        let out: DataFrame =
+            pl_serialize::deserialize_from_reader::<_, _, false>(bytes.as_slice())?;
+        assert_eq!(out.schema(), df.schema());
+        assert!(df.equals_missing(&out));
+        assert_eq!(
+            out.column("cat")?.cat32()?.iter_str().collect::<Vec<_>>(),
+            [Some("b"), None, Some("a"), Some("b"), None, Some("a")]
+        );
+        Ok(())
+    }
+
     // STRUCT REFACTOR
     #[ignore]
     #[test]
@@ -954,6 +954,43 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+    fn test_temporal_arithmetic() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [Some(3_000i64), Some(5_000), None])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let b = Series::new("b".into(), [1_000_000i64, 1_000_000, 1_000_000])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+
+        // Datetime - Datetime -> Duration in the coarsest time unit.
+        let diff = (&a - &b)?;
+        assert_eq!(diff.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            Vec::from(diff.duration()?.physical()),
+            [Some(2_000), Some(4_000), None]
+        );
+
+        // Datetime +/- Duration -> Datetime.
+        let shifted = (&b + &diff)?;
+        assert_eq!(
+            shifted.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(
+            Vec::from(shifted.datetime()?.physical()),
+            [Some(3_000), Some(5_000), None]
+        );
+        let back = (&shifted - &diff)?;
+        assert_eq!(
+            Vec::from(back.datetime()?.physical()),
+            [Some(1_000), Some(1_000), None]
+        );
+
+        // Duration + Datetime commutes.
+        assert!((&diff + &b)?.equals_missing(&shifted));
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "checked_arithmetic")]
     fn test_checked_div() {
@@ -55,6 +55,8 @@ impl Series {
     /// # Safety
     ///
     /// The caller must ensure that the given `dtype`'s physical type matches all the `ArrayRef` dtypes.
+    /// For logical types the values must also be valid for `dtype`, e.g. categories must exist in
+    /// the mapping. Use [`Series::from_chunk_and_dtype`] for a checked version.
     pub unsafe fn from_chunks_and_dtype_unchecked(
         name: PlSmallStr,
         chunks: Vec<ArrayRef>,
@@ -108,6 +108,29 @@ macro_rules! impl_cat_series {
                 list.into_series()
             }
 
+            #[cfg(feature = "algorithm_group_by")]
+            unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+                // Categoricals are ordered by their string value, enums by their physical value.
+                if self.0.uses_lexical_ordering() {
+                    let s = self.0.cast(&DataType::String).unwrap();
+                    s.agg_min(groups).cast(self.dtype()).unwrap()
+                } else {
+                    self.apply_on_phys(|cats| cats.agg_min(groups).as_ref().as_ref().clone())
+                        .into_series()
+                }
+            }
+
+            #[cfg(feature = "algorithm_group_by")]
+            unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+                if self.0.uses_lexical_ordering() {
+                    let s = self.0.cast(&DataType::String).unwrap();
+                    s.agg_max(groups).cast(self.dtype()).unwrap()
+                } else {
+                    self.apply_on_phys(|cats| cats.agg_max(groups).as_ref().as_ref().clone())
+                        .into_series()
+                }
+            }
+
             #[cfg(feature = "algorithm_group_by")]
             fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
                 self.0.physical().group_tuples(multithreaded, sorted)
@@ -391,6 +391,19 @@ impl SeriesTrait for SeriesWrap<DateChunked> {
         ))
     }
 
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let av: AnyValue = self
+            .0
+            .physical()
+            .quantile(quantile, method)?
+            .map(|v| (v * (MS_IN_DAY as f64)) as i64)
+            .into();
+        Ok(Scalar::new(
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            av,
+        ))
+    }
+
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
         Arc::new(SeriesWrap(Clone::clone(&self.0)))
     }
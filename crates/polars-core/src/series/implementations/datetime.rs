@@ -377,8 +377,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         Ok(Scalar::new(self.dtype().clone(), av))
     }
 
-    fn quantile_reduce(&self, _quantile: f64, _method: QuantileMethod) -> PolarsResult<Scalar> {
-        Ok(Scalar::new(self.dtype().clone(), AnyValue::Null))
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let sc = self.0.physical().quantile_reduce(quantile, method)?;
+        let av = sc.value().cast(&DataType::Int64).into_static();
+        Ok(Scalar::new(self.dtype().clone(), av))
     }
 
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
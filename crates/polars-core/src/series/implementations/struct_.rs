@@ -1,5 +1,3 @@
-use std::ops::Not;
-
 use arrow::bitmap::Bitmap;
 
 use super::*;
@@ -235,25 +233,11 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
     }
 
     fn is_null(&self) -> BooleanChunked {
-        let iter = self.downcast_iter().map(|arr| {
-            let bitmap = match arr.validity() {
-                Some(valid) => valid.not(),
-                None => Bitmap::new_with_value(false, arr.len()),
-            };
-            BooleanArray::from_data_default(bitmap, None)
-        });
-        BooleanChunked::from_chunk_iter(self.name().clone(), iter)
+        self.0.is_null()
     }
 
     fn is_not_null(&self) -> BooleanChunked {
-        let iter = self.downcast_iter().map(|arr| {
-            let bitmap = match arr.validity() {
-                Some(valid) => valid.clone(),
-                None => Bitmap::new_with_value(true, arr.len()),
-            };
-            BooleanArray::from_data_default(bitmap, None)
-        });
-        BooleanChunked::from_chunk_iter(self.name().clone(), iter)
+        self.0.is_not_null()
     }
 
     fn reverse(&self) -> Series {
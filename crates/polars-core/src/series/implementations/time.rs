@@ -360,6 +360,16 @@ impl SeriesTrait for SeriesWrap<TimeChunked> {
         Ok(Scalar::new(self.dtype().clone(), av))
     }
 
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let sc = self.0.physical().quantile_reduce(quantile, method)?;
+        let av = sc
+            .value()
+            .cast(&DataType::Int64)
+            .cast(self.dtype())
+            .into_static();
+        Ok(Scalar::new(self.dtype().clone(), av))
+    }
+
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
         Arc::new(SeriesWrap(Clone::clone(&self.0)))
     }
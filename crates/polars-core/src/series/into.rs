@@ -16,6 +16,17 @@ impl Series {
         &self.chunks()[chunk_idx] as &ArrayRef
     }
 
+    /// Convert all chunks to their Arrow type, together with the Arrow field that describes them.
+    ///
+    /// This is the inverse of [`Series::from_arrow_field`].
+    pub fn to_arrow_chunks(&self, compat_level: CompatLevel) -> (ArrowField, Vec<ArrayRef>) {
+        let field = self.field().to_arrow(compat_level);
+        let chunks = (0..self.n_chunks())
+            .map(|i| self.to_arrow(i, compat_level))
+            .collect();
+        (field, chunks)
+    }
+
     /// Convert a chunk in the Series to the correct Arrow type.
     /// This conversion is needed because polars doesn't use a
     /// 1 on 1 mapping for logical/categoricals, etc.
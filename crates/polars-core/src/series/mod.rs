@@ -31,6 +31,7 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
+use arrow::bitmap::Bitmap;
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::offset::Offsets;
 pub use from::*;
@@ -245,6 +246,49 @@ impl Series {
         new
     }
 
+    /// The validity bitmaps of the chunks, without copying. `None` means that the chunk has no
+    /// nulls.
+    pub fn null_bitmaps(&self) -> impl Iterator<Item = Option<&Bitmap>> {
+        self.chunks().iter().map(|arr| arr.validity())
+    }
+
+    /// Count the nulls in the `length` values starting at `offset`, using only the validity
+    /// bitmaps.
+    ///
+    /// # Panics
+    /// Panics if `offset + length` is larger than the length of the Series.
+    pub fn null_count_in_range(&self, offset: usize, length: usize) -> usize {
+        assert!(
+            offset + length <= self.len(),
+            "range {offset}..{} out of bounds for length {}",
+            offset + length,
+            self.len()
+        );
+        if !self.has_nulls() {
+            return 0;
+        }
+
+        let mut offset = offset;
+        let mut remaining = length;
+        let mut null_count = 0;
+        for arr in self.chunks() {
+            if remaining == 0 {
+                break;
+            }
+            if offset >= arr.len() {
+                offset -= arr.len();
+                continue;
+            }
+            let n = remaining.min(arr.len() - offset);
+            if let Some(validity) = arr.validity() {
+                null_count += validity.null_count_range(offset, n);
+            }
+            offset = 0;
+            remaining -= n;
+        }
+        null_count
+    }
+
     pub fn is_sorted_flag(&self) -> IsSorted {
         if self.len() <= 1 {
             return IsSorted::Ascending;
@@ -294,16 +338,40 @@ impl Series {
         Self::try_from((name, array))
     }
 
+    /// Create a [`Series`] from arrow chunks described by `field`.
+    ///
+    /// The field metadata is used to restore logical types that arrow can't express on its own.
+    /// Errors if a chunk's arrow type differs from the field's.
+    pub fn from_arrow_field(field: &ArrowField, arrays: Vec<ArrayRef>) -> PolarsResult<Series> {
+        for arr in &arrays {
+            polars_ensure!(
+                arr.dtype() == field.dtype(),
+                SchemaMismatch: "cannot create series from arrow array of type {:?} with field of type {:?}",
+                arr.dtype(), field.dtype()
+            );
+        }
+        Self::try_from((field, arrays))
+    }
+
     /// Shrink the capacity of this array to fit its length.
     pub fn shrink_to_fit(&mut self) {
         self._get_inner_mut().shrink_to_fit()
     }
 
+    /// Whether a [`Series`] of `dtype` must be cast to the [`DataType`] of this [`Series`] before
+    /// it can be appended.
+    ///
+    /// (Nested) Categoricals with different [`Categories`] are remapped to the categories of
+    /// this [`Series`].
+    fn must_cast_to_append(&self, dtype: &DataType) -> PolarsResult<bool> {
+        dtype.matches_schema_type_remap_categories(self.dtype())
+    }
+
     /// Append in place. This is done by adding the chunks of `other` to this [`Series`].
     ///
     /// See [`ChunkedArray::append`] and [`ChunkedArray::extend`].
     pub fn append(&mut self, other: &Series) -> PolarsResult<&mut Self> {
-        let must_cast = other.dtype().matches_schema_type(self.dtype())?;
+        let must_cast = self.must_cast_to_append(other.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
             self.append_owned(other)?;
@@ -317,7 +385,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::append_owned`] and [`ChunkedArray::extend`].
     pub fn append_owned(&mut self, other: Series) -> PolarsResult<&mut Self> {
-        let must_cast = other.dtype().matches_schema_type(self.dtype())?;
+        let must_cast = self.must_cast_to_append(other.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
             self._get_inner_mut().append_owned(other)?;
@@ -336,7 +404,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::extend`] and [`ChunkedArray::append`].
     pub fn extend(&mut self, other: &Series) -> PolarsResult<&mut Self> {
-        let must_cast = other.dtype().matches_schema_type(self.dtype())?;
+        let must_cast = self.must_cast_to_append(other.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
             self._get_inner_mut().extend(&other)?;
@@ -1089,6 +1157,24 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "dtype-time")]
+    fn cast_string_to_time() {
+        let s = Series::new("a".into(), ["12:30:05.5", "08:15", "x", "25:00:00"]);
+        let out = s.cast(&DataType::Time).unwrap();
+        assert_eq!(
+            Vec::from(out.time().unwrap().physical()),
+            &[
+                Some(45_005_500_000_000),
+                Some(29_700_000_000_000),
+                None,
+                None
+            ]
+        );
+        assert!(s.strict_cast(&DataType::Time).is_err());
+        assert!(s.head(Some(2)).strict_cast(&DataType::Time).is_ok());
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series".into(), &vec![true, false, true]);
@@ -1190,4 +1276,74 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    fn arrow_field_round_trip() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(1i64), None, Some(3)]);
+        s.append(&Series::new("a".into(), [4i64]))?;
+        #[cfg(feature = "dtype-datetime")]
+        let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+
+        let (field, chunks) = s.to_arrow_chunks(CompatLevel::newest());
+        assert_eq!(chunks.len(), 2);
+        let out = Series::from_arrow_field(&field, chunks)?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+
+        let other = Series::new("a".into(), ["x"]).to_arrow(0, CompatLevel::newest());
+        assert!(Series::from_arrow_field(&field, vec![other]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn null_count_in_range() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(1), None, Some(3), None]);
+        s.append(&Series::new("a".into(), [4, 5]))?;
+        s.append(&Series::new("a".into(), [None, Some(7)]))?;
+        assert_eq!(s.null_bitmaps().filter(|v| v.is_some()).count(), 2);
+
+        for offset in 0..s.len() {
+            for length in 0..=s.len() - offset {
+                assert_eq!(
+                    s.null_count_in_range(offset, length),
+                    s.slice(offset as i64, length).null_count()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn is_null_from_validity() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(1), None, Some(3)]);
+        s.append(&Series::new("a".into(), [4, 5]))?;
+
+        let is_not_null = s.is_not_null();
+        let is_null = s.is_null();
+        assert_eq!(
+            Vec::from(&is_null),
+            &[
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false)
+            ]
+        );
+        assert_eq!(is_not_null.sum(), Some(4));
+
+        // The validity of the first chunk is reused and the count of its inverse is known.
+        let validity = s.chunks()[0].validity().unwrap();
+        let values = is_not_null.downcast_get(0).unwrap().values();
+        assert_eq!(values.as_slice().0.as_ptr(), validity.as_slice().0.as_ptr());
+        let inverted = is_null.downcast_get(0).unwrap().values();
+        assert_eq!(inverted.lazy_set_bits(), Some(1));
+        // The chunk without nulls shares the static zeroed buffer.
+        let zeroed = is_null.downcast_get(1).unwrap().values();
+        assert_eq!(
+            zeroed.as_slice().0.as_ptr(),
+            Bitmap::new_zeroed(2).as_slice().0.as_ptr()
+        );
+        Ok(())
+    }
 }
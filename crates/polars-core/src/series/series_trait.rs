@@ -282,7 +282,9 @@ pub trait SeriesTrait:
     /// Null values in `idx` because null values in the output array.
     ///
     /// # Safety
-    /// This doesn't check any bounds.
+    /// Every non-null index in `idx` must be smaller than `self.len()`. This is not checked, and
+    /// an out-of-bounds index is undefined behavior. Use [`SeriesTrait::take`] for a checked
+    /// version.
     unsafe fn take_unchecked(&self, _idx: &IdxCa) -> Series;
 
     /// Take from `self` at the indexes given by `idx`.
@@ -293,7 +295,9 @@ pub trait SeriesTrait:
     /// Take from `self` at the indexes given by `idx`.
     ///
     /// # Safety
-    /// This doesn't check any bounds.
+    /// Every index in `idx` must be smaller than `self.len()`. This is not checked, and an
+    /// out-of-bounds index is undefined behavior. Use [`SeriesTrait::take_slice`] for a checked
+    /// version.
     unsafe fn take_slice_unchecked(&self, _idx: &[IdxSize]) -> Series;
 
     /// Get length of series.
@@ -413,7 +417,8 @@ pub trait SeriesTrait:
     /// This may refer to physical types
     ///
     /// # Safety
-    /// Does not do any bounds checking
+    /// `index` must be smaller than `self.len()`. This is not checked, and an out-of-bounds
+    /// index is undefined behavior. Use [`SeriesTrait::get`] for a checked version.
     unsafe fn get_unchecked(&self, _index: usize) -> AnyValue<'_>;
 
     fn sort_with(&self, _options: SortOptions) -> PolarsResult<Series> {
@@ -0,0 +1,49 @@
+//! Scoped control over how categoricals with different [`Categories`] are combined.
+//!
+//! Categoricals carry their [`Categories`] in their dtype. While the string cache is enabled,
+//! which is the default, a categorical that is appended to a categorical with other
+//! [`Categories`] is re-encoded in the categories of the left-hand side, see
+//! [`CategoricalChunked::merge_categories`]. With the string cache disabled this raises a
+//! `StringCacheMismatch` error instead.
+//!
+//! ```rust
+//! use polars_core::string_cache::{using_string_cache, with_string_cache};
+//!
+//! {
+//!     let _guard = with_string_cache(false);
+//!     assert!(!using_string_cache());
+//! }
+//! assert!(using_string_cache());
+//! ```
+//!
+//! [`Categories`]: crate::datatypes::Categories
+//! [`CategoricalChunked::merge_categories`]: crate::chunked_array::logical::CategoricalChunked::merge_categories
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static STRING_CACHE: AtomicBool = AtomicBool::new(true);
+
+/// Whether the string cache is enabled.
+pub fn using_string_cache() -> bool {
+    STRING_CACHE.load(Ordering::Relaxed)
+}
+
+/// Enable or disable the string cache until the returned guard is dropped.
+///
+/// The setting is process-wide. Dropping the guard restores the setting that was active when it
+/// was created, so nested guards must be dropped in reverse order.
+pub fn with_string_cache(enable: bool) -> StringCacheGuard {
+    let previous = STRING_CACHE.swap(enable, Ordering::Relaxed);
+    StringCacheGuard { previous }
+}
+
+/// Restores the previous string cache setting when dropped, see [`with_string_cache`].
+#[must_use]
+pub struct StringCacheGuard {
+    previous: bool,
+}
+
+impl Drop for StringCacheGuard {
+    fn drop(&mut self) {
+        STRING_CACHE.store(self.previous, Ordering::Relaxed);
+    }
+}
@@ -1354,4 +1354,26 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_supertype_options() -> PolarsResult<()> {
+        use crate::schema::SchemaExt;
+
+        assert_eq!(
+            get_supertype(&DataType::Int32, &DataType::String),
+            Some(DataType::String)
+        );
+        let strict = SuperTypeOptions::strict_strings();
+        assert_eq!(
+            get_supertype_with_options(&DataType::Int32, &DataType::String, strict),
+            None
+        );
+
+        let other = Schema::from_iter([Field::new("a".into(), DataType::String)]);
+        let mut schema = Schema::from_iter([Field::new("a".into(), DataType::Int32)]);
+        assert!(schema.try_merge_with_options(&other, strict).is_err());
+        schema.try_merge(&other)?;
+        assert_eq!(schema.get("a"), Some(&DataType::String));
+        Ok(())
+    }
 }
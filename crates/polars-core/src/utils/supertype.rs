@@ -109,6 +109,9 @@ impl Default for SuperTypeFlags {
     }
 }
 
+/// The rules used to resolve the supertype of two data types.
+///
+/// The default options allow primitive types to be cast to strings, but do not implode lists.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
 pub struct SuperTypeOptions {
     pub flags: SuperTypeFlags,
@@ -121,6 +124,16 @@ impl From<SuperTypeFlags> for SuperTypeOptions {
 }
 
 impl SuperTypeOptions {
+    /// Options that never resolve a primitive type and a string to a string supertype.
+    pub fn strict_strings() -> Self {
+        (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into()
+    }
+
+    /// Options that resolve a list and its inner type to the list type.
+    pub fn implode_list() -> Self {
+        (SuperTypeFlags::default() | SuperTypeFlags::ALLOW_IMPLODE_LIST).into()
+    }
+
     pub fn allow_implode_list(&self) -> bool {
         self.flags.contains(SuperTypeFlags::ALLOW_IMPLODE_LIST)
     }
@@ -131,11 +144,16 @@ impl SuperTypeOptions {
     }
 }
 
+/// Given two data types, determine the data type that both types can safely be cast to, using
+/// the default [`SuperTypeOptions`].
+///
+/// Returns [`None`] if no such data type exists.
 pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     get_supertype_with_options(l, r, SuperTypeOptions::default())
 }
 
-/// Given two data types, determine the data type that both types can safely be cast to.
+/// Given two data types, determine the data type that both types can safely be cast to under the
+/// rules set by `options`.
 ///
 /// Returns [`None`] if no such data type exists.
 pub fn get_supertype_with_options(
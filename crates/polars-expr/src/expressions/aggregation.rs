@@ -135,6 +135,7 @@ impl PhysicalExpr for AggregationExpr {
                 s,
                 allow_threading,
             ),
+            GroupByMethod::Product => s.product().map(|sc| sc.into_column(s.name().clone())),
             GroupByMethod::Groups => unreachable!(),
             GroupByMethod::NUnique => s.n_unique().map(|count| {
                 IdxCa::from_slice(s.name().clone(), &[count as IdxSize]).into_column()
@@ -206,6 +207,11 @@ impl PhysicalExpr for AggregationExpr {
                     let agg_c = c.agg_sum(&groups);
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
+                GroupByMethod::Product => {
+                    let (c, groups) = ac.get_final_aggregation();
+                    let agg_c = c.agg_product(&groups);
+                    AggregatedScalar(agg_c.with_name(keep_name))
+                },
                 GroupByMethod::Count { include_nulls } => {
                     if include_nulls || ac.get_values().null_count() == 0 {
                         // a few fast paths that prevent materializing new groups
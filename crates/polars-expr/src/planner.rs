@@ -537,9 +537,48 @@ fn create_physical_expr_inner(
             let output_field = expr_arena
                 .get(expression)
                 .to_field_with_ctx(schema, ctxt, expr_arena)?;
-            let input =
+            let use_agg_product =
+                matches!(function, IRFunctionExpr::Product) && matches!(ctxt, Context::Aggregation);
+            if use_agg_product {
+                // The grouped kernel can't raise, so check the dtype like `Series::product`.
+                let dtype = expr_arena
+                    .get(input[0].node())
+                    .to_dtype(schema, expr_arena)?;
+                use DataType::*;
+                polars_ensure!(
+                    matches!(
+                        dtype,
+                        Boolean
+                            | Int8
+                            | UInt8
+                            | Int16
+                            | UInt16
+                            | Int32
+                            | UInt32
+                            | Int64
+                            | UInt64
+                            | Float32
+                            | Float64
+                    ) || (cfg!(feature = "dtype-i128") && dtype == Int128),
+                    InvalidOperation: "`product` operation not supported for dtype `{dtype}`"
+                );
+            }
+            let mut input =
                 create_physical_expressions_from_irs(input, ctxt, expr_arena, schema, state)?;
 
+            // Use the grouped kernel instead of applying the function to every group.
+            if use_agg_product {
+                let agg_type = AggregationType {
+                    groupby: GroupByMethod::Product,
+                    allow_threading: false,
+                };
+                return Ok(Arc::new(AggregationExpr::new(
+                    input.pop().unwrap(),
+                    agg_type,
+                    Some(output_field),
+                )));
+            }
+
             Ok(Arc::new(ApplyExpr::new(
                 input,
                 function.clone().into(),
@@ -120,6 +120,7 @@ pub struct ExecutionState {
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
+    memory_limit: Option<usize>,
 }
 
 impl ExecutionState {
@@ -137,6 +138,7 @@ impl ExecutionState {
             ext_contexts: Default::default(),
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
+            memory_limit: polars_core::config::get_config().memory_limit,
         }
     }
 
@@ -160,6 +162,7 @@ impl ExecutionState {
                 Duration::from_nanos(start),
                 Duration::from_nanos(end),
                 name.to_string(),
+                None,
             );
         }
     }
@@ -168,14 +171,54 @@ impl ExecutionState {
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
         polars_ensure!(!self.stop.load(), ComputeError: "query interrupted");
+        if let Some(limit) = self.memory_limit {
+            polars_core::memory::check_memory_limit(limit)?;
+        }
         Ok(())
     }
 
+    /// Abort the query once more than `limit` bytes are allocated. Overrides the limit of the
+    /// global [`Config`](polars_core::config::Config).
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit;
+    }
+
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
     pub fn cancel_token(&self) -> Arc<RelaxedCell<bool>> {
         self.stop.clone()
     }
 
+    /// Use `token` to interrupt the query. Setting it to `true` aborts the query at the next
+    /// [`should_stop`](Self::should_stop) check.
+    pub fn set_cancel_token(&mut self, token: Arc<RelaxedCell<bool>>) {
+        self.stop = token;
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+        self.record_impl(func, name, |_| None)
+    }
+
+    /// Same as [`record`](Self::record), but also stores the number of rows of the produced
+    /// [`DataFrame`].
+    pub fn record_df<F: FnOnce() -> PolarsResult<DataFrame>>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
+        self.record_impl(func, name, |out| {
+            out.as_ref().ok().map(|df| df.height() as u64)
+        })
+    }
+
+    fn record_impl<T, F: FnOnce() -> T>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+        rows: impl FnOnce(&T) -> Option<u64>,
+    ) -> T {
         match &self.node_timer {
             None => func(),
             Some(timer) => {
@@ -183,7 +226,7 @@ impl ExecutionState {
                 let out = func();
                 let end = std::time::Instant::now();
 
-                timer.store(start, end, name.as_ref().to_string());
+                timer.store(start, end, name.as_ref().to_string(), rows(&out));
                 out
             },
         }
@@ -201,6 +244,7 @@ impl ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            memory_limit: self.memory_limit,
         }
     }
 
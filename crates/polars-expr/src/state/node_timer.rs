@@ -9,35 +9,55 @@ type EndInstant = Instant;
 
 type Nodes = Vec<String>;
 type Ticks = Vec<(Duration, Duration)>;
+type Rows = Vec<Option<u64>>;
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
-    data: Arc<Mutex<(Nodes, Ticks)>>,
+    data: Arc<Mutex<(Nodes, Ticks, Rows)>>,
 }
 
 impl NodeTimer {
     pub(super) fn new(query_start: Instant) -> Self {
         Self {
             query_start,
-            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            data: Arc::new(Mutex::new((
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+                Vec::with_capacity(16),
+            ))),
         }
     }
 
-    pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
+    pub(super) fn store(
+        &self,
+        start: StartInstant,
+        end: EndInstant,
+        name: String,
+        rows: Option<u64>,
+    ) {
         self.store_duration(
             start.duration_since(self.query_start),
             end.duration_since(self.query_start),
             name,
+            rows,
         )
     }
 
-    pub(super) fn store_duration(&self, start: Duration, end: Duration, name: String) {
+    pub(super) fn store_duration(
+        &self,
+        start: Duration,
+        end: Duration,
+        name: String,
+        rows: Option<u64>,
+    ) {
         let mut data = self.data.lock().unwrap();
         let nodes = &mut data.0;
         nodes.push(name);
         let ticks = &mut data.1;
-        ticks.push((start, end))
+        ticks.push((start, end));
+        let node_rows = &mut data.2;
+        node_rows.push(rows);
     }
 
     pub(super) fn finish(self) -> PolarsResult<DataFrame> {
@@ -50,6 +70,8 @@ impl NodeTimer {
         polars_ensure!(!ticks.is_empty(), ComputeError: "no data to time");
         let start = ticks[0].0;
         ticks.push((Duration::from_nanos(0), start));
+        let mut rows = std::mem::take(&mut data.2);
+        rows.push(None);
         let nodes_s = Column::new(PlSmallStr::from_static("node"), nodes);
         let start: NoNull<UInt64Chunked> = ticks
             .iter()
@@ -65,8 +87,18 @@ impl NodeTimer {
         let mut end = end.into_inner();
         end.rename(PlSmallStr::from_static("end"));
 
+        let rows = UInt64Chunked::from_iter_options(
+            PlSmallStr::from_static("rows_emitted"),
+            rows.into_iter(),
+        );
+
         let height = nodes_s.len();
-        let columns = vec![nodes_s, start.into_column(), end.into_column()];
+        let columns = vec![
+            nodes_s,
+            start.into_column(),
+            end.into_column(),
+            rows.into_column(),
+        ];
         let df = unsafe { DataFrame::new_no_checks(height, columns) };
         df.sort(vec!["start"], SortMultipleOptions::default())
     }
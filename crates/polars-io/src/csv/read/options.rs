@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use polars_core::datatypes::{DataType, Field};
 use polars_core::schema::{Schema, SchemaRef};
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_err};
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,6 +27,8 @@ pub struct CsvReadOptions {
     pub columns: Option<Arc<[PlSmallStr]>>,
     pub projection: Option<Arc<Vec<usize>>>,
     pub schema: Option<SchemaRef>,
+    /// Error if the columns in the file don't match `schema`.
+    pub strict_schema: bool,
     pub schema_overwrite: Option<SchemaRef>,
     pub dtype_overwrite: Option<Arc<Vec<DataType>>>,
     // CSV-specific options
@@ -75,6 +77,7 @@ impl Default for CsvReadOptions {
             columns: None,
             projection: None,
             schema: None,
+            strict_schema: false,
             schema_overwrite: None,
             dtype_overwrite: None,
 
@@ -84,7 +87,7 @@ impl Default for CsvReadOptions {
             skip_rows: 0,
             skip_lines: 0,
             skip_rows_after_header: 0,
-            infer_schema_length: Some(100),
+            infer_schema_length: Some(polars_core::config::get_infer_schema_length()),
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
@@ -172,6 +175,17 @@ impl CsvReadOptions {
         self
     }
 
+    /// Set the schema to use for the CSV file and error if the file does not adhere to it.
+    ///
+    /// The file must have exactly the columns of the schema. If the file has a header the
+    /// column names must match in order. Values that fail to parse as the declared dtype raise
+    /// an error, so this cannot be combined with `ignore_errors`.
+    pub fn with_strict_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self.strict_schema = true;
+        self
+    }
+
     /// Overwrites the data types in the schema by column name.
     pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
         self.schema_overwrite = schema_overwrite;
@@ -390,6 +404,8 @@ pub enum NullValues {
     AllColumns(Vec<PlSmallStr>),
     /// Tuples that map column names to null value of that column
     Named(Vec<(PlSmallStr, PlSmallStr)>),
+    /// A regular expression, every value in any column that it matches is null
+    Pattern(PlSmallStr),
 }
 
 impl NullValues {
@@ -405,10 +421,18 @@ impl NullValues {
                 }
                 NullValuesCompiled::Columns(null_values)
             },
+            NullValues::Pattern(pattern) => {
+                NullValuesCompiled::Pattern(compile_null_pattern(&pattern)?)
+            },
         })
     }
 }
 
+pub(super) fn compile_null_pattern(pattern: &str) -> PolarsResult<regex::bytes::Regex> {
+    regex::bytes::Regex::new(pattern)
+        .map_err(|e| polars_err!(ComputeError: "invalid null value pattern {:?}: {}", pattern, e))
+}
+
 #[derive(Debug, Clone)]
 pub enum NullValuesCompiled {
     /// A single value that's used for all columns
@@ -417,6 +441,8 @@ pub enum NullValuesCompiled {
     AllColumns(Vec<PlSmallStr>),
     /// A different null value per column, computed from `NullValues::Named`
     Columns(Vec<PlSmallStr>),
+    /// A regular expression that's used for all columns
+    Pattern(regex::bytes::Regex),
 }
 
 impl NullValuesCompiled {
@@ -432,6 +458,7 @@ impl NullValuesCompiled {
                 debug_assert!(index < v.len());
                 v.get_unchecked(index).as_bytes() == field
             },
+            Pattern(re) => re.is_match(field),
         }
     }
 }
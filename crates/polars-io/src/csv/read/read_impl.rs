@@ -19,7 +19,7 @@ use super::parser::{
     skip_lines_naive, skip_this_line,
 };
 use super::reader::prepare_csv_schema;
-use super::schema_inference::infer_file_schema;
+use super::schema_inference::{check_strict_schema, infer_file_schema};
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
@@ -146,6 +146,7 @@ impl<'a> CoreReader<'a> {
         has_header: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
+        strict_schema: bool,
         columns: Option<Arc<[PlSmallStr]>>,
         n_threads: Option<usize>,
         schema_overwrite: Option<SchemaRef>,
@@ -187,6 +188,22 @@ impl<'a> CoreReader<'a> {
         }
 
         let mut schema = match schema {
+            Some(schema) if strict_schema => {
+                // Only the header is read to check the columns against the schema.
+                let (file_schema, _, _) = infer_file_schema(
+                    &reader_bytes,
+                    &parse_options,
+                    Some(0),
+                    has_header,
+                    None,
+                    skip_rows,
+                    skip_lines,
+                    skip_rows_after_header,
+                    raise_if_empty,
+                )?;
+                check_strict_schema(&file_schema, &schema, has_header, ignore_errors)?;
+                schema
+            },
             Some(schema) => schema,
             None => {
                 let (inferred_schema, _, _) = infer_file_schema(
@@ -121,6 +121,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.has_header,
             self.options.ignore_errors,
             self.options.schema.clone(),
+            self.options.strict_schema,
             self.options.columns.clone(),
             self.options.n_threads,
             self.options.schema_overwrite.clone(),
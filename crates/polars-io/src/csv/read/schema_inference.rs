@@ -7,13 +7,18 @@ use polars_time::chunkedarray::string::infer as date_infer;
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
 
+use super::options::compile_null_pattern;
 use super::parser::{SplitLines, is_comment_line, skip_bom, skip_line_ending};
 use super::splitfields::SplitFields;
 use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 use crate::csv::read::parser::skip_lines_naive;
 use crate::mmap::ReaderBytes;
+use crate::utils::compression::{SupportedCompression, decompress_prefix, maybe_decompress_bytes};
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
+/// Number of decompressed bytes that are first used to infer the schema of a compressed file.
+const COMPRESSED_INFERENCE_CHUNK_SIZE: usize = 1 << 20;
+
 #[derive(Clone, Debug, Default)]
 pub struct SchemaInferenceResult {
     inferred_schema: SchemaRef,
@@ -54,6 +59,12 @@ impl SchemaInferenceResult {
             raise_if_empty,
         )?;
 
+        if options.strict_schema {
+            if let Some(schema) = &options.schema {
+                check_strict_schema(&inferred_schema, schema, has_header, options.ignore_errors)?;
+            }
+        }
+
         let this = Self {
             inferred_schema: Arc::new(inferred_schema),
             rows_read,
@@ -65,6 +76,67 @@ impl SchemaInferenceResult {
         Ok(this)
     }
 
+    /// Infer the schema from `bytes`, which may be compressed.
+    ///
+    /// Compressed data is only decompressed as far as needed to read `infer_schema_length` rows.
+    /// The total size is then estimated from the compression ratio of that prefix.
+    pub fn try_from_maybe_compressed_bytes_and_options(
+        bytes: &[u8],
+        options: &CsvReadOptions,
+    ) -> PolarsResult<Self> {
+        let (Some(algo), Some(infer_schema_length)) = (
+            SupportedCompression::check(bytes),
+            options.infer_schema_length,
+        ) else {
+            let mut owned = vec![];
+            let bytes = maybe_decompress_bytes(bytes, &mut owned)?;
+            if bytes.len() < 2 && options.raise_if_empty {
+                polars_bail!(NoData: "empty CSV")
+            }
+            return Self::try_from_reader_bytes_and_options(&ReaderBytes::Borrowed(bytes), options);
+        };
+
+        let eol_char = options.parse_options.eol_char;
+        let mut chunk_size = COMPRESSED_INFERENCE_CHUNK_SIZE;
+        let mut failed_before = false;
+        loop {
+            let mut out = vec![];
+            let consumed = decompress_prefix(bytes, algo, &mut out, chunk_size)?;
+            if out.len() < chunk_size {
+                // Everything was decompressed.
+                if out.len() < 2 && options.raise_if_empty {
+                    polars_bail!(NoData: "empty CSV")
+                }
+                return Self::try_from_reader_bytes_and_options(
+                    &ReaderBytes::Borrowed(&out),
+                    options,
+                );
+            }
+
+            // Only infer from complete lines. If the prefix does not hold enough rows or ends in
+            // the middle of a quoted field, retry with a larger prefix. An error that persists in
+            // the larger prefix is not caused by the truncation and is returned.
+            let prefix_len = memchr::memrchr(eol_char, &out).map_or(0, |i| i + 1);
+            let result = Self::try_from_reader_bytes_and_options(
+                &ReaderBytes::Borrowed(&out[..prefix_len]),
+                options,
+            );
+            match result {
+                Ok(mut result) => {
+                    if result.rows_read >= infer_schema_length {
+                        result.bytes_total =
+                            (out.len() as f64 / consumed as f64 * bytes.len() as f64) as usize;
+                        return Ok(result);
+                    }
+                    failed_before = false;
+                },
+                Err(e) if failed_before => return Err(e),
+                Err(_) => failed_before = true,
+            }
+            chunk_size *= 2;
+        }
+    }
+
     pub fn with_inferred_schema(mut self, inferred_schema: SchemaRef) -> Self {
         self.inferred_schema = inferred_schema;
         self
@@ -86,6 +158,39 @@ impl CsvReadOptions {
     }
 }
 
+/// Check that the columns found in a file match the strict `schema` of the reader.
+pub(super) fn check_strict_schema(
+    file_schema: &Schema,
+    schema: &Schema,
+    has_header: bool,
+    ignore_errors: bool,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        !ignore_errors,
+        InvalidOperation: "'ignore_errors' cannot be combined with a strict schema"
+    );
+    if has_header {
+        if let Some(name) = file_schema.iter_names().find(|name| !schema.contains(name)) {
+            polars_bail!(SchemaMismatch: "unexpected column {:?} in CSV file", name)
+        }
+        if let Some(name) = schema.iter_names().find(|name| !file_schema.contains(name)) {
+            polars_bail!(SchemaMismatch: "column {:?} not found in CSV file", name)
+        }
+        polars_ensure!(
+            file_schema.iter_names().eq(schema.iter_names()),
+            SchemaMismatch: "column order of CSV file does not match the schema: expected {:?}, got {:?}",
+            schema.iter_names().collect::<Vec<_>>(), file_schema.iter_names().collect::<Vec<_>>()
+        );
+    } else {
+        polars_ensure!(
+            file_schema.len() == schema.len(),
+            SchemaMismatch: "CSV file has {} columns, but the schema has {}",
+            file_schema.len(), schema.len()
+        );
+    }
+    Ok(())
+}
+
 pub fn finish_infer_field_schema(possibilities: &PlHashSet<DataType>) -> DataType {
     // determine data type based on possible types
     // if there are incompatible types, use DataType::String
@@ -329,6 +434,11 @@ fn infer_file_schema_inner(
     // needed to prevent ownership going into the iterator loop
     let records_ref = &mut lines;
 
+    let null_pattern = match &parse_options.null_values {
+        Some(NullValues::Pattern(pattern)) => Some(compile_null_pattern(pattern)?),
+        _ => None,
+    };
+
     let mut end_ptr = start_ptr;
     for mut line in records_ref
         .take(match max_read_rows {
@@ -449,6 +559,18 @@ fn infer_file_schema_inner(
                             ))
                         }
                     },
+                    Some(NullValues::Pattern(_)) => {
+                        let null_pattern = null_pattern.as_ref().unwrap();
+                        if !null_pattern.is_match(slice_escaped) {
+                            Some(infer_field_schema(
+                                &s,
+                                parse_options.try_parse_dates,
+                                parse_options.decimal_comma,
+                            ))
+                        } else {
+                            None
+                        }
+                    },
                 };
                 if let Some(dtype) = dtype {
                     unsafe { column_types.get_unchecked_mut(i).insert(dtype) };
@@ -558,3 +680,34 @@ pub fn infer_file_schema(
         )
     }
 }
+
+#[cfg(all(test, feature = "decompress"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_compressed_schema_from_prefix() -> PolarsResult<()> {
+        let mut csv = String::from("a,b\n");
+        for i in 0..200_000 {
+            csv.push_str(&format!("{i},{}\n", i % 7));
+        }
+        let compressed = zstd::encode_all(csv.as_bytes(), 0)?;
+        let options = CsvReadOptions::default().with_infer_schema_length(Some(100));
+
+        let result = SchemaInferenceResult::try_from_maybe_compressed_bytes_and_options(
+            &compressed,
+            &options,
+        )?;
+        assert_eq!(
+            result.get_inferred_schema().as_ref(),
+            &Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new("b".into(), DataType::Int64),
+            ])
+        );
+        // Only a prefix was decompressed, so the row count is an estimate.
+        let n_rows = result.get_estimated_n_rows();
+        assert!((100_000..400_000).contains(&n_rows), "{n_rows}");
+        Ok(())
+    }
+}
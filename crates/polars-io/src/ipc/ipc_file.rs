@@ -48,21 +48,20 @@ use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
+use crate::utils::split_to_chunk_size;
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct IpcScanOptions;
-
-#[expect(clippy::derivable_impls)]
-impl Default for IpcScanOptions {
-    fn default() -> Self {
-        Self {}
-    }
+pub struct IpcScanOptions {
+    /// Maximum number of rows of the morsels, and so of the output chunks, of a scan.
+    pub chunk_size: Option<usize>,
 }
 
 /// Read Arrows IPC format into a DataFrame
 ///
+/// Legacy Feather V1 files are detected and read as well.
+///
 /// # Example
 /// ```
 /// use polars_core::prelude::*;
@@ -93,6 +92,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) memory_map: Option<PathBuf>,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    chunk_size: Option<usize>,
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -139,6 +139,13 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Limit the chunks of the output to `chunk_size` rows. Longer record batches are sliced
+    /// without copying and shorter ones are kept as is. Setting this disables rechunking.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
@@ -178,6 +185,29 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Read a legacy Feather V1 file. Only the projected columns are read from the file.
+    fn finish_feather_v1(mut self) -> PolarsResult<DataFrame> {
+        let metadata = read::read_feather_v1_metadata(&mut self.reader)?;
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &metadata.schema)?);
+        }
+
+        let batch = read::read_feather_v1(&mut self.reader, &metadata, self.projection.as_deref())?;
+        let mut df = DataFrame::from(batch);
+
+        if let Some(n_rows) = self.n_rows {
+            df = df.slice(0, n_rows);
+        }
+        if let Some(ri) = &self.row_index {
+            unsafe { df.with_row_index_mut(ri.name.clone(), Some(ri.offset)) };
+        }
+        if let Some(chunk_size) = self.chunk_size {
+            split_to_chunk_size(&mut df, chunk_size);
+        }
+        Ok(df)
+    }
+
     // todo! hoist to lazy crate
     #[cfg(feature = "lazy")]
     pub fn finish_with_scan_ops(
@@ -239,6 +269,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: None,
             metadata: None,
             schema: None,
+            chunk_size: None,
         }
     }
 
@@ -248,6 +279,10 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
+        if read::is_feather_v1(&mut self.reader)? {
+            return self.finish_feather_v1();
+        }
+
         let reader_schema = if let Some(ref schema) = self.schema {
             schema.clone()
         } else {
@@ -257,6 +292,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
 
         let hive_partition_columns = self.hive_partition_columns.take();
         let include_file_path = self.include_file_path.take();
+        let chunk_size = self.chunk_size;
 
         // In case only hive columns are projected, the df would be empty, but we need the row count
         // of the file in order to project the correct number of rows for the hive columns.
@@ -283,7 +319,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
                     Err(err) => check_mmap_err(err)?,
                 }
             }
-            let rechunk = self.rechunk;
+            let rechunk = self.rechunk && chunk_size.is_none();
             let schema = self.get_metadata()?.schema.clone();
 
             if let Some(columns) = &self.columns {
@@ -305,6 +341,10 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             Ok(df)
         })()?;
 
+        if let Some(chunk_size) = chunk_size {
+            split_to_chunk_size(&mut df, chunk_size);
+        }
+
         if let Some(hive_cols) = hive_partition_columns {
             materialize_hive_partitions(&mut df, reader_schema, Some(hive_cols.as_slice()));
         };
@@ -218,12 +218,32 @@ where
     ignore_errors: bool,
     infer_schema_len: Option<NonZeroUsize>,
     batch_size: NonZeroUsize,
-    projection: Option<Vec<PlSmallStr>>,
+    columns: Option<Vec<PlSmallStr>>,
+    column_indices: Option<Vec<usize>>,
     schema: Option<SchemaRef>,
+    strict_schema: bool,
     schema_overwrite: Option<&'a Schema>,
     json_format: JsonFormat,
 }
 
+fn check_missing_fields(json_value: &BorrowedValue, schema: &Schema) -> PolarsResult<()> {
+    let objects = match json_value {
+        BorrowedValue::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+    for object in objects {
+        if let BorrowedValue::Object(object) = object {
+            if let Some(name) = schema
+                .iter_names()
+                .find(|name| !object.contains_key(name.as_str()))
+            {
+                polars_bail!(SchemaMismatch: "field {:?} of the schema is missing in JSON object", name)
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn remove_bom(bytes: &[u8]) -> PolarsResult<&[u8]> {
     if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
         // UTF-8 BOM
@@ -246,8 +266,10 @@ where
             ignore_errors: false,
             infer_schema_len: Some(NonZeroUsize::new(100).unwrap()),
             batch_size: NonZeroUsize::new(8192).unwrap(),
-            projection: None,
+            columns: None,
+            column_indices: None,
             schema: None,
+            strict_schema: false,
             schema_overwrite: None,
             json_format: JsonFormat::Json,
         }
@@ -286,7 +308,12 @@ where
                     }
                 }
 
-                let allow_extra_fields_in_struct = self.schema.is_some();
+                if self.strict_schema {
+                    if let Some(schema) = &self.schema {
+                        check_missing_fields(&json_value, schema)?;
+                    }
+                }
+                let allow_extra_fields_in_struct = self.schema.is_some() && !self.strict_schema;
 
                 // struct type
                 let dtype = if let Some(mut schema) = self.schema {
@@ -350,10 +377,15 @@ where
                 DataFrame::try_from(arr.clone())
             },
             JsonFormat::JsonLines => {
+                polars_ensure!(
+                    !(self.strict_schema && self.ignore_errors),
+                    InvalidOperation: "'ignore_errors' cannot be combined with a strict schema"
+                );
                 let mut json_reader = CoreJsonReader::new(
                     rb,
                     None,
                     self.schema,
+                    self.strict_schema,
                     self.schema_overwrite,
                     None,
                     1024, // sample size
@@ -364,6 +396,7 @@ where
                     None,
                     None,
                     None,
+                    None,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -374,8 +407,10 @@ where
         }?;
 
         // TODO! Ensure we don't materialize the columns we don't need
-        if let Some(proj) = self.projection.as_deref() {
-            out.select(proj.iter().cloned())
+        if let Some(columns) = self.columns.as_deref() {
+            out.select(columns.iter().cloned())
+        } else if let Some(column_indices) = self.column_indices.as_deref() {
+            out.select(projection_to_columns(column_indices, out.schema())?)
         } else {
             Ok(out)
         }
@@ -392,6 +427,16 @@ where
         self
     }
 
+    /// Set the JSON file's schema and error if the file does not adhere to it.
+    ///
+    /// Objects with fields that are not in the schema, objects that miss a field of the schema and
+    /// values that cannot be read as the declared dtype all raise an error.
+    pub fn with_strict_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self.strict_schema = true;
+        self
+    }
+
     /// Overwrite parts of the inferred schema.
     pub fn with_schema_overwrite(mut self, schema: &'a Schema) -> Self {
         self.schema_overwrite = Some(schema);
@@ -419,13 +464,25 @@ where
         self
     }
 
-    /// Set the reader's column projection: the names of the columns to keep after deserialization. If `None`, all
-    /// columns are kept.
+    /// Set the names of the columns to keep after deserialization. If `None`, all columns are kept.
     ///
-    /// Setting `projection` to the columns you want to keep is more efficient than deserializing all of the columns and
+    /// Setting `columns` to the columns you want to keep is more efficient than deserializing all of the columns and
     /// then dropping the ones you don't want.
-    pub fn with_projection(mut self, projection: Option<Vec<PlSmallStr>>) -> Self {
-        self.projection = projection;
+    pub fn with_columns(mut self, columns: Option<Vec<PlSmallStr>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the names of the columns to keep after deserialization. If `None`, all columns are kept.
+    #[deprecated(since = "0.50.0", note = "use `JsonReader::with_columns`")]
+    pub fn with_projection(self, projection: Option<Vec<PlSmallStr>>) -> Self {
+        self.with_columns(projection)
+    }
+
+    /// Set the indices of the columns to keep after deserialization. This counts from 0, meaning
+    /// that `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_column_indices(mut self, column_indices: Option<Vec<usize>>) -> Self {
+        self.column_indices = column_indices;
         self
     }
 
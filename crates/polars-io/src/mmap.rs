@@ -1,5 +1,7 @@
 use std::fs::File;
 use std::io::{BufReader, Cursor, Read, Seek};
+#[cfg(feature = "csv")]
+use std::io::{SeekFrom, Write};
 
 use polars_core::config::verbose;
 use polars_utils::file::ClosableFile;
@@ -70,6 +72,99 @@ impl<T: MmapBytesReader> MmapBytesReader for &mut T {
     }
 }
 
+/// Number of bytes a [`SpooledReader`] keeps in memory before spooling to a temporary file.
+#[cfg(feature = "csv")]
+const DEFAULT_SPOOL_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Makes a non-seekable reader, such as stdin or a pipe, usable as a [`MmapBytesReader`].
+///
+/// The input is read to the end when the reader is created. It is kept in memory if it fits in
+/// the memory limit and is spooled to a temporary file otherwise, which is then memory mapped.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::mmap::SpooledReader;
+/// use polars_io::prelude::*;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let reader = SpooledReader::new(std::io::stdin().lock())?;
+///     CsvReader::new(reader).finish()
+/// }
+/// ```
+#[cfg(feature = "csv")]
+pub enum SpooledReader {
+    Memory(Cursor<Vec<u8>>),
+    File(File),
+}
+
+#[cfg(feature = "csv")]
+impl SpooledReader {
+    pub fn new<R: Read>(reader: R) -> std::io::Result<Self> {
+        Self::with_memory_limit(reader, DEFAULT_SPOOL_MEMORY_LIMIT)
+    }
+
+    /// Spool `reader`, keeping at most `memory_limit` bytes in memory.
+    pub fn with_memory_limit<R: Read>(mut reader: R, memory_limit: usize) -> std::io::Result<Self> {
+        let mut buf = vec![];
+        reader
+            .by_ref()
+            .take(memory_limit as u64 + 1)
+            .read_to_end(&mut buf)?;
+        if buf.len() <= memory_limit {
+            return Ok(Self::Memory(Cursor::new(buf)));
+        }
+
+        if verbose() {
+            eprintln!("input exceeds {memory_limit} bytes; spooling to a temporary file.")
+        }
+        let mut file = tempfile::tempfile()?;
+        file.write_all(&buf)?;
+        drop(buf);
+        std::io::copy(&mut reader, &mut file)?;
+        file.rewind()?;
+        Ok(Self::File(file))
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Read for SpooledReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Memory(cursor) => cursor.read(buf),
+            Self::File(file) => file.read(buf),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Seek for SpooledReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            Self::Memory(cursor) => cursor.seek(pos),
+            Self::File(file) => file.seek(pos),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl MmapBytesReader for SpooledReader {
+    fn to_file(&self) -> Option<&File> {
+        match self {
+            Self::Memory(_) => None,
+            Self::File(file) => Some(file),
+        }
+    }
+
+    fn to_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Memory(cursor) => Some(cursor.get_ref()),
+            Self::File(_) => None,
+        }
+    }
+}
+
 // Handle various forms of input bytes
 pub enum ReaderBytes<'a> {
     Borrowed(&'a [u8]),
@@ -36,7 +36,8 @@ where
     ignore_errors: bool,
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
-    projection: Option<Arc<[PlSmallStr]>>,
+    columns: Option<Arc<[PlSmallStr]>>,
+    column_indices: Option<Vec<usize>>,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -67,8 +68,22 @@ where
         self
     }
 
-    pub fn with_projection(mut self, projection: Option<Arc<[PlSmallStr]>>) -> Self {
-        self.projection = projection;
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Columns to select/ project
+    #[deprecated(since = "0.50.0", note = "use `JsonLineReader::with_columns`")]
+    pub fn with_projection(self, projection: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.with_columns(projection)
+    }
+
+    /// Set the indices of the columns to select. This counts from 0, meaning that `vec![0, 4]`
+    /// would select the 1st and 5th column.
+    pub fn with_column_indices(mut self, column_indices: Option<Vec<usize>>) -> Self {
+        self.column_indices = column_indices;
         self
     }
 
@@ -117,6 +132,7 @@ where
             reader_bytes,
             self.n_rows,
             self.schema,
+            false,
             self.schema_overwrite,
             self.n_threads,
             1024, // sample size
@@ -126,7 +142,8 @@ where
             self.ignore_errors,
             self.row_index,
             self.predicate,
-            self.projection,
+            self.columns,
+            self.column_indices,
         )?;
 
         json_reader.count()
@@ -161,7 +178,8 @@ where
             ignore_errors: false,
             row_index: None,
             predicate: None,
-            projection: None,
+            columns: None,
+            column_indices: None,
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -171,6 +189,7 @@ where
             reader_bytes,
             self.n_rows,
             self.schema,
+            false,
             self.schema_overwrite,
             self.n_threads,
             1024, // sample size
@@ -180,7 +199,8 @@ where
             self.ignore_errors,
             self.row_index,
             self.predicate,
-            self.projection,
+            self.columns,
+            self.column_indices,
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -195,6 +215,7 @@ pub(crate) struct CoreJsonReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
     n_rows: Option<usize>,
     schema: SchemaRef,
+    strict_schema: bool,
     n_threads: Option<usize>,
     sample_size: usize,
     chunk_size: NonZeroUsize,
@@ -210,6 +231,7 @@ impl<'a> CoreJsonReader<'a> {
         reader_bytes: ReaderBytes<'a>,
         n_rows: Option<usize>,
         schema: Option<SchemaRef>,
+        strict_schema: bool,
         schema_overwrite: Option<&Schema>,
         n_threads: Option<usize>,
         sample_size: usize,
@@ -219,7 +241,8 @@ impl<'a> CoreJsonReader<'a> {
         ignore_errors: bool,
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
-        projection: Option<Arc<[PlSmallStr]>>,
+        columns: Option<Arc<[PlSmallStr]>>,
+        column_indices: Option<Vec<usize>>,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
@@ -236,9 +259,18 @@ impl<'a> CoreJsonReader<'a> {
             overwrite_schema(schema, overwriting_schema)?;
         }
 
+        let projection = match (columns, column_indices) {
+            (Some(columns), _) => Some(columns),
+            (None, Some(column_indices)) => {
+                Some(projection_to_columns(&column_indices, &schema)?.into())
+            },
+            (None, None) => None,
+        };
+
         Ok(CoreJsonReader {
             reader_bytes: Some(reader_bytes),
             schema,
+            strict_schema,
             sample_size,
             n_rows,
             n_threads,
@@ -296,11 +328,12 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut local_df = parse_ndjson(
+                    let mut local_df = parse_ndjson_impl(
                         &bytes[start_pos..stop_at_nbytes],
                         Some(capacity),
                         &self.schema,
                         self.ignore_errors,
+                        self.strict_schema,
                     )?;
 
                     let prepredicate_height = local_df.height() as IdxSize;
@@ -355,6 +388,7 @@ fn parse_impl(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
     scratch: &mut Scratch,
+    strict_schema: bool,
 ) -> PolarsResult<usize> {
     scratch.json.clear();
     scratch.json.extend_from_slice(bytes);
@@ -366,10 +400,24 @@ fn parse_impl(
             buffers.iter_mut().try_for_each(|(s, inner)| {
                 match s.0.map_lookup(&value) {
                     Some(v) => inner.add(v)?,
+                    None if strict_schema => polars_bail!(
+                        SchemaMismatch: "field {:?} of the schema is missing in JSON object", s.0.key()
+                    ),
                     None => inner.add_null(),
                 }
                 PolarsResult::Ok(())
             })?;
+            // All fields of the schema are present, so any other key is an extra field.
+            if strict_schema && value.len() > buffers.len() {
+                let name = value
+                    .keys()
+                    .find(|name| !buffers.keys().any(|s| s.0.key() == *name))
+                    .unwrap();
+                polars_bail!(SchemaMismatch: "unexpected field {:?} in JSON object", name)
+            }
+        },
+        _ if strict_schema => {
+            polars_bail!(SchemaMismatch: "expected a JSON object with the fields of the schema")
         },
         _ => {
             buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
@@ -397,12 +445,16 @@ pub fn json_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
     })
 }
 
-fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
+fn parse_lines(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    strict_schema: bool,
+) -> PolarsResult<()> {
     let mut scratch = Scratch::default();
 
     let iter = json_lines(bytes);
     for bytes in iter {
-        parse_impl(bytes, buffers, &mut scratch)?;
+        parse_impl(bytes, buffers, &mut scratch, strict_schema)?;
     }
     Ok(())
 }
@@ -412,11 +464,23 @@ pub fn parse_ndjson(
     n_rows_hint: Option<usize>,
     schema: &Schema,
     ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
+    parse_ndjson_impl(bytes, n_rows_hint, schema, ignore_errors, false)
+}
+
+/// Parse the lines in `bytes`. With `strict_schema`, every line must be an object with exactly
+/// the fields of `schema`.
+fn parse_ndjson_impl(
+    bytes: &[u8],
+    n_rows_hint: Option<usize>,
+    schema: &Schema,
+    ignore_errors: bool,
+    strict_schema: bool,
 ) -> PolarsResult<DataFrame> {
     let capacity = n_rows_hint.unwrap_or_else(|| estimate_n_lines_in_chunk(bytes));
 
     let mut buffers = init_buffers(schema, capacity, ignore_errors)?;
-    parse_lines(bytes, &mut buffers)?;
+    parse_lines(bytes, &mut buffers, strict_schema)?;
 
     DataFrame::new(
         buffers
@@ -20,6 +20,7 @@ mod mmap;
 mod options;
 mod read_impl;
 mod reader;
+mod statistics;
 mod utils;
 
 const ROW_COUNT_OVERFLOW_ERR: PolarsError = PolarsError::ComputeError(ErrString::new_static(
@@ -37,10 +38,12 @@ pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 pub use reader::ParquetReader;
+pub use statistics::DataFrameStatistics;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
+    pub use super::statistics::{ColumnStatistics, load_column_statistics, row_group_lengths};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
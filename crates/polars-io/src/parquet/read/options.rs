@@ -10,6 +10,8 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Maximum number of rows of the morsels, and so of the output chunks, of a scan.
+    pub chunk_size: Option<usize>,
 }
 
 impl Default for ParquetOptions {
@@ -19,6 +21,7 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            chunk_size: None,
         }
     }
 }
@@ -235,7 +235,10 @@ fn rg_to_dfs_optionally_par_over_columns(
                 store,
             )?;
 
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+            // The sorting columns refer to the leaves of the parquet schema.
+            if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+            }
             Ok(series.into_column())
         };
 
@@ -376,7 +379,10 @@ fn rg_to_dfs_par_over_rg(
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        // The sorting columns refer to the leaves of the parquet schema.
+                        if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                            try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+                        }
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
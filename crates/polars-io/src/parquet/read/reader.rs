@@ -6,11 +6,14 @@ use polars_core::prelude::*;
 use polars_parquet::read;
 
 use super::read_impl::read_parquet;
+#[cfg(feature = "dtype-struct")]
+use super::statistics::read_statistics;
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
+use crate::utils::split_to_chunk_size;
 
 /// Read Apache parquet format into a DataFrame.
 #[must_use]
@@ -27,6 +30,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    chunk_size: Option<usize>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -43,6 +47,13 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Limit the chunks of the output to `chunk_size` rows. Longer row groups are sliced without
+    /// copying and shorter ones are kept as is. Setting this disables rechunking.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
     pub fn with_slice(mut self, slice: Option<(usize, usize)>) -> Self {
         self.slice = slice.unwrap_or((0, usize::MAX));
         self
@@ -150,6 +161,18 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         Ok(metadata.num_rows)
     }
 
+    /// Statistics of the row groups in the parquet file, one row per row group.
+    ///
+    /// Has a `len` column with the number of rows and a `columns` struct column with a
+    /// `{min, max, null_count}` struct field for every column of the file. Statistics that were
+    /// not written, or that can't be read for the column's type (e.g. nested columns), are null.
+    #[cfg(feature = "dtype-struct")]
+    pub fn statistics(&mut self) -> PolarsResult<DataFrame> {
+        let schema = self.schema()?;
+        let metadata = self.get_metadata()?;
+        read_statistics(metadata, &schema)
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -191,6 +214,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            chunk_size: None,
         }
     }
 
@@ -219,7 +243,9 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.hive_partition_columns.as_deref(),
         )?;
 
-        if self.rechunk {
+        if let Some(chunk_size) = self.chunk_size {
+            split_to_chunk_size(&mut df, chunk_size);
+        } else if self.rechunk {
             df.as_single_chunk_par();
         };
 
@@ -0,0 +1,215 @@
+#[cfg(feature = "dtype-struct")]
+use arrow::datatypes::ArrowSchema;
+use polars_core::prelude::*;
+#[cfg(feature = "dtype-struct")]
+use polars_parquet::read::FileMetadata;
+use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
+use polars_utils::format_pl_smallstr;
+
+use crate::predicates::use_min_max;
+
+/// The min, max and null count of a column, with one row per row group.
+pub struct ColumnStatistics {
+    pub min: Column,
+    pub max: Column,
+    pub null_count: Column,
+}
+
+impl ColumnStatistics {
+    /// Compute the statistics of all values of `column` as a single row.
+    ///
+    /// This follows the statistics written to parquet files: nulls are ignored, a NaN
+    /// propagates to the minimum and maximum, and dtypes the batch-skipping predicates can't
+    /// compare have a null minimum and maximum.
+    pub fn from_column(column: &Column) -> PolarsResult<Self> {
+        let dtype = column.dtype();
+        let nan = match dtype {
+            DataType::Float32 if column.is_nan()?.any() => Some(AnyValue::Float32(f32::NAN)),
+            DataType::Float64 if column.is_nan()?.any() => Some(AnyValue::Float64(f64::NAN)),
+            _ => None,
+        };
+        let (min, max) = match nan {
+            _ if !use_min_max(dtype) => (Scalar::null(dtype.clone()), Scalar::null(dtype.clone())),
+            Some(nan) => {
+                let nan = Scalar::new(dtype.clone(), nan);
+                (nan.clone(), nan)
+            },
+            None => (column.min_reduce()?, column.max_reduce()?),
+        };
+
+        Ok(Self {
+            min: min.into_column(PlSmallStr::EMPTY),
+            max: max.into_column(PlSmallStr::EMPTY),
+            null_count: Column::new(PlSmallStr::EMPTY, [column.null_count() as IdxSize]),
+        })
+    }
+
+    /// Name the statistics `{name}_min`, `{name}_max` and `{name}_nc`, as the batch-skipping
+    /// predicates expect.
+    pub fn into_named_columns(self, name: &str) -> [Column; 3] {
+        [
+            self.min.with_name(format_pl_smallstr!("{name}_min")),
+            self.max.with_name(format_pl_smallstr!("{name}_max")),
+            self.null_count.with_name(format_pl_smallstr!("{name}_nc")),
+        ]
+    }
+
+    fn new_null(dtype: &DataType, height: usize) -> Self {
+        Self {
+            min: Column::full_null(PlSmallStr::EMPTY, height, dtype),
+            max: Column::full_null(PlSmallStr::EMPTY, height, dtype),
+            null_count: Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
+        }
+    }
+
+    fn from_arrow_statistics(
+        statistics: ArrowColumnStatisticsArrays,
+        field: &ArrowField,
+    ) -> PolarsResult<Self> {
+        let md = field.metadata.as_deref();
+        // SAFETY: the statistics are deserialized to the arrow type of the field.
+        let (min, max) = unsafe {
+            (
+                Series::_try_from_arrow_unchecked_with_md(
+                    PlSmallStr::EMPTY,
+                    vec![statistics.min_value],
+                    field.dtype(),
+                    md,
+                )?,
+                Series::_try_from_arrow_unchecked_with_md(
+                    PlSmallStr::EMPTY,
+                    vec![statistics.max_value],
+                    field.dtype(),
+                    md,
+                )?,
+            )
+        };
+        let null_count = Series::from_arrow(PlSmallStr::EMPTY, statistics.null_count.boxed())?;
+
+        Ok(Self {
+            min: min.into_column(),
+            max: max.into_column(),
+            null_count: null_count.into_column(),
+        })
+    }
+}
+
+/// Load the statistics of `field` in `row_groups`.
+///
+/// Statistics that were not written, or that can't be read for the type of the field (e.g.
+/// nested columns), are null. A field that is missing from the file also has null statistics.
+pub fn load_column_statistics(
+    row_groups: &[RowGroupMetadata],
+    field: &ArrowField,
+) -> PolarsResult<ColumnStatistics> {
+    let null_statistics = || {
+        Ok(ColumnStatistics::new_null(
+            &DataType::from_arrow_field(field),
+            row_groups.len(),
+        ))
+    };
+
+    let Some(first) = row_groups.first() else {
+        return null_statistics();
+    };
+    // This can be None in the allow_missing_columns case.
+    let Some(idxs) = first.columns_idxs_under_root_iter(&field.name) else {
+        return null_statistics();
+    };
+
+    // 0 is possible for possible for empty structs.
+    //
+    // 2+ is for structs. We don't support reading nested statistics for now. It does not
+    // really make any sense at the moment with how we structure statistics.
+    let [idx] = idxs else {
+        return null_statistics();
+    };
+
+    let Some(statistics) = deserialize_all(field, row_groups, *idx)? else {
+        return null_statistics();
+    };
+
+    ColumnStatistics::from_arrow_statistics(statistics, field)
+}
+
+/// The number of rows of every row group.
+pub fn row_group_lengths(row_groups: &[RowGroupMetadata]) -> PolarsResult<Vec<IdxSize>> {
+    row_groups
+        .iter()
+        .map(|rg| {
+            IdxSize::try_from(rg.num_rows())
+                .map_err(|_| polars_err!(bigidx, ctx = "parquet row group", size = rg.num_rows()))
+        })
+        .collect()
+}
+
+/// Collect the statistics of every row group into a [`DataFrame`] with one row per row group.
+///
+/// Has a `len` column with the number of rows and a `columns` struct column, which has a
+/// `{min, max, null_count}` struct field for every column in `schema`.
+#[cfg(feature = "dtype-struct")]
+pub(super) fn read_statistics(
+    metadata: &FileMetadata,
+    schema: &ArrowSchema,
+) -> PolarsResult<DataFrame> {
+    let row_groups = metadata.row_groups.as_slice();
+    let height = row_groups.len();
+
+    let columns = schema
+        .iter_values()
+        .map(|field| {
+            let statistics = load_column_statistics(row_groups, field)?;
+            let fields = [
+                statistics.min.with_name(PlSmallStr::from_static("min")),
+                statistics.max.with_name(PlSmallStr::from_static("max")),
+                statistics
+                    .null_count
+                    .with_name(PlSmallStr::from_static("null_count")),
+            ];
+            StructChunked::from_columns(field.name.clone(), height, &fields)
+                .map(|ca| ca.into_series())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let columns =
+        StructChunked::from_series(PlSmallStr::from_static("columns"), height, columns.iter())?;
+
+    let lengths = Column::new(
+        PlSmallStr::from_static("len"),
+        row_group_lengths(row_groups)?,
+    );
+    DataFrame::new_with_height(height, vec![lengths, columns.into_series().into_column()])
+}
+
+/// Summary statistics of the columns of a [`DataFrame`].
+pub trait DataFrameStatistics {
+    /// Create a single-row [`DataFrame`] with summary statistics of every column.
+    ///
+    /// The output has a `len` column followed by `{name}_min`, `{name}_max`, `{name}_nc` (null
+    /// count) and `{name}_nd` (distinct count) for each column. The first three are computed by
+    /// [`ColumnStatistics::from_column`], in the layout the batch-skipping predicates of the scans
+    /// are evaluated on. Nulls are not counted as a distinct value.
+    fn column_stats(&self) -> PolarsResult<DataFrame>;
+}
+
+impl DataFrameStatistics for DataFrame {
+    fn column_stats(&self) -> PolarsResult<DataFrame> {
+        let mut columns = Vec::with_capacity(1 + self.width() * 4);
+        columns.push(Column::new(
+            PlSmallStr::from_static("len"),
+            [self.height() as IdxSize],
+        ));
+
+        for c in self.get_columns() {
+            let name = c.name();
+            let n_unique = c.n_unique()? - usize::from(c.has_nulls());
+            columns.extend(ColumnStatistics::from_column(c)?.into_named_columns(name));
+            columns.push(Column::new(
+                format_pl_smallstr!("{name}_nd"),
+                [n_unique as IdxSize],
+            ));
+        }
+
+        DataFrame::new(columns)
+    }
+}
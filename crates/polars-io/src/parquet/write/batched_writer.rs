@@ -1,15 +1,18 @@
 use std::io::Write;
 use std::sync::Mutex;
+use std::sync::mpsc::{TryRecvError, channel};
 
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_columns, schema_to_metadata_key,
 };
+use rayon::Yield;
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
@@ -25,6 +28,7 @@ pub struct BatchedWriter<W: Write> {
     pub(super) column_options: Vec<ColumnWriteOptions>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    pub(super) maintain_order: bool,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
 }
 
@@ -42,6 +46,7 @@ impl<W: Write> BatchedWriter<W> {
             column_options,
             options,
             parallel,
+            maintain_order: true,
             key_value_metadata,
         }
     }
@@ -68,9 +73,17 @@ impl<W: Write> BatchedWriter<W> {
 
     /// Write a batch to the parquet writer.
     ///
+    /// The sorted flags of the columns of `df` are recorded as the sorting columns of the
+    /// written row groups.
+    ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let sorting_columns = get_sorting_columns(df, &self.parquet_schema);
+        if self.parallel && !self.maintain_order {
+            return self.write_batch_unordered(df, sorting_columns);
+        }
+
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
@@ -81,25 +94,77 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            writer.write_with_sorting_columns(group?, sorting_columns.clone())?;
         }
         Ok(())
     }
 
+    /// Encode the row groups of `df` concurrently and write every row group as soon as it is
+    /// encoded.
+    fn write_batch_unordered(
+        &mut self,
+        df: &DataFrame,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        let writer = self.writer.get_mut().unwrap();
+        let fields = self.parquet_schema.fields();
+        let column_options = self.column_options.as_slice();
+        let options = self.options;
+
+        let (tx, rx) = channel();
+        POOL.in_place_scope(|scope| {
+            for batch in df.iter_chunks(CompatLevel::newest(), false) {
+                if batch.len() == 0 {
+                    continue;
+                }
+                let tx = tx.clone();
+                scope.spawn(move |_| {
+                    let row_group =
+                        encode_and_compress_row_group(batch, fields, column_options, options);
+                    let _ = tx.send(row_group);
+                });
+            }
+            drop(tx);
+
+            loop {
+                let row_group = match rx.try_recv() {
+                    Ok(row_group) => row_group,
+                    Err(TryRecvError::Disconnected) => return Ok(()),
+                    // Help with the encoding if this thread is part of the pool. Blocking a
+                    // worker here could deadlock the pool.
+                    Err(TryRecvError::Empty) => match POOL.yield_now() {
+                        Some(Yield::Executed) => continue,
+                        Some(Yield::Idle) => {
+                            std::thread::yield_now();
+                            continue;
+                        },
+                        None => match rx.recv() {
+                            Ok(row_group) => row_group,
+                            Err(_) => return Ok(()),
+                        },
+                    },
+                };
+                write_compressed_row_group(writer, &row_group?, sorting_columns.clone())?;
+            }
+        })
+    }
+
     pub fn parquet_schema(&mut self) -> &SchemaDescriptor {
         let writer = self.writer.get_mut().unwrap();
         writer.parquet_schema()
     }
 
     pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
-        let writer = self.writer.get_mut().unwrap();
-        let rg = DynIter::new(rg.iter().map(|col_pages| {
-            Ok(DynStreamingIterator::new(
-                fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
-            ))
-        }));
-        writer.write(rg)?;
-        Ok(())
+        self.write_row_group_with_sorting_columns(rg, None)
+    }
+
+    /// Write a row group of compressed pages and record the order in which its rows are sorted.
+    pub fn write_row_group_with_sorting_columns(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        write_compressed_row_group(self.writer.get_mut().unwrap(), rg, sorting_columns)
     }
 
     pub fn get_writer(&self) -> &Mutex<FileWriter<W>> {
@@ -143,6 +208,97 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// Whether the order of `dtype` in Polars is the type-defined order of its parquet type.
+fn has_parquet_sort_order(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(
+            dtype,
+            DataType::Boolean | DataType::String | DataType::Binary
+        )
+}
+
+/// Get the [`SortingColumn`] of `c` from its sorted flag.
+///
+/// Returns `None` if `c` is not sorted, or if it is not a single leaf of the parquet schema.
+pub fn get_sorting_column(c: &Column, parquet_schema: &SchemaDescriptor) -> Option<SortingColumn> {
+    let descending = match c.is_sorted_flag() {
+        IsSorted::Ascending => false,
+        IsSorted::Descending => true,
+        IsSorted::Not => return None,
+    };
+    if !has_parquet_sort_order(c.dtype()) {
+        return None;
+    }
+    let column_idx = parquet_schema
+        .columns()
+        .iter()
+        .position(|leaf| leaf.path_in_schema.as_slice() == [c.name().clone()])?;
+    let nulls_first = c.null_count() > 0 && c.get(0).is_ok_and(|av| av.is_null());
+    Some(SortingColumn {
+        column_idx: column_idx as i32,
+        descending,
+        nulls_first,
+    })
+}
+
+/// Get the [`SortingColumn`]s of the row groups of `df` from the sorted flags of its columns.
+///
+/// A row group is sorted by all of these columns lexicographically, as every column is sorted on
+/// its own.
+fn get_sorting_columns(
+    df: &DataFrame,
+    parquet_schema: &SchemaDescriptor,
+) -> Option<Vec<SortingColumn>> {
+    let sorting_columns = df
+        .get_columns()
+        .iter()
+        .filter_map(|c| get_sorting_column(c, parquet_schema))
+        .collect::<Vec<_>>();
+
+    (!sorting_columns.is_empty()).then_some(sorting_columns)
+}
+
+fn write_compressed_row_group<W: Write>(
+    writer: &mut FileWriter<W>,
+    rg: &[Vec<CompressedPage>],
+    sorting_columns: Option<Vec<SortingColumn>>,
+) -> PolarsResult<()> {
+    let rg = DynIter::new(rg.iter().map(|col_pages| {
+        Ok(DynStreamingIterator::new(
+            fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
+        ))
+    }));
+    writer.write_with_sorting_columns(rg, sorting_columns)
+}
+
+/// Encode and compress all columns of `batch` into the pages of a row group.
+fn encode_and_compress_row_group(
+    batch: RecordBatch,
+    fields: &[ParquetType],
+    column_options: &[ColumnWriteOptions],
+    options: WriteOptions,
+) -> PolarsResult<Vec<Vec<CompressedPage>>> {
+    let mut row_group = Vec::with_capacity(batch.columns().len());
+    for ((array, type_), column_options) in batch.columns().iter().zip(fields).zip(column_options) {
+        let encoded_columns = array_to_columns(array, type_.clone(), column_options, options)?;
+        for encoded_pages in encoded_columns {
+            let pages = Compressor::new_from_vec(
+                encoded_pages.map(|result| {
+                    result.map_err(|e| {
+                        ParquetError::FeatureNotSupported(format!("reraised in polars: {e}",))
+                    })
+                }),
+                options.compression,
+                vec![],
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+            row_group.push(pages);
+        }
+    }
+    Ok(row_group)
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
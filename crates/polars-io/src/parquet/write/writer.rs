@@ -44,6 +44,8 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Write the row groups in the order of the [`DataFrame`]
+    maintain_order: bool,
     field_overwrites: Vec<ParquetFieldOverwrites>,
     /// Custom file-level key value metadata
     key_value_metadata: Option<KeyValueMetadata>,
@@ -67,6 +69,7 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            maintain_order: true,
             field_overwrites: Vec::new(),
             key_value_metadata: None,
             context_info: None,
@@ -107,6 +110,15 @@ where
         self
     }
 
+    /// Write the row groups in the order of the [`DataFrame`]. Defaults to `true`.
+    ///
+    /// If `false`, a parallel writer encodes the row groups concurrently and writes every row
+    /// group as soon as it is encoded. The rows within a row group keep their order.
+    pub fn with_maintain_order(mut self, maintain_order: bool) -> Self {
+        self.maintain_order = maintain_order;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
@@ -137,6 +149,7 @@ where
             column_options,
             options,
             parallel: self.parallel,
+            maintain_order: self.maintain_order,
             key_value_metadata: self.key_value_metadata,
         })
     }
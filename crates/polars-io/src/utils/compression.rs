@@ -4,6 +4,7 @@ use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
 
 /// Represents the compression algorithms that we have decoders for
+#[derive(Clone, Copy)]
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
@@ -62,3 +63,39 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Decompress at most `n_bytes` of `bytes` into `out`, which must be empty. Returns the number of
+/// compressed bytes that were consumed.
+pub fn decompress_prefix(
+    bytes: &[u8],
+    algo: SupportedCompression,
+    out: &mut Vec<u8>,
+    n_bytes: usize,
+) -> PolarsResult<usize> {
+    assert!(out.is_empty());
+
+    feature_gated!("decompress", {
+        let mut remaining = bytes;
+        match algo {
+            SupportedCompression::GZIP => {
+                flate2::bufread::MultiGzDecoder::new(&mut remaining)
+                    .take(n_bytes as u64)
+                    .read_to_end(out)
+                    .map_err(to_compute_err)?;
+            },
+            SupportedCompression::ZLIB => {
+                flate2::bufread::ZlibDecoder::new(&mut remaining)
+                    .take(n_bytes as u64)
+                    .read_to_end(out)
+                    .map_err(to_compute_err)?;
+            },
+            SupportedCompression::ZSTD => {
+                zstd::Decoder::with_buffer(&mut remaining)?
+                    .take(n_bytes as u64)
+                    .read_to_end(out)?;
+            },
+        }
+
+        Ok(bytes.len() - remaining.len())
+    })
+}
@@ -78,6 +78,21 @@ pub fn columns_to_projection<T: AsRef<str>>(
     Ok(prj)
 }
 
+#[cfg(feature = "json")]
+pub fn projection_to_columns(
+    projection: &[usize],
+    schema: &Schema,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    projection
+        .iter()
+        .map(|&i| {
+            schema.get_at_index(i).map(|(name, _)| name.clone()).ok_or_else(
+                || polars_err!(OutOfBounds: "projection index {} is out of bounds for schema with length {}", i, schema.len()),
+            )
+        })
+        .collect()
+}
+
 #[cfg(debug_assertions)]
 fn check_offsets(dfs: &[DataFrame]) {
     dfs.windows(2).for_each(|s| {
@@ -90,6 +105,28 @@ fn check_offsets(dfs: &[DataFrame]) {
     })
 }
 
+/// Slice the chunks of `df` that are longer than `chunk_size` rows. Shorter chunks are kept as is,
+/// so no data is copied.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub(crate) fn split_to_chunk_size(df: &mut DataFrame, chunk_size: usize) {
+    use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+    let chunk_size = chunk_size.max(1);
+    if df.height() <= chunk_size {
+        return;
+    }
+
+    let chunks = df
+        .split_chunks()
+        .flat_map(|chunk| {
+            (0..chunk.height())
+                .step_by(chunk_size)
+                .map(move |offset| chunk.slice(offset as i64, chunk_size))
+        })
+        .collect::<Vec<_>>();
+    *df = accumulate_dataframes_vertical_unchecked(chunks);
+}
+
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
 #[cfg(any(feature = "csv", feature = "json"))]
@@ -22,7 +22,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
             .ok_or_else(|| polars_err!(NoData: "empty container given"))?,
     );
 
-    let opt_state = lf.opt_state;
+    let opt_state = lf.get_opt_state();
     let cached_arenas = lf.cached_arena.clone();
 
     let mut lps = Vec::with_capacity(inputs.len());
@@ -56,7 +56,7 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     let lfs = inputs.as_ref();
     let (opt_state, cached_arena) = lfs
         .first()
-        .map(|lf| (lf.opt_state, lf.cached_arena.clone()))
+        .map(|lf| (lf.get_opt_state(), lf.cached_arena.clone()))
         .ok_or_else(
             || polars_err!(NoData: "Require at least one LazyFrame for horizontal concatenation"),
         )?;
@@ -76,6 +76,61 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Concat multiple [`LazyFrame`]s vertically, keeping one row per distinct value of `subset`,
+/// or of all columns if `subset` is `None`.
+///
+/// Every input is deduplicated before the union, so duplicates within an input are dropped
+/// before the inputs are concatenated. If `args.maintain_order` is set, the first occurrence of
+/// every row is kept in its original position.
+pub fn concat_distinct<L: AsRef<[LazyFrame]>>(
+    inputs: L,
+    subset: Option<Selector>,
+    args: UnionArgs,
+) -> PolarsResult<LazyFrame> {
+    let distinct = |lf: LazyFrame| {
+        if args.maintain_order {
+            lf.unique_stable(subset.clone(), UniqueKeepStrategy::First)
+        } else {
+            lf.unique(subset.clone(), UniqueKeepStrategy::Any)
+        }
+    };
+    let inputs = inputs
+        .as_ref()
+        .iter()
+        .cloned()
+        .map(distinct)
+        .collect::<Vec<_>>();
+    Ok(distinct(concat(inputs, args)?))
+}
+
+/// Merge multiple [`LazyFrame`]s that are each sorted by `key` into a single sorted [`LazyFrame`].
+///
+/// The inputs are merged pairwise in a balanced tree, so every row passes through `log(k)`
+/// merges. Rows with equal keys keep the order of their inputs.
+#[cfg(feature = "merge_sorted")]
+pub fn merge_sorted<L, S>(inputs: L, key: S) -> PolarsResult<LazyFrame>
+where
+    L: AsRef<[LazyFrame]>,
+    S: Into<PlSmallStr>,
+{
+    let key = key.into();
+    let mut lfs = inputs.as_ref().to_vec();
+    polars_ensure!(!lfs.is_empty(), NoData: "empty container given");
+
+    while lfs.len() > 1 {
+        let mut merged = Vec::with_capacity(lfs.len().div_ceil(2));
+        let mut iter = lfs.into_iter();
+        while let Some(left) = iter.next() {
+            merged.push(match iter.next() {
+                Some(right) => left.merge_sorted(right, key.clone())?,
+                None => left,
+            });
+        }
+        lfs = merged;
+    }
+    Ok(lfs.pop().unwrap())
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
@@ -92,6 +147,63 @@ mod test {
     #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    #[cfg(feature = "merge_sorted")]
+    fn test_merge_sorted_many() -> PolarsResult<()> {
+        let a = df![
+            "k" => [1, 4, 7],
+            "src" => ["a", "a", "a"]
+        ]?;
+        let b = df![
+            "k" => [2, 4, 8],
+            "src" => ["b", "b", "b"]
+        ]?;
+        let c = df![
+            "k" => [0, 4, 9],
+            "src" => ["c", "c", "c"]
+        ]?;
+
+        let out = merge_sorted([a.lazy(), b.lazy(), c.lazy()], "k")?.collect()?;
+        let expected = df![
+            "k" => [0, 1, 2, 4, 4, 4, 7, 8, 9],
+            "src" => ["c", "a", "b", "a", "b", "c", "a", "b", "c"]
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_distinct() -> PolarsResult<()> {
+        let a = df![
+            "k" => [1, 2, 2, 3],
+            "v" => ["a", "b", "b", "c"]
+        ]?;
+        let b = df![
+            "k" => [3, 4, 1, 4],
+            "v" => ["c", "d", "x", "d"]
+        ]?;
+        let args = UnionArgs {
+            maintain_order: true,
+            ..Default::default()
+        };
+
+        let out = concat_distinct([a.clone().lazy(), b.clone().lazy()], None, args)?.collect()?;
+        let expected = df![
+            "k" => [1, 2, 3, 4, 1],
+            "v" => ["a", "b", "c", "d", "x"]
+        ]?;
+        assert!(out.equals(&expected));
+
+        let subset = Some(by_name(["k"], true));
+        let out = concat_distinct([a.lazy(), b.lazy()], subset, args)?.collect()?;
+        let expected = df![
+            "k" => [1, 2, 3, 4],
+            "v" => ["a", "b", "c", "d"]
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "diagonal_concat")]
     fn test_diag_concat_lf() -> PolarsResult<()> {
@@ -132,4 +244,48 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "diagonal_concat")]
+    fn test_diag_concat_lf_to_supertypes() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32, 2],
+            "b" => ["a", "b"]
+        ]?;
+
+        let b = df![
+            "a" => [3i64],
+            "c" => [1.5]
+        ]?;
+
+        let out = concat_lf_diagonal(
+            &[a.clone().lazy(), b.lazy()],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+
+        let expected = df![
+            "a" => [1i64, 2, 3],
+            "b" => [Some("a"), Some("b"), None],
+            "c" => [None, None, Some(1.5)]
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let c = DataFrame::new(vec![Column::new("a".into(), [b"x".as_slice()])])?;
+        let err = concat_lf_diagonal(
+            &[a.lazy(), c.lazy()],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?
+        .collect()
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot merge field"));
+
+        Ok(())
+    }
 }
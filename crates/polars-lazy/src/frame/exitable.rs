@@ -40,6 +40,18 @@ impl LazyFrame {
             token,
         })
     }
+
+    /// Execute the query on the current thread and abort it with an error once `token` is set to
+    /// `true`, e.g. from another thread.
+    ///
+    /// The token is checked before every node of the plan is executed, and joins and group-bys
+    /// also check it once their inputs are computed and between aggregations. A kernel that is
+    /// already running, such as a sort or the probe of a join, is not interrupted.
+    pub fn collect_with_token(self, token: Arc<RelaxedCell<bool>>) -> PolarsResult<DataFrame> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
+        state.set_cancel_token(token);
+        physical_plan.execute(&mut state)
+    }
 }
 
 #[derive(Clone)]
@@ -17,6 +17,8 @@ pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub use flight::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -30,7 +32,7 @@ use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::RowIndex;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
-use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_ops::frame::{JoinBuildSide, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
@@ -52,6 +54,7 @@ impl IntoLazy for DataFrame {
         LazyFrame {
             logical_plan: lp,
             opt_state: Default::default(),
+            memory_limit: None,
             cached_arena: Default::default(),
         }
     }
@@ -72,6 +75,7 @@ impl IntoLazy for LazyFrame {
 pub struct LazyFrame {
     pub logical_plan: DslPlan,
     pub(crate) opt_state: OptFlags,
+    pub(crate) memory_limit: Option<usize>,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
 }
 
@@ -80,20 +84,29 @@ impl From<DslPlan> for LazyFrame {
         Self {
             logical_plan: plan,
             opt_state: OptFlags::default(),
+            memory_limit: None,
             cached_arena: Default::default(),
         }
     }
 }
 
+/// The options of a [`LazyFrame`] that are inherited by every frame derived from it.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct FrameOptions {
+    opt_state: OptFlags,
+    memory_limit: Option<usize>,
+}
+
 impl LazyFrame {
     pub(crate) fn from_inner(
         logical_plan: DslPlan,
-        opt_state: OptFlags,
+        options: FrameOptions,
         cached_arena: Arc<Mutex<Option<CachedArena>>>,
     ) -> Self {
         Self {
             logical_plan,
-            opt_state,
+            opt_state: options.opt_state,
+            memory_limit: options.memory_limit,
             cached_arena,
         }
     }
@@ -102,16 +115,15 @@ impl LazyFrame {
         DslBuilder::from(self.logical_plan)
     }
 
-    fn get_opt_state(&self) -> OptFlags {
-        self.opt_state
+    pub(crate) fn get_opt_state(&self) -> FrameOptions {
+        FrameOptions {
+            opt_state: self.opt_state,
+            memory_limit: self.memory_limit,
+        }
     }
 
-    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptFlags) -> Self {
-        LazyFrame {
-            logical_plan,
-            opt_state,
-            cached_arena: Default::default(),
-        }
+    fn from_logical_plan(logical_plan: DslPlan, options: FrameOptions) -> Self {
+        Self::from_inner(logical_plan, options, Default::default())
     }
 
     /// Get current optimizations.
@@ -148,6 +160,20 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle reordering of inner join chains based on the estimated number of rows of the
+    /// joined relations.
+    pub fn with_reorder_joins(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::REORDER_JOINS, toggle);
+        self
+    }
+
+    /// Toggle filtering of scans that are joined with an in-memory [`DataFrame`] on the distinct
+    /// join keys of that [`DataFrame`].
+    pub fn with_join_pruning(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_PRUNING, toggle);
+        self
+    }
+
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
@@ -217,6 +243,27 @@ impl LazyFrame {
         self
     }
 
+    /// Abort the query with an error once more than `bytes` are allocated by the process.
+    ///
+    /// The limit is inherited by every frame derived from this one and applies to all engines:
+    /// the in-memory engine checks it before every node is executed, the streaming engine
+    /// (including `sink_*`) before every phase of the pipeline graph. A running streaming
+    /// phase is not interrupted, so peak memory can exceed the limit by the memory that phase
+    /// needs. This requires [`TrackingAllocator`](polars_core::memory::TrackingAllocator) to be
+    /// installed as the global allocator, otherwise collecting returns an error.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    fn execution_state(&self) -> ExecutionState {
+        let mut state = ExecutionState::new();
+        if self.memory_limit.is_some() {
+            state.set_memory_limit(self.memory_limit);
+        }
+        state
+    }
+
     /// Return a String describing the naive (un-optimized) logical plan.
     pub fn describe_plan(&self) -> PolarsResult<String> {
         Ok(self.clone().to_alp()?.describe())
@@ -253,6 +300,16 @@ impl LazyFrame {
         }
     }
 
+    /// Optimize the logical plan and return the places where an optimization had to stop, with
+    /// the reason why. This can be used to find out why e.g. a predicate was not pushed down.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn optimizer_trace(&self) -> PolarsResult<Vec<OptimizerTraceEvent>> {
+        let (plan, events) = record_optimizer_trace(|| self.clone().to_alp_optimized());
+        plan?;
+        Ok(events)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -416,6 +473,17 @@ impl LazyFrame {
         })
     }
 
+    /// Rename all columns in the LazyFrame by applying `function` to every column name.
+    ///
+    /// Raises a [`PolarsError::Duplicate`] error while materializing the [`LazyFrame`] if
+    /// the new names are not unique.
+    pub fn rename_with<F>(self, function: F) -> Self
+    where
+        F: Fn(&PlSmallStr) -> PolarsResult<PlSmallStr> + 'static + Send + Sync,
+    {
+        self.select(vec![col(PlSmallStr::from_static("*")).name().map(function)])
+    }
+
     /// Removes columns from the DataFrame.
     /// Note that it's better to only select the columns you need
     /// and let the projection pushdown optimize away the unneeded columns.
@@ -515,6 +583,35 @@ impl LazyFrame {
         Ok(IRPlan::new(node, lp_arena, expr_arena))
     }
 
+    /// Optimize the query and serialize the optimized plan as JSON.
+    ///
+    /// The plan can be deserialized as an [`IRPlan`] in another process and executed there with
+    /// [`LazyFrame::collect_optimized_plan`]. Fails if the plan contains parts that cannot be
+    /// serialized, such as Rust UDFs.
+    #[cfg(feature = "ir_serde")]
+    pub fn serialize_optimized_plan_json<W: std::io::Write>(self, writer: W) -> PolarsResult<()> {
+        let plan = self.to_alp_optimized()?;
+        serde_json::to_writer(writer, &plan).map_err(polars_core::error::to_compute_err)
+    }
+
+    /// Execute an already optimized plan with the in-memory engine, e.g. one that was created by
+    /// [`LazyFrame::serialize_optimized_plan_json`]. No further optimizations are applied.
+    pub fn collect_optimized_plan(plan: IRPlan) -> PolarsResult<DataFrame> {
+        let IRPlan {
+            lp_top,
+            mut lp_arena,
+            mut expr_arena,
+        } = plan;
+        let mut physical_plan = create_physical_plan(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            BUILD_STREAMING_EXECUTOR,
+        )?;
+        let mut state = ExecutionState::new();
+        physical_plan.execute(&mut state)
+    }
+
     pub fn to_alp(mut self) -> PolarsResult<IRPlan> {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
         let node = to_alp(
@@ -614,7 +711,7 @@ impl LazyFrame {
             BUILD_STREAMING_EXECUTOR,
         )?;
 
-        let state = ExecutionState::new();
+        let state = self.execution_state();
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -692,6 +789,7 @@ impl LazyFrame {
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    self.memory_limit,
                 );
                 result.map(|v| v.unwrap_single())
             }),
@@ -705,7 +803,7 @@ impl LazyFrame {
                     &mut alp_plan.expr_arena,
                     BUILD_STREAMING_EXECUTOR,
                 )?;
-                let mut state = ExecutionState::new();
+                let mut state = self.execution_state();
                 physical_plan.execute(&mut state)
             },
         }
@@ -715,6 +813,7 @@ impl LazyFrame {
         let sink_multiple = LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
+            memory_limit: None,
             cached_arena: Default::default(),
         };
         sink_multiple.explain(true)
@@ -741,6 +840,7 @@ impl LazyFrame {
         let mut sink_multiple = LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
+            memory_limit: None,
             cached_arena: Default::default(),
         };
 
@@ -769,6 +869,7 @@ impl LazyFrame {
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    None,
                 );
                 return result.map(|v| v.unwrap_multiple());
             });
@@ -844,6 +945,15 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute the query and abort it with an error once more than `limit` bytes are
+    /// allocated by the process.
+    ///
+    /// Shorthand for `self.with_memory_limit(limit).collect()`, see
+    /// [`with_memory_limit`](Self::with_memory_limit).
+    pub fn collect_with_memory_limit(self, limit: usize) -> PolarsResult<DataFrame> {
+        self.with_memory_limit(limit).collect()
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
     pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
@@ -868,7 +978,8 @@ impl LazyFrame {
     ///
     /// This will run the query and return a tuple
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
-    /// of each node that is executed.
+    /// of each node that is executed: its `start` and `end` time and the number of rows it
+    /// produced (`rows_emitted`), if known.
     ///
     /// The units of the timings are microseconds.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
@@ -1085,6 +1196,7 @@ impl LazyFrame {
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    self.memory_limit,
                 )
             };
 
@@ -1577,6 +1689,7 @@ impl LazyFrame {
             nulls_equal,
             coalesce,
             maintain_order,
+            build_side,
         } = args;
 
         if slice.is_some() {
@@ -1592,7 +1705,8 @@ impl LazyFrame {
             .validate(validation)
             .join_nulls(nulls_equal)
             .coalesce(coalesce)
-            .maintain_order(maintain_order);
+            .maintain_order(maintain_order)
+            .build_side(build_side);
 
         if let Some(suffix) = suffix {
             builder = builder.suffix(suffix);
@@ -1926,6 +2040,37 @@ impl LazyFrame {
         self.slice(neg_tail, n)
     }
 
+    /// Collect the first `n_head` and the last `n_tail` rows of the query, e.g. to inspect a large
+    /// dataset.
+    ///
+    /// The head and tail are taken with slices that are pushed down to the scans, so file scans
+    /// only read the rows they need. The number of rows of the query is computed first, which for
+    /// file scans only reads the metadata. Rows are never returned twice: if the query has fewer
+    /// than `n_head + n_tail` rows, every row is returned once.
+    pub fn preview(self, n_head: IdxSize, n_tail: IdxSize) -> PolarsResult<DataFrame> {
+        let height = self.clone().select([len()]).collect()?;
+        let height = height.get_columns()[0].idx()?.get(0).unwrap_or(0);
+        self.preview_query(height, n_head, n_tail)?.collect()
+    }
+
+    /// The query of [`LazyFrame::preview`] for an input of `height` rows.
+    pub(crate) fn preview_query(
+        self,
+        height: IdxSize,
+        n_head: IdxSize,
+        n_tail: IdxSize,
+    ) -> PolarsResult<LazyFrame> {
+        if height <= n_head.saturating_add(n_tail) {
+            return Ok(self);
+        }
+        let head = self.clone().slice(0, n_head);
+        let tail = self.tail(n_tail);
+        let mut out = concat([head, tail], UnionArgs::default())?;
+        // Caching the shared input would stop the slices from reaching the scans.
+        out.opt_state.set(OptFlags::COMM_SUBPLAN_ELIM, false);
+        Ok(out)
+    }
+
     /// Unpivot the DataFrame from wide to long format.
     ///
     /// See [`UnpivotArgsIR`] for information on how to unpivot a DataFrame.
@@ -1936,6 +2081,42 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// Every value in `on_columns` becomes a column holding the (aggregated) `values` for each
+    /// group of `index`. As the output schema has to be known before the data is, the values of
+    /// the `on` column have to be given upfront. Values of `on` that are not in `on_columns` are
+    /// ignored. Values in `on_columns` that don't occur in the data produce a column filled with
+    /// the aggregation of an empty group, like any other missing `index`/`on` combination.
+    ///
+    /// See [`pivot::pivot_stable`] for the eager variant.
+    #[cfg(feature = "pivot")]
+    pub fn pivot<I0, I1, S0, S1>(
+        self,
+        on: impl Into<PlSmallStr>,
+        on_columns: &Series,
+        index: I0,
+        values: I1,
+        agg_expr: Option<Expr>,
+        separator: Option<&str>,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I0: IntoIterator<Item = S0>,
+        I1: IntoIterator<Item = S1>,
+        S0: Into<PlSmallStr>,
+        S1: Into<PlSmallStr>,
+    {
+        pivot::pivot_lazy(
+            self,
+            on.into(),
+            on_columns,
+            index.into_iter().map(Into::into).collect(),
+            values.into_iter().map(Into::into).collect(),
+            agg_expr,
+            separator,
+        )
+    }
+
     /// Limit the DataFrame to the first `n` rows.
     pub fn limit(self, n: IdxSize) -> LazyFrame {
         self.slice(0, n)
@@ -2062,13 +2243,14 @@ impl LazyFrame {
         S: Into<PlSmallStr>,
     {
         let key = key.into();
+        let opt_state = self.get_opt_state();
 
         let lp = DslPlan::MergeSorted {
             input_left: Arc::new(self.logical_plan),
             input_right: Arc::new(other.logical_plan),
             key,
         };
-        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+        Ok(LazyFrame::from_logical_plan(lp, opt_state))
     }
 }
 
@@ -2076,7 +2258,7 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct LazyGroupBy {
     pub logical_plan: DslPlan,
-    opt_state: OptFlags,
+    opt_state: FrameOptions,
     keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
@@ -2087,11 +2269,7 @@ pub struct LazyGroupBy {
 
 impl From<LazyGroupBy> for LazyFrame {
     fn from(lgb: LazyGroupBy) -> Self {
-        Self {
-            logical_plan: lgb.logical_plan,
-            opt_state: lgb.opt_state,
-            cached_arena: Default::default(),
-        }
+        Self::from_logical_plan(lgb.logical_plan, lgb.opt_state)
     }
 }
 
@@ -2202,6 +2380,7 @@ pub struct JoinBuilder {
     nulls_equal: bool,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
+    build_side: Option<JoinBuildSide>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2219,6 +2398,7 @@ impl JoinBuilder {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            build_side: None,
         }
     }
 
@@ -2305,9 +2485,18 @@ impl JoinBuilder {
         self
     }
 
+    /// Force the side the hash table is built on, instead of letting the engine choose.
+    ///
+    /// Only the streaming engine supports this. The in-memory engine raises an error, as does a
+    /// `maintain_order` that requires the other build side.
+    pub fn build_side(mut self, build_side: Option<JoinBuildSide>) -> Self {
+        self.build_side = build_side;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let opt_state = self.lf.get_opt_state();
         let other = self.other.expect("'with' not set in join builder");
 
         let args = JoinArgs {
@@ -2318,6 +2507,7 @@ impl JoinBuilder {
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            build_side: self.build_side,
         };
 
         let lp = self
@@ -2340,7 +2530,7 @@ impl JoinBuilder {
 
     // Finish with join predicates
     pub fn join_where(self, predicates: Vec<Expr>) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let opt_state = self.lf.get_opt_state();
         let other = self.other.expect("with not set");
 
         // Decompose `And` conjunctions into their component expressions
@@ -2409,6 +2599,7 @@ impl JoinBuilder {
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            build_side: self.build_side,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
@@ -2494,12 +2685,13 @@ mod streaming_dispatch {
     }
 
     impl Executor for StreamingQueryExecutor {
-        fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        fn execute(&mut self, cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
             // Must not block rayon thread on pending new-streaming future.
             assert!(POOL.current_thread_index().is_none());
 
             let mut df = { self.executor.try_lock().unwrap().take() }
                 .expect("unhandled: execute() more than once")
+                .with_memory_limit(cache.memory_limit())
                 .execute()
                 .map(|x| x.unwrap_single())?;
 
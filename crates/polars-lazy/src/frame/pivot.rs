@@ -1,16 +1,18 @@
 //! Module containing implementation of the pivot operation.
 //!
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
-//! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
-//! workflows. An optimization can never be pushed down past a pivot.
+//! In general it is impossible to know the schema of a pivot without materializing the whole
+//! dataset. This makes a pivot quite a terrible operation for performant workflows. An
+//! optimization can never be pushed down past a pivot.
 //!
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
-//! pivot is here, because we want to be able to pass expressions to the pivot operation.
+//! pivot is here, because we want to be able to pass expressions to the pivot operation. A
+//! `LazyFrame` can only be pivoted if the values that become the new columns are given upfront.
 //!
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_ops::pivot::PivotAgg;
+use polars_utils::format_pl_smallstr;
 
 use crate::physical_plan::exotic::{contains_column_refs, prepare_expression_for_context};
 use crate::prelude::*;
@@ -98,3 +100,160 @@ where
     let agg_expr = agg_expr.map(|ae| PivotAgg(Arc::new(PivotExpr(ae))));
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+/// Names of the columns that are created by pivoting the `values` columns on `on_columns`.
+fn pivot_column_names(
+    values: &[PlSmallStr],
+    on_columns: &StringChunked,
+    separator: &str,
+) -> Vec<PlSmallStr> {
+    if values.len() > 1 {
+        values
+            .iter()
+            .flat_map(|value| {
+                on_columns
+                    .into_no_null_iter()
+                    .map(move |v| format_pl_smallstr!("{value}{separator}{v}"))
+            })
+            .collect()
+    } else {
+        on_columns
+            .into_no_null_iter()
+            .map(PlSmallStr::from_str)
+            .collect()
+    }
+}
+
+fn pivot_schema(
+    input_schema: &Schema,
+    index: &[PlSmallStr],
+    values: &[PlSmallStr],
+    names: &[PlSmallStr],
+    agg_expr: Option<&Expr>,
+) -> PolarsResult<Schema> {
+    let mut schema = Schema::with_capacity(index.len() + names.len());
+    for name in index {
+        schema.insert(name.clone(), input_schema.try_get(name)?.clone());
+    }
+
+    let mut names = names.iter();
+    let n_on_columns = names.len() / values.len();
+    for value in values {
+        let dtype = input_schema.try_get(value)?;
+        let dtype = match agg_expr {
+            Some(agg_expr) => {
+                let element_schema =
+                    Schema::from_iter([Field::new(PlSmallStr::EMPTY, dtype.clone())]);
+                agg_expr.to_field(&element_schema)?.dtype
+            },
+            None => dtype.clone(),
+        };
+        for name in names.by_ref().take(n_on_columns) {
+            schema.insert(name.clone(), dtype.clone());
+        }
+    }
+    Ok(schema)
+}
+
+/// The result of the aggregation on an empty column, used for the cells without any values.
+fn agg_on_empty(agg_expr: Option<&Expr>, dtype: &DataType) -> PolarsResult<Scalar> {
+    let Some(agg_expr) = agg_expr else {
+        return Ok(Scalar::null(dtype.clone()));
+    };
+    let out = Column::new_empty(PlSmallStr::EMPTY, dtype)
+        .into_frame()
+        .lazy()
+        .select([agg_expr.clone()])
+        .collect()?;
+    let c = &out.get_columns()[0];
+    let value = c.get(0).unwrap_or_default().into_static();
+    Ok(Scalar::new(c.dtype().clone(), value))
+}
+
+/// Pivot a [`LazyFrame`] on a single column with the values of that column known upfront.
+///
+/// See [`LazyFrame::pivot`].
+pub(crate) fn pivot_lazy(
+    lf: LazyFrame,
+    on: PlSmallStr,
+    on_columns: &Series,
+    index: Vec<PlSmallStr>,
+    values: Vec<PlSmallStr>,
+    agg_expr: Option<Expr>,
+    separator: Option<&str>,
+) -> PolarsResult<LazyFrame> {
+    polars_ensure!(!index.is_empty(), InvalidOperation: "`index` cannot be empty in `pivot` operation");
+    polars_ensure!(!values.is_empty(), InvalidOperation: "`values` cannot be empty in `pivot` operation");
+    polars_ensure!(
+        on_columns.null_count() == 0,
+        InvalidOperation: "`on_columns` cannot contain nulls in `pivot` operation"
+    );
+    if agg_expr.as_ref().is_some_and(contains_column_refs) {
+        polars_bail!(InvalidOperation: "explicit column references are not allowed in aggregate_function");
+    }
+
+    let separator = PlSmallStr::from_str(separator.unwrap_or("_"));
+    let on_columns = on_columns.cast(&DataType::String)?;
+    let names: Arc<[PlSmallStr]> =
+        pivot_column_names(&values, on_columns.str()?, &separator).into();
+
+    let input_columns = std::iter::once(&on)
+        .chain(&index)
+        .chain(&values)
+        .map(|name| col(name.clone()))
+        .collect::<Vec<_>>();
+
+    let schema_fn = {
+        let (index, values, names, agg_expr) = (
+            index.clone(),
+            values.clone(),
+            names.clone(),
+            agg_expr.clone(),
+        );
+        move |input_schema: &Schema| {
+            pivot_schema(input_schema, &index, &values, &names, agg_expr.as_ref()).map(Arc::new)
+        }
+    };
+
+    let function = move |df: DataFrame| {
+        let schema = pivot_schema(&df.schema(), &index, &values, &names, agg_expr.as_ref())?;
+        let out = pivot_stable(
+            &df,
+            [on.clone()],
+            Some(index.iter().cloned()),
+            Some(values.iter().cloned()),
+            false,
+            agg_expr.clone(),
+            Some(separator.as_str()),
+        )?;
+
+        // Values of `on` that are not in `on_columns` are dropped. Values in `on_columns` that
+        // don't occur in this frame get the same default as missing `index`/`on` combinations.
+        let n_on_columns = names.len() / values.len();
+        let height = out.height();
+        let columns = schema
+            .iter()
+            .enumerate()
+            .map(|(i, (name, dtype))| match out.column(name) {
+                Ok(c) => c.cast(dtype),
+                Err(_) => {
+                    let value = &values[(i - index.len()) / n_on_columns];
+                    let default = agg_on_empty(agg_expr.as_ref(), df.column(value)?.dtype())?;
+                    Column::new_scalar(name.clone(), default, height).cast(dtype)
+                },
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    };
+
+    // The output columns don't exist in the input, so nothing can be pushed down past the pivot.
+    // The pivot also needs to see all rows at once.
+    let optimizations = OptFlags::default()
+        & !(OptFlags::PREDICATE_PUSHDOWN | OptFlags::PROJECTION_PUSHDOWN | OptFlags::NEW_STREAMING);
+    Ok(lf.select(input_columns).map(
+        function,
+        optimizations,
+        Some(Arc::new(schema_fn)),
+        Some("PIVOT"),
+    ))
+}
@@ -11,6 +11,7 @@ use polars_utils::mmap::MemSlice;
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
+use super::file_list_reader::select_scan_columns;
 use crate::prelude::*;
 
 #[derive(Clone)]
@@ -92,6 +93,21 @@ impl LazyCsvReader {
         self
     }
 
+    /// Only keep the columns with these names.
+    #[must_use]
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.read_options.columns = columns;
+        self
+    }
+
+    /// Only keep the columns at these indices. This counts from 0, meaning that `vec![0, 4]`
+    /// would select the 1st and 5th column.
+    #[must_use]
+    pub fn with_projection(mut self, projection: Option<Arc<Vec<usize>>>) -> Self {
+        self.read_options.projection = projection;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -99,6 +115,13 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set the CSV file's schema and error if a file does not adhere to it.
+    #[must_use]
+    pub fn with_strict_schema(mut self, schema: SchemaRef) -> Self {
+        self.read_options = self.read_options.with_strict_schema(schema);
+        self
+    }
+
     /// Skip the first `n` rows during parsing. The header will be parsed at row `n`.
     /// Note that by row we mean valid CSV, encoding and comments are respected.
     #[must_use]
@@ -322,10 +345,13 @@ impl LazyCsvReader {
 
 impl LazyFileListReader for LazyCsvReader {
     /// Get the final [LazyFrame].
-    fn finish(self) -> PolarsResult<LazyFrame> {
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
         let rechunk = self.rechunk();
         let row_index = self.row_index().cloned();
         let pre_slice = self.n_rows().map(|len| Slice::Positive { offset: 0, len });
+        // These are applied as a projection on the scan.
+        let columns = self.read_options.columns.take();
+        let projection = self.read_options.projection.take();
 
         let lf: LazyFrame = DslBuilder::scan_csv(
             self.sources,
@@ -351,7 +377,11 @@ impl LazyFileListReader for LazyCsvReader {
         )?
         .build()
         .into();
-        Ok(lf)
+        Ok(select_scan_columns(
+            lf,
+            columns.as_deref(),
+            projection.as_deref().map(Vec::as_slice),
+        ))
     }
 
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
@@ -121,3 +121,30 @@ pub trait LazyFileListReader: Clone {
         None
     }
 }
+
+/// Only keep the `columns` of a scan, or else the columns at `column_indices` in its output.
+/// Projection pushdown moves this selection into the scan itself.
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "json",
+    feature = "parquet"
+))]
+pub(super) fn select_scan_columns(
+    lf: LazyFrame,
+    columns: Option<&[PlSmallStr]>,
+    column_indices: Option<&[usize]>,
+) -> LazyFrame {
+    if let Some(columns) = columns {
+        lf.select(columns.iter().cloned().map(col).collect::<Vec<_>>())
+    } else if let Some(column_indices) = column_indices {
+        lf.select(
+            column_indices
+                .iter()
+                .map(|&i| nth(i as i64).as_expr())
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        lf
+    }
+}
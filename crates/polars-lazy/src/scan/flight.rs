@@ -0,0 +1,87 @@
+use std::any::Any;
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_io::SerReader;
+use polars_io::ipc::IpcStreamReader;
+
+use crate::prelude::*;
+
+/// A request for the record batches of a Flight stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlightDescriptor {
+    /// Opaque ticket that identifies the stream on the server.
+    pub ticket: Arc<[u8]>,
+    /// The columns the query needs. `None` requests all columns.
+    pub columns: Option<Arc<[PlSmallStr]>>,
+}
+
+/// Transport to an Arrow Flight server.
+///
+/// Polars doesn't ship a gRPC stack, implement this trait on top of the Flight client of your
+/// choice.
+pub trait FlightClient: Send + Sync {
+    /// Get the schema of the stream identified by `ticket` (`GetSchema`).
+    fn get_schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef>;
+
+    /// Fetch the stream for `descriptor` (`DoGet`).
+    ///
+    /// Returns the Arrow IPC stream carried by the `FlightData` messages. The server may ignore
+    /// `descriptor.columns`, columns that are not needed are dropped after reading.
+    fn do_get(&self, descriptor: &FlightDescriptor) -> PolarsResult<Box<dyn Read + Send>>;
+}
+
+struct FlightScan {
+    client: Arc<dyn FlightClient>,
+    ticket: Arc<[u8]>,
+}
+
+impl AnonymousScan for FlightScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let descriptor = FlightDescriptor {
+            ticket: self.ticket.clone(),
+            columns: scan_opts.with_columns.clone(),
+        };
+        let reader = self.client.do_get(&descriptor)?;
+        let columns = scan_opts
+            .with_columns
+            .map(|columns| columns.iter().map(|c| c.to_string()).collect());
+
+        IpcStreamReader::new(reader)
+            .with_columns(columns)
+            .with_n_rows(scan_opts.n_rows)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        self.client.get_schema(&self.ticket)
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a [`LazyFrame`] from the stream identified by `ticket` on an Arrow Flight server.
+    ///
+    /// The columns selected by the query are passed to the server in the [`FlightDescriptor`].
+    pub fn scan_flight(client: Arc<dyn FlightClient>, ticket: Arc<[u8]>) -> PolarsResult<Self> {
+        let function = Arc::new(FlightScan { client, ticket });
+        Self::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                name: "FLIGHT SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
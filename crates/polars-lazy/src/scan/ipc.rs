@@ -5,6 +5,7 @@ use polars_io::{HiveOptions, RowIndex};
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
+use super::file_list_reader::select_scan_columns;
 use crate::prelude::*;
 
 #[derive(Clone)]
@@ -16,6 +17,12 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Maximum number of rows per output chunk.
+    pub chunk_size: Option<usize>,
+    /// Only keep the columns with these names.
+    pub columns: Option<Arc<[PlSmallStr]>>,
+    /// Only keep the columns at these indices, counting from 0.
+    pub projection: Option<Arc<[usize]>>,
 }
 
 impl Default for ScanArgsIpc {
@@ -28,6 +35,9 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            chunk_size: None,
+            columns: None,
+            projection: None,
         }
     }
 }
@@ -51,7 +61,9 @@ impl LazyFileListReader for LazyIpcReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let args = self.args;
 
-        let options = IpcScanOptions {};
+        let options = IpcScanOptions {
+            chunk_size: args.chunk_size,
+        };
         let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
 
         let cloud_options = args.cloud_options;
@@ -60,6 +72,8 @@ impl LazyFileListReader for LazyIpcReader {
         let cache = args.cache;
         let row_index = args.row_index;
         let include_file_paths = args.include_file_paths;
+        let columns = args.columns;
+        let projection = args.projection;
 
         let lf: LazyFrame = DslBuilder::scan_ipc(
             self.sources,
@@ -86,7 +100,11 @@ impl LazyFileListReader for LazyIpcReader {
         .build()
         .into();
 
-        Ok(lf)
+        Ok(select_scan_columns(
+            lf,
+            columns.as_deref(),
+            projection.as_deref(),
+        ))
     }
 
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
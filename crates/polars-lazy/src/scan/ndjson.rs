@@ -12,7 +12,7 @@ use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
 use crate::prelude::LazyFrame;
-use crate::scan::file_list_reader::LazyFileListReader;
+use crate::scan::file_list_reader::{LazyFileListReader, select_scan_columns};
 
 #[derive(Clone)]
 pub struct LazyJsonLineReader {
@@ -28,6 +28,8 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) columns: Option<Arc<[PlSmallStr]>>,
+    pub(crate) column_indices: Option<Arc<[usize]>>,
 }
 
 impl LazyJsonLineReader {
@@ -49,6 +51,8 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            columns: None,
+            column_indices: None,
         }
     }
 
@@ -121,6 +125,21 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Only keep the columns with these names.
+    #[must_use]
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only keep the columns at these indices. This counts from 0, meaning that `vec![0, 4]`
+    /// would select the 1st and 5th column.
+    #[must_use]
+    pub fn with_column_indices(mut self, column_indices: Option<Arc<[usize]>>) -> Self {
+        self.column_indices = column_indices;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -156,12 +175,17 @@ impl LazyFileListReader for LazyJsonLineReader {
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
 
-        Ok(LazyFrame::from(DslPlan::Scan {
+        let lf = LazyFrame::from(DslPlan::Scan {
             sources: self.sources,
             unified_scan_args: Box::new(unified_scan_args),
             scan_type,
             cached_ir: Default::default(),
-        }))
+        });
+        Ok(select_scan_columns(
+            lf,
+            self.columns.as_deref(),
+            self.column_indices.as_deref(),
+        ))
     }
 
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
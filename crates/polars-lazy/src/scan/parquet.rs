@@ -6,6 +6,7 @@ use polars_io::{HiveOptions, RowIndex};
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
+use super::file_list_reader::select_scan_columns;
 use crate::prelude::*;
 
 #[derive(Clone)]
@@ -24,6 +25,12 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Maximum number of rows per output chunk.
+    pub chunk_size: Option<usize>,
+    /// Only keep the columns with these names.
+    pub columns: Option<Arc<[PlSmallStr]>>,
+    /// Only keep the columns at these indices, counting from 0.
+    pub projection: Option<Arc<[usize]>>,
 }
 
 impl Default for ScanArgsParquet {
@@ -42,6 +49,9 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            chunk_size: None,
+            columns: None,
+            projection: None,
         }
     }
 }
@@ -69,6 +79,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            chunk_size: self.args.chunk_size,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -108,7 +119,11 @@ impl LazyFileListReader for LazyParquetReader {
             lf = lf.with_row_index(row_index.name, Some(row_index.offset))
         }
 
-        Ok(lf)
+        Ok(select_scan_columns(
+            lf,
+            self.args.columns.as_deref(),
+            self.args.projection.as_deref(),
+        ))
     }
 
     fn glob(&self) -> bool {
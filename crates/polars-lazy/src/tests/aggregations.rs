@@ -415,6 +415,85 @@ fn test_shift_elementwise_issue_2509() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_nan_arg_min_max_in_groups() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "name" => ["x", "y", "z", "v", "w"],
+        "v" => [1.0, f64::NAN, 3.0, 2.0, 1.0],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("name").get(col("v").arg_max()).alias("arg_max"),
+            col("name").get(col("v").nan_arg_max()).alias("nan_arg_max"),
+            col("name").get(col("v").arg_min()).alias("arg_min"),
+            col("name").get(col("v").nan_arg_min()).alias("nan_arg_min"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "g" => ["a", "b"],
+        "arg_max" => ["z", "v"],
+        "nan_arg_max" => ["y", "v"],
+        "arg_min" => ["x", "w"],
+        "nan_arg_min" => ["y", "w"],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_product_in_groups() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "c"],
+        "i" => [Some(2i32), Some(3), None, Some(4), Some(5), None],
+        "f" => [0.5f32, 2.0, 3.0, 1.0, -1.0, 2.0],
+    ]?;
+
+    let lf = df.clone().lazy().group_by_stable([col("g")]).agg([
+        col("i").product(),
+        col("f").product(),
+        col("i")
+            .filter(col("f").gt(lit(1.0f32)))
+            .product()
+            .alias("i_filtered"),
+        col("f")
+            .quantile(lit(0.5), QuantileMethod::Linear)
+            .alias("f_quantile"),
+        col("f").std(0).alias("f_std"),
+        col("i").var(1).alias("i_var"),
+    ]);
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(&**out.schema(), &*schema);
+
+    let expected = df![
+        "g" => ["a", "b", "c"],
+        "i" => [6i64, 20, 1],
+        "f" => [3.0f32, -1.0, 2.0],
+        "i_filtered" => [3i64, 1, 1],
+        "f_quantile" => [2.0f32, 0.0, 2.0],
+        "f_std" => [Some(1.027402333791974f32), Some(1.0), Some(0.0)],
+        "i_var" => [Some(0.5), Some(0.5), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("g").product().alias("g_product")])
+        .collect();
+    assert!(
+        out.unwrap_err()
+            .to_string()
+            .contains("`product` operation not supported for dtype `str`")
+    );
+    Ok(())
+}
+
 #[test]
 fn take_aggregations() -> PolarsResult<()> {
     let df = df![
@@ -568,3 +647,258 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "ipc"))]
+fn test_streaming_group_by_spill() -> PolarsResult<()> {
+    let df = df![
+        "key" => (0..10_000).map(|i| i % 1000).collect::<Vec<i32>>(),
+        "value" => (0..10_000).collect::<Vec<i32>>(),
+    ]?;
+    let q = |lf: LazyFrame| {
+        lf.group_by([col("key")])
+            .agg([
+                col("value").sum().alias("sum"),
+                col("value").mean().alias("mean"),
+                col("value").first().alias("first"),
+                col("value").last().alias("last"),
+            ])
+            .sort(["key"], Default::default())
+    };
+
+    let expected = q(df.clone().lazy()).collect()?;
+    // Spill all cold rows of the group-by to disk.
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    unsafe { std::env::set_var("POLARS_STREAMING_GROUP_BY_SPILL_SIZE", "1") };
+    let out = q(df.lazy()).collect_with_engine(Engine::Streaming);
+    unsafe { std::env::remove_var("POLARS_STREAMING_GROUP_BY_SPILL_SIZE") };
+
+    assert!(out?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_group_by_first_last_follow_row_order() -> PolarsResult<()> {
+    // Few groups on many rows, so the in-memory engine runs a partitioned group-by.
+    let n = 100_000;
+    let df = df![
+        "key" => (0..n).map(|i| (i * 7919) % 13).collect::<Vec<i32>>(),
+        "value" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by([col("key")])
+            .agg([
+                col("value").first().alias("first"),
+                col("value").last().alias("last"),
+                col("value").min().alias("min"),
+                col("value").max().alias("max"),
+            ])
+            .collect_with_engine(engine)?;
+        assert!(
+            out.column("first")?
+                .equals(&out.column("min")?.clone().with_name("first".into()))
+        );
+        assert!(
+            out.column("last")?
+                .equals(&out.column("max")?.clone().with_name("last".into()))
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-duration",
+    feature = "dtype-time"
+))]
+fn test_temporal_quantile_median() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2],
+        "v" => [10i64, 30, 20, 5],
+    ]?;
+    let ms = DataType::Datetime(TimeUnit::Milliseconds, None);
+    let ms_in_day = 86_400_000i64;
+    // (input dtype, output dtype, physical values of the median, and of the per-group maxima)
+    let cases = [
+        (
+            DataType::Date,
+            ms.clone(),
+            15 * ms_in_day,
+            [30, 5].map(|d| d * ms_in_day),
+        ),
+        (ms.clone(), ms, 15, [30, 5]),
+        (
+            DataType::Duration(TimeUnit::Microseconds),
+            DataType::Duration(TimeUnit::Microseconds),
+            15,
+            [30, 5],
+        ),
+        (DataType::Time, DataType::Time, 15, [30, 5]),
+    ];
+
+    for (dtype, out_dtype, median, maxima) in cases {
+        let lf = df.clone().lazy().with_column(col("v").cast(dtype));
+
+        let mut q = lf.clone().select([
+            col("v")
+                .quantile(lit(0.5), QuantileMethod::Linear)
+                .alias("q"),
+            col("v").median().alias("m"),
+        ]);
+        let schema = q.collect_schema()?;
+        let out = q.collect()?;
+        assert_eq!(schema.get("q"), Some(&out_dtype));
+        assert_eq!(schema.get("m"), Some(&out_dtype));
+        assert_eq!(out.column("q")?.dtype(), &out_dtype);
+        assert_eq!(out.column("m")?.dtype(), &out_dtype);
+        assert_eq!(out.column("q")?.get(0)?, out.column("m")?.get(0)?);
+        assert_eq!(
+            out.column("q")?
+                .to_physical_repr()
+                .cast(&DataType::Int64)?
+                .get(0)?,
+            AnyValue::Int64(median)
+        );
+
+        let out = lf
+            .group_by_stable([col("g")])
+            .agg([col("v")
+                .quantile(lit(1.0), QuantileMethod::Nearest)
+                .alias("q")])
+            .collect()?;
+        assert_eq!(out.column("q")?.dtype(), &out_dtype);
+        assert_eq!(
+            Vec::from(
+                out.column("q")?
+                    .to_physical_repr()
+                    .cast(&DataType::Int64)?
+                    .i64()?
+            ),
+            maxima.map(Some)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_string_categorical_min_max() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "s" => [Some("b"), None, Some("a"), Some("d"), Some("c")],
+    ]?;
+    // Insertion order "b" < "a", so lexical and physical ordering differ.
+    let cat = DataType::from_categories(Categories::random(
+        PlSmallStr::EMPTY,
+        CategoricalPhysical::U32,
+    ));
+    // Enums are ordered by their categories, not lexically.
+    let enum_ = DataType::from_frozen_categories(FrozenCategories::new(["d", "c", "b", "a"])?);
+    let lf = df.lazy().with_columns([
+        col("s").cast(cat).alias("c"),
+        col("s").cast(enum_).alias("e"),
+    ]);
+
+    let out = lf
+        .clone()
+        .group_by_stable([col("g")])
+        .agg([
+            col("s").min().alias("s_min"),
+            col("s").max().alias("s_max"),
+            col("c").min().alias("c_min"),
+            col("c").max().alias("c_max"),
+            col("e").min().alias("e_min"),
+            col("e").max().alias("e_max"),
+        ])
+        .collect()?;
+    let str_col = |name: &str| -> PolarsResult<Vec<Option<String>>> {
+        let s = out.column(name)?.cast(&DataType::String)?;
+        Ok(s.str()?.into_iter().map(|v| v.map(String::from)).collect())
+    };
+    let v = |a: &str, b: &str| vec![Some(a.to_string()), Some(b.to_string())];
+    assert_eq!(str_col("s_min")?, v("a", "c"));
+    assert_eq!(str_col("s_max")?, v("b", "d"));
+    assert_eq!(str_col("c_min")?, v("a", "c"));
+    assert_eq!(str_col("c_max")?, v("b", "d"));
+    assert_eq!(str_col("e_min")?, v("b", "d"));
+    assert_eq!(str_col("e_max")?, v("a", "c"));
+    assert!(matches!(
+        out.column("c_min")?.dtype(),
+        DataType::Categorical(_, _)
+    ));
+    assert!(matches!(out.column("e_max")?.dtype(), DataType::Enum(_, _)));
+
+    let out = lf
+        .select([
+            col("c").min().over([col("g")]).alias("c_min"),
+            col("e").max().over([col("g")]).alias("e_max"),
+        ])
+        .collect()?;
+    let c_min = out.column("c_min")?.cast(&DataType::String)?;
+    let e_max = out.column("e_max")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(c_min.str()?),
+        &[Some("a"), Some("a"), Some("a"), Some("c"), Some("c")]
+    );
+    assert_eq!(
+        Vec::from(e_max.str()?),
+        &[Some("a"), Some("a"), Some("a"), Some("c"), Some("c")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_boolean_sum_mean() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "flag" => [Some(true), Some(false), Some(true), None, Some(false)],
+    ]?;
+    let check_schema = |lf: &mut LazyFrame| -> PolarsResult<()> {
+        let schema = lf.collect_schema()?;
+        assert_eq!(schema.get("sum"), Some(&IDX_DTYPE));
+        assert_eq!(schema.get("mean"), Some(&DataType::Float64));
+        Ok(())
+    };
+
+    let mut lf = df.clone().lazy().group_by_stable([col("g")]).agg([
+        col("flag").sum().alias("sum"),
+        col("flag").mean().alias("mean"),
+    ]);
+    check_schema(&mut lf)?;
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.idx()?),
+        &[Some(2 as IdxSize), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(2.0 / 3.0), Some(0.0)]
+    );
+
+    let mut lf = df.clone().lazy().select([
+        col("flag").sum().alias("sum"),
+        col("flag").mean().alias("mean"),
+    ]);
+    check_schema(&mut lf)?;
+    let out = lf.collect()?;
+    assert_eq!(out.column("sum")?.idx()?.get(0), Some(2));
+    assert_eq!(out.column("mean")?.f64()?.get(0), Some(0.5));
+
+    let mut lf = df.lazy().select([
+        col("flag").sum().over([col("g")]).alias("sum"),
+        col("flag").mean().over([col("g")]).alias("mean"),
+    ]);
+    check_schema(&mut lf)?;
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.idx()?),
+        &[Some(2 as IdxSize), Some(2), Some(2), Some(0), Some(0)]
+    );
+    Ok(())
+}
@@ -345,3 +345,30 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_independently_built_subplans() -> PolarsResult<()> {
+    // Both sides are built separately rather than cloned from the same `LazyFrame`.
+    let side = || {
+        scan_foods_ipc()
+            .filter(col("fats_g").gt(2.0))
+            .select([col("category"), col("fats_g")])
+    };
+
+    let q = side()
+        .inner_join(side(), col("fats_g"), col("fats_g"))
+        .with_comm_subplan_elim(true);
+    cached_before_root(q.clone());
+
+    let expected = q
+        .clone()
+        .with_comm_subplan_elim(false)
+        .sort(["category", "fats_g"], Default::default())
+        .collect()?;
+    let out = q
+        .sort(["category", "fats_g"], Default::default())
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
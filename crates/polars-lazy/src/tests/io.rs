@@ -318,6 +318,19 @@ fn test_parquet_statistics() -> PolarsResult<()> {
         .collect()?;
     assert_eq!(out.shape(), (0, 6));
 
+    // is_null
+    // note that calories has no nulls
+    let out = scan_foods_parquet(par)
+        .filter(col("calories").is_null())
+        .collect()?;
+    assert_eq!(out.shape(), (0, 4));
+
+    // not(is_not_null) => is_null
+    let out = scan_foods_parquet(par)
+        .filter(not(col("calories").is_not_null()))
+        .collect()?;
+    assert_eq!(out.shape(), (0, 4));
+
     // Test multiple predicates
 
     // And operation
@@ -411,6 +424,85 @@ fn test_scan_parquet_limit_9001() {
     });
 }
 
+fn chunk_lengths(df: &DataFrame) -> Vec<usize> {
+    df.get_columns()[0]
+        .as_materialized_series()
+        .chunk_lengths()
+        .collect()
+}
+
+#[test]
+fn test_scan_parquet_chunk_size() -> PolarsResult<()> {
+    init_files();
+    let args = ScanArgsParquet {
+        chunk_size: Some(10),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(PlPath::new(FOODS_PARQUET), args)?.collect()?;
+
+    let lengths = chunk_lengths(&out);
+    assert!(lengths.iter().all(|&len| len <= 10), "{lengths:?}");
+    assert!(out.equals(&scan_foods_parquet(false).collect()?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_ipc_chunk_size() -> PolarsResult<()> {
+    init_files();
+    let args = ScanArgsIpc {
+        chunk_size: Some(10),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ipc(PlPath::new(FOODS_IPC), args)?.collect()?;
+
+    let lengths = chunk_lengths(&out);
+    assert!(lengths.iter().all(|&len| len <= 10), "{lengths:?}");
+    assert!(out.equals(&scan_foods_ipc().collect()?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "ipc", feature = "json"))]
+fn test_scan_columns_and_projection() -> PolarsResult<()> {
+    init_files();
+    let columns: Arc<[PlSmallStr]> = ["fats_g".into(), "category".into()].into();
+    let expected = scan_foods_csv()
+        .select([col("fats_g"), col("category")])
+        .collect()?;
+
+    let out = LazyCsvReader::new(PlPath::new(FOODS_CSV))
+        .with_columns(Some(columns.clone()))
+        .finish()?
+        .collect()?;
+    assert!(out.equals(&expected));
+    let out = LazyCsvReader::new(PlPath::new(FOODS_CSV))
+        .with_projection(Some(Arc::new(vec![2, 0])))
+        .finish()?
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    let args = ScanArgsParquet {
+        projection: Some([2, 0].into()),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(PlPath::new(FOODS_PARQUET), args)?.collect()?;
+    assert!(out.equals(&expected));
+    let args = ScanArgsIpc {
+        columns: Some(columns.clone()),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ipc(PlPath::new(FOODS_IPC), args)?.collect()?;
+    assert!(out.equals(&expected));
+
+    let out = LazyJsonLineReader::new(PlPath::new("../../examples/datasets/foods1.ndjson"))
+        .with_column_indices(Some([2, 0].into()))
+        .finish()?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["fats_g", "category"]);
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_ipc_globbing() -> PolarsResult<()> {
@@ -427,6 +519,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            chunk_size: None,
         },
     )?
     .collect()?;
@@ -680,6 +773,51 @@ fn test_row_index_on_files() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_row_index_parallel_parquet() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_row_index_parallel.parquet");
+    let mut df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(100))
+        .finish(&mut df)?;
+
+    let offset = 5;
+    for parallel in [
+        ParallelStrategy::None,
+        ParallelStrategy::Columns,
+        ParallelStrategy::RowGroups,
+        ParallelStrategy::Prefiltered,
+        ParallelStrategy::Auto,
+    ] {
+        let args = ScanArgsParquet {
+            parallel,
+            row_index: Some(RowIndex {
+                name: PlSmallStr::from_static("index"),
+                offset,
+            }),
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_parquet(PlPath::new(path.to_str().unwrap()), args)?
+            .filter((col("a") % lit(7i64)).eq(lit(0i64)))
+            .select([
+                col("index").cast(DataType::Int64),
+                col("a") + lit(offset as i64),
+            ])
+            .collect()?;
+        assert_eq!(out.height(), 1429, "{parallel:?}");
+        assert_eq!(
+            out.column("index")?,
+            &out.column("a")?.clone().with_name("index".into())
+        );
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 #[test]
 fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     let df = LazyCsvReader::new(PlPath::new(FOODS_CSV))
@@ -781,6 +919,50 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "flight")]
+fn scan_flight_projection() -> PolarsResult<()> {
+    use std::io::Read;
+    use std::sync::Mutex;
+
+    use polars_io::SerWriter;
+    use polars_io::ipc::IpcStreamWriter;
+
+    struct MyClient {
+        requested: Mutex<Vec<FlightDescriptor>>,
+    }
+
+    impl FlightClient for MyClient {
+        fn get_schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef> {
+            assert_eq!(ticket, b"fruits");
+            Ok(fruits_cars().schema().clone())
+        }
+
+        fn do_get(&self, descriptor: &FlightDescriptor) -> PolarsResult<Box<dyn Read + Send>> {
+            self.requested.lock().unwrap().push(descriptor.clone());
+            let mut df = fruits_cars();
+            let mut buf = vec![];
+            IpcStreamWriter::new(&mut buf).finish(&mut df)?;
+            Ok(Box::new(Cursor::new(buf)))
+        }
+    }
+
+    let client = Arc::new(MyClient {
+        requested: Mutex::new(vec![]),
+    });
+    let df = LazyFrame::scan_flight(client.clone(), Arc::from(&b"fruits"[..]))?
+        .select([col("fruits"), col("B")])
+        .collect()?;
+
+    assert_eq!(df, fruits_cars().select(["fruits", "B"])?);
+    let requested = client.requested.lock().unwrap();
+    assert_eq!(requested.len(), 1);
+    let mut columns = requested[0].columns.as_deref().unwrap().to_vec();
+    columns.sort();
+    assert_eq!(columns, &["B", "fruits"]);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -805,3 +987,74 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+fn scan_csv_parse_dates() -> PolarsResult<()> {
+    let csv = "date,datetime,text\n\
+        2024-01-02,2024-01-02 03:04:05,2024-01-02\n\
+        2024-02-03,2024-02-03 04:05:06,2024-02-03\n";
+    let sources = || {
+        ScanSources::Buffers(
+            [polars_utils::mmap::MemSlice::from_vec(
+                csv.as_bytes().to_vec(),
+            )]
+            .into(),
+        )
+    };
+
+    let df = LazyCsvReader::new_with_sources(sources())
+        .with_try_parse_dates(true)
+        .finish()?
+        .collect()?;
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::Date,
+            DataType::Datetime(TimeUnit::Microseconds, None),
+            DataType::Date
+        ]
+    );
+
+    // Parse only a single column by overwriting its dtype.
+    let df = LazyCsvReader::new_with_sources(sources())
+        .with_dtype_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "date".into(),
+            DataType::Date,
+        )]))))
+        .finish()?
+        .collect()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Date, DataType::String, DataType::String]
+    );
+    assert_eq!(
+        df.column("date")?.date()?.physical().get(1),
+        Some(19756) // 2024-02-03
+    );
+    Ok(())
+}
+
+#[test]
+fn scan_csv_strict_schema() -> PolarsResult<()> {
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let scan = |csv: &str| {
+        let sources = ScanSources::Buffers(
+            [polars_utils::mmap::MemSlice::from_vec(
+                csv.as_bytes().to_vec(),
+            )]
+            .into(),
+        );
+        LazyCsvReader::new_with_sources(sources)
+            .with_strict_schema(schema.clone())
+            .finish()?
+            .collect()
+    };
+
+    assert_eq!(scan("a,b\n1,x\n")?.shape(), (1, 2));
+    assert!(scan("a,b,c\n1,x,2\n").is_err());
+    Ok(())
+}
@@ -94,6 +94,92 @@ fn test_pred_pd_1() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_filter_select_filter_gathers_once() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    // The filters are combined below the projection, so the frame is only gathered once.
+    let q = df
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .select([col("A"), col("B"), (col("A") * col("B")).alias("AB")])
+        .filter(col("B").gt(lit(1)));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let n_filters = lp_arena
+        .iter(lp)
+        .filter(|(_, lp)| matches!(lp, IR::Filter { .. }))
+        .count();
+    assert_eq!(n_filters, 1);
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("AB")?.i32()?),
+        &[Some(8), Some(9), Some(8)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_slice_pushdown_left_join() -> PolarsResult<()> {
+    use polars_ops::frame::MaintainOrderJoin;
+
+    let df1 = df![
+        "a" => [1, 2, 2, 3, 4],
+    ]?;
+    let df2 = df![
+        "a" => [2, 2, 4],
+        "b" => [10, 20, 40],
+    ]?;
+
+    // The slice is absorbed by the in-memory scan of the left input.
+    let left_input_height = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena.iter(lp).find_map(|(_, lp)| match lp {
+            IR::Join { input_left, .. } => match lp_arena.get(*input_left) {
+                IR::DataFrameScan { df, .. } => Some(df.height()),
+                _ => None,
+            },
+            _ => None,
+        })
+    };
+    let join = |maintain_order| {
+        df1.clone()
+            .lazy()
+            .join_builder()
+            .with(df2.clone().lazy())
+            .left_on([col("a")])
+            .right_on([col("a")])
+            .how(JoinType::Left)
+            .maintain_order(maintain_order)
+            .finish()
+    };
+
+    // Every left row produces at least one output row, so at most `offset + len` left rows are
+    // needed.
+    let q = join(MaintainOrderJoin::Left).slice(1, 3);
+    assert_eq!(left_input_height(q.clone()), Some(4));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), Some(20), Some(10)]
+    );
+
+    // Without a left order guarantee the output rows can't be traced back to the left input.
+    assert_eq!(
+        left_input_height(join(MaintainOrderJoin::None).slice(1, 3)),
+        Some(5)
+    );
+    Ok(())
+}
+
 #[test]
 fn test_no_left_join_pass() -> PolarsResult<()> {
     let df1 = df![
@@ -304,6 +390,79 @@ fn test_lazy_filter_and_rename() {
     assert_eq!(lf.collect().unwrap().get_column_names(), &["x", "b", "c"]);
 }
 
+/// A frame with an `id` key, a value column `v` and a list column `l` of `[v, v + 1]`.
+#[cfg(feature = "pivot")]
+fn id_v_list_frame() -> PolarsResult<LazyFrame> {
+    Ok(df![
+        "id" => [1, 2, 2],
+        "v" => [10, 20, 30],
+    ]?
+    .lazy()
+    .with_column(concat_list([col("v"), col("v") + lit(1)])?.alias("l")))
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_pred_pd_explode_unpivot_unique() -> PolarsResult<()> {
+    let df = id_v_list_frame()?;
+
+    // Predicates on columns that are not changed by the node are pushed past it.
+    let q = df.clone().explode(by_name(["l"], true));
+    assert!(predicate_at_scan(q.clone().filter(col("id").eq(lit(2)))));
+    assert!(!predicate_at_scan(q.filter(col("l").gt(lit(20)))));
+
+    let q = df
+        .clone()
+        .select([col("id"), col("v")])
+        .unpivot(UnpivotArgsDSL {
+            on: by_name(["v"], true),
+            index: by_name(["id"], true),
+            variable_name: None,
+            value_name: None,
+        });
+    assert!(predicate_at_scan(q.clone().filter(col("id").eq(lit(2)))));
+    assert!(!predicate_at_scan(q.filter(col("value").gt(lit(20)))));
+
+    let q = df.unique_stable(Some(by_name(["id"], true)), UniqueKeepStrategy::First);
+    let out = q.clone().filter(col("id").eq(lit(2)));
+    assert!(predicate_at_scan(out.clone()));
+    assert_eq!(Vec::from(out.collect()?.column("v")?.i32()?), &[Some(20)]);
+    // Filtering on a non-key column first would change which row is kept.
+    assert!(!predicate_at_scan(q.filter(col("v").eq(lit(30)))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_pred_pd_explode_unpivot_partial() -> PolarsResult<()> {
+    let df = id_v_list_frame()?;
+
+    // Only the part of the conjunction on `id` is pushed past the explode.
+    let q = df
+        .clone()
+        .explode(by_name(["l"], true))
+        .filter(col("id").eq(lit(2)).and(col("l").gt(lit(20))));
+    assert!(predicate_at_scan(q.clone()));
+    assert_eq!(
+        Vec::from(q.collect()?.column("l")?.i32()?),
+        &[Some(21), Some(30), Some(31)]
+    );
+
+    let q = df.select([col("id"), col("v")]).unpivot(UnpivotArgsDSL {
+        on: by_name(["v"], true),
+        index: by_name(["id"], true),
+        variable_name: Some("var".into()),
+        value_name: Some("val".into()),
+    });
+    assert!(!predicate_at_scan(
+        q.clone().filter(col("var").eq(lit("v")))
+    ));
+    let q = q.filter(col("id").eq(lit(1)).and(col("val").gt(lit(5))));
+    assert!(predicate_at_scan(q.clone()));
+    assert_eq!(Vec::from(q.collect()?.column("val")?.i32()?), &[Some(10)]);
+    Ok(())
+}
+
 #[test]
 fn test_with_row_index_opts() -> PolarsResult<()> {
     let df = df![
@@ -650,3 +809,203 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_reorder_joins() -> PolarsResult<()> {
+    let fact = df![
+        "id" => 0..100,
+        "a" => (0..100).map(|i| i % 50).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| i % 3).collect::<Vec<_>>(),
+    ]?;
+    let big = df![
+        "a" => 0..50,
+        "big_val" => (0..50).map(|i| i * 10).collect::<Vec<_>>(),
+    ]?;
+    let small = df![
+        "b_key" => [0, 2],
+        "small_val" => ["x", "z"],
+    ]?;
+
+    let q = fact
+        .lazy()
+        .inner_join(big.lazy(), col("a"), col("a"))
+        .inner_join(small.lazy(), col("b"), col("b_key"));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_reorder_joins(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    // The smallest relation is now joined first.
+    let IR::SimpleProjection { input, .. } = lp_arena.get(lp) else {
+        panic!("expected a projection restoring the column order")
+    };
+    let IR::Join { input_left, .. } = lp_arena.get(*input) else {
+        panic!("expected a join")
+    };
+    let IR::Join { input_right, .. } = lp_arena.get(*input_left) else {
+        panic!("expected a join")
+    };
+    assert!(matches!(
+        lp_arena.get(*input_right),
+        IR::DataFrameScan { df, .. } if df.height() == 2
+    ));
+
+    let expected = q.clone().sort(["id"], Default::default()).collect()?;
+    let out = q
+        .with_reorder_joins(true)
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_reorder_joins_skips_dependent_keys() -> PolarsResult<()> {
+    let left = df!["a" => [1, 2, 3]]?;
+    let mid = df!["a" => [1, 2, 3], "b" => [4, 5, 6]]?;
+    let right = df!["b" => [4, 5]]?;
+
+    // The second join is keyed on a column of the first right relation, so the joins can't be
+    // swapped.
+    let q = left
+        .lazy()
+        .inner_join(mid.lazy(), col("a"), col("a"))
+        .inner_join(right.lazy(), col("b"), col("b"))
+        .with_reorder_joins(true);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(matches!(lp_arena.get(lp), IR::Join { .. }));
+    assert_eq!(q.collect()?.height(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_optimizer_trace() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+        "c" => [7, 8, 9],
+    ]?;
+
+    // Fully pushed down, nothing to report.
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("b")]);
+    assert!(q.optimizer_trace()?.is_empty());
+
+    let q = df
+        .lazy()
+        .map(
+            Ok,
+            OptFlags::default() & !OptFlags::PROJECTION_PUSHDOWN,
+            None,
+            Some("my_udf"),
+        )
+        .slice(0, 2)
+        .filter(col("a").gt(lit(1)))
+        .select([col("b")]);
+    let events = q.optimizer_trace()?;
+    assert_eq!(events.len(), 2, "{events:?}");
+
+    let predicate = events
+        .iter()
+        .find(|e| e.optimization == "predicate_pushdown")
+        .unwrap();
+    assert_eq!(predicate.node, "slice");
+    assert_eq!(predicate.exprs.len(), 1);
+    assert!(predicate.exprs[0].contains("col(\"a\")"));
+
+    let projection = events
+        .iter()
+        .find(|e| e.optimization == "projection_pushdown")
+        .unwrap();
+    assert!(projection.node.contains("my_udf"), "{}", projection.node);
+    assert!(projection.reason.contains("function"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_join_pruning() -> PolarsResult<()> {
+    let keys = df![
+        "category" => ["vegetables", "fruit", "vegetables"],
+        "tag" => [1, 2, 3],
+    ]?;
+
+    let q = scan_foods_csv().inner_join(keys.lazy(), col("category"), col("category"));
+
+    // Without the optimization, there is nothing to push into the scan.
+    assert!(!predicate_at_scan(q.clone()));
+    assert!(predicate_at_scan(q.clone().with_join_pruning(true)));
+
+    let by = ["category", "calories", "fats_g", "sugars_g", "tag"];
+    let expected = q.clone().sort(by, Default::default()).collect()?;
+    let out = q
+        .with_join_pruning(true)
+        .sort(by, Default::default())
+        .collect()?;
+    assert!(out.height() > 0);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_join_pruning_keeps_unmatched_left_rows() -> PolarsResult<()> {
+    let keys = df!["category" => ["fruit"]]?;
+
+    // The rows of the scan are kept by a left join, so the scan can't be pruned.
+    let q = scan_foods_csv()
+        .left_join(keys.lazy(), col("category"), col("category"))
+        .with_join_pruning(true);
+    assert!(!predicate_at_scan(q.clone()));
+    assert_eq!(q.collect()?.height(), scan_foods_csv().collect()?.height());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_preview_slice_pushdown() -> PolarsResult<()> {
+    use polars_utils::slice_enum::Slice;
+
+    let q = scan_foods_parquet(false).preview_query(27, 3, 2)?;
+
+    // Both the head and the tail slice reach the scan.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let mut slices = lp_arena
+        .iter(lp)
+        .filter_map(|(_, lp)| match lp {
+            IR::Scan {
+                unified_scan_args, ..
+            } => unified_scan_args.pre_slice.clone(),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    slices.sort_by_key(|slice| matches!(slice, Slice::Negative { .. }));
+    assert_eq!(
+        slices,
+        [
+            Slice::Positive { offset: 0, len: 3 },
+            Slice::Negative {
+                offset_from_end: 2,
+                len: 2
+            }
+        ]
+    );
+
+    let expected = scan_foods_parquet(false).collect()?;
+    let expected = expected.slice(0, 3).vstack(&expected.slice(-2, 2))?;
+    assert!(q.collect()?.equals_missing(&expected));
+    assert!(
+        scan_foods_parquet(false)
+            .preview(3, 2)?
+            .equals_missing(&expected)
+    );
+    Ok(())
+}
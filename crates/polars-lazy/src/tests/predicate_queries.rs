@@ -314,3 +314,70 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_filter_conjunction_multiple_chunks() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [Some(1), None, Some(3), Some(4)],
+        "b" => [1, 2, 3, 4],
+        "c" => [10, 20, 30, 40],
+    }?;
+    df.vstack_mut(&df! {
+        "a" => [Some(5), Some(6), None, Some(8)],
+        "b" => [5, 6, 7, 8],
+        "c" => [50, 60, 70, 80],
+    }?)?;
+    assert_eq!(df.first_col_n_chunks(), 2);
+
+    let out = df
+        .lazy()
+        .filter(
+            col("a")
+                .gt(lit(2))
+                .and(col("b").neq(lit(6)))
+                .and(col("c").lt(lit(80))),
+        )
+        .collect()?;
+
+    let expected = df! {
+        "a" => [3, 4, 5],
+        "b" => [3, 4, 5],
+        "c" => [30, 40, 50],
+    }?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_filter_conjunction_evaluates_cheap_parts_first() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df! {
+        "a" => [1, 2, 3, 4, 5, 6],
+        "b" => [1, 1, 1, 1, 1, 0],
+    }?;
+
+    // Record the number of rows the expensive part of the predicate is evaluated on.
+    let rows_seen = Arc::new(AtomicUsize::new(0));
+    let rows_seen_udf = rows_seen.clone();
+    let expensive = col("b").map(
+        move |c: Column| {
+            rows_seen_udf.fetch_add(c.len(), Ordering::Relaxed);
+            Ok(c.as_materialized_series().equal(1)?.into_column())
+        },
+        |_, f| Ok(Field::new(f.name().clone(), DataType::Boolean)),
+    );
+
+    let out = df
+        .lazy()
+        .filter(expensive.and(col("a").gt(lit(3))))
+        .collect()?;
+
+    let expected = df! {
+        "a" => [4, 5],
+        "b" => [1, 1],
+    }?;
+    assert_eq!(out, expected);
+    assert_eq!(rows_seen.load(Ordering::Relaxed), 3);
+    Ok(())
+}
@@ -67,6 +67,44 @@ fn test_lazy_unpivot() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot() -> PolarsResult<()> {
+    let df = df![
+        "store" => ["a", "a", "a", "b", "b", "c"],
+        "product" => ["x", "y", "x", "x", "z", "y"],
+        "sales" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    // `z` is not in `on_columns` and `w` does not occur in the data.
+    let on_columns = Series::new("product".into(), ["x", "y", "w"]);
+    let lf = df
+        .lazy()
+        .filter(col("store").neq(lit("c")))
+        .pivot(
+            "product",
+            &on_columns,
+            ["store"],
+            ["sales"],
+            Some(col("").sum()),
+            None,
+        )?
+        .filter(col("x").gt(lit(2)));
+
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(out.schema().as_ref(), schema.as_ref());
+
+    let expected = df![
+        "store" => ["a", "b"],
+        "x" => [4, 4],
+        "y" => [2, 0],
+        "w" => [0, 0],
+    ]?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -1202,6 +1240,61 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_join_build_side() -> PolarsResult<()> {
+    use polars_ops::frame::{JoinBuildSide, MaintainOrderJoin};
+
+    let left = df![
+        "k" => (0..1000).map(|i| i % 10).collect::<Vec<i32>>(),
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let right = df![
+        "k" => [1, 3, 3, 12],
+        "b" => ["x", "y", "z", "w"],
+    ]?;
+    let q = |build_side, maintain_order, engine| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("k")])
+            .right_on([col("k")])
+            .build_side(build_side)
+            .maintain_order(maintain_order)
+            .finish()
+            .sort(["a", "b"], Default::default())
+            .collect_with_engine(engine)
+    };
+
+    let expected = q(None, MaintainOrderJoin::None, Engine::InMemory)?;
+    assert_eq!(expected.height(), 300);
+    assert!(q(None, MaintainOrderJoin::None, Engine::Streaming)?.equals(&expected));
+    for build_side in [JoinBuildSide::Left, JoinBuildSide::Right] {
+        assert!(q(Some(build_side), MaintainOrderJoin::None, Engine::Streaming)?.equals(&expected));
+        assert!(q(Some(build_side), MaintainOrderJoin::None, Engine::InMemory).is_err());
+    }
+
+    // Maintaining the left order requires building on the right.
+    assert!(
+        q(
+            Some(JoinBuildSide::Right),
+            MaintainOrderJoin::Left,
+            Engine::Streaming
+        )
+        .is_ok()
+    );
+    assert!(
+        q(
+            Some(JoinBuildSide::Left),
+            MaintainOrderJoin::Left,
+            Engine::Streaming
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1273,6 +1366,92 @@ fn test_regex_selection() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "strings", feature = "extract_groups"))]
+fn test_extract_groups() -> PolarsResult<()> {
+    let df = df![
+        "log" => [Some("GET /index 200"), Some("no match"), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("log")
+            .str()
+            .extract_groups(r"(?<method>\w+) (\S+) (?<status>\d+)")?])
+        .collect()?;
+
+    let ca = out.column("log")?.struct_()?;
+    let fields = ca.fields_as_series();
+    let names = fields.iter().map(|s| s.name().as_str()).collect::<Vec<_>>();
+    assert_eq!(names, &["method", "2", "status"]);
+    assert_eq!(Vec::from(fields[0].str()?), &[Some("GET"), None, None]);
+    assert_eq!(Vec::from(fields[2].str()?), &[Some("200"), None, None]);
+    // A null input remains null, a non-matching string gives a struct of nulls.
+    assert_eq!(ca.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "binary_hash"))]
+fn test_str_hash_digests() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("polars"), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().md5().alias("md5"),
+            col("s").str().sha256().alias("sha256"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("md5")?.str()?),
+        &[Some("33f83688ff9c10a6eb6a9b34d8fac7d7"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("sha256")?.str()?),
+        &[
+            Some("e37e7e864c58e1e96380229f66040d1852e73f5e80739aa742488ea869faecc1"),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "binary_encoding"))]
+fn test_str_uuid_round_trip() -> PolarsResult<()> {
+    let df = df![
+        "s" => [
+            Some("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+            Some("67e5504410b1426f9247bb680e5fe0c8"),
+            Some("not a uuid"),
+            None
+        ]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").str().uuid_decode(false).binary().uuid_encode()])
+        .collect()?;
+
+    let expected = Some("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(
+        Vec::from(out.column("s")?.str()?),
+        &[expected, expected, None, None]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("s").str().uuid_decode(true)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_sort_by() -> PolarsResult<()> {
     let df = df![
@@ -1323,6 +1502,50 @@ fn test_sort_by() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_sort_by_in_groups_multiple_options() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 1, 2, 2],
+        "event" => ["a", "b", "c", "d", "e", "f"],
+        "ts" => [Some(2), None, Some(2), Some(1), Some(5), Some(3)],
+        "prio" => [1, 0, 2, 0, 0, 0]
+    ]?;
+    // Sort `ts` ascending with nulls first and break ties on `prio` descending.
+    let options = SortMultipleOptions::default()
+        .with_order_descending_multi([false, true])
+        .with_nulls_last_multi([false, true]);
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("event")
+                .sort_by([col("ts"), col("prio")], options.clone())
+                .alias("events"),
+            col("event")
+                .sort_by([col("ts"), col("prio")], options.with_nulls_last(true))
+                .first()
+                .alias("first"),
+        ])
+        .collect()?;
+
+    let events = out.column("events")?.explode(false)?;
+    assert_eq!(
+        Vec::from(events.str()?),
+        &[
+            Some("b"),
+            Some("d"),
+            Some("c"),
+            Some("a"),
+            Some("f"),
+            Some("e")
+        ]
+    );
+    let first = out.column("first")?;
+    assert_eq!(Vec::from(first.str()?), &[Some("d"), Some("f")]);
+    Ok(())
+}
+
 #[test]
 fn test_filter_after_shift_in_groups() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -1701,6 +1924,64 @@ fn test_single_ranked_group() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "rank")]
+fn test_percent_rank_over() -> PolarsResult<()> {
+    let df = df![
+        "group" => [1, 1, 1, 1, 2, 2],
+        "value" => [Some(10), Some(20), Some(20), None, Some(5), Some(1)]
+    ]?;
+    let percent_rank = |method| {
+        col("value")
+            .percent_rank(
+                RankOptions {
+                    method,
+                    ..Default::default()
+                },
+                None,
+            )
+            .over([col("group")])
+    };
+
+    let out = df
+        .lazy()
+        .select([
+            percent_rank(RankMethod::Average).alias("average"),
+            percent_rank(RankMethod::Min).alias("min"),
+            percent_rank(RankMethod::Dense).alias("dense"),
+        ])
+        .collect()?;
+
+    let third = 1.0 / 3.0;
+    assert_eq!(
+        Vec::from(out.column("average")?.f64()?),
+        &[
+            Some(third),
+            Some(2.5 / 3.0),
+            Some(2.5 / 3.0),
+            None,
+            Some(1.0),
+            Some(0.5)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("min")?.f64()?),
+        &[
+            Some(third),
+            Some(2.0 / 3.0),
+            Some(2.0 / 3.0),
+            None,
+            Some(1.0),
+            Some(0.5)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("dense")?.f64()?),
+        &[Some(0.5), Some(1.0), Some(1.0), None, Some(1.0), Some(0.5)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "diff")]
 fn empty_df() -> PolarsResult<()> {
@@ -2010,3 +2291,320 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_select_seq_evaluates_in_order() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2],
+        "a" => [1, 2, 3],
+    ]?;
+    let log = Arc::new(std::sync::Mutex::new(vec![]));
+    let logged = |name: &'static str| {
+        let log = log.clone();
+        col("a").map(
+            move |c| {
+                log.lock().unwrap().push(name);
+                Ok(c)
+            },
+            |_, f| Ok(f.clone()),
+        )
+    };
+
+    let out = df
+        .lazy()
+        .select_seq([
+            logged("x").alias("x"),
+            col("a").sum().over([col("g")]).alias("w"),
+            (logged("y") + logged("z")).alias("yz"),
+        ])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["x", "w", "yz"]);
+    assert_eq!(*log.lock().unwrap(), ["x", "y", "z"]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "arg_where", feature = "range", feature = "is_in"))]
+fn test_arg_where_is_in_index_round_trip() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, Some(3), Some(4), Some(0)],
+    ]?;
+
+    let indices = df
+        .clone()
+        .lazy()
+        .select([arg_where(col("a").gt(lit(2))).alias("idx")])
+        .collect()?;
+    assert_eq!(indices.column("idx")?.dtype(), &IDX_DTYPE);
+    assert_eq!(
+        Vec::from(indices.column("idx")?.idx()?),
+        &[Some(2), Some(3)]
+    );
+
+    // Turn the selected rows back into a mask over another frame.
+    let out = df
+        .lazy()
+        .with_column(
+            is_in_index(
+                lit(indices.column("idx")?.as_materialized_series().clone()),
+                len(),
+            )
+            .alias("mask"),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("mask")?.bool()?),
+        &[
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(false)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_profile_rows_emitted() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2, 5, 4],
+    ]?;
+
+    let (out, profile) = df
+        .lazy()
+        .filter(col("a").gt(lit(2)))
+        .sort(["a"], Default::default())
+        .profile()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(
+        profile.get_column_names(),
+        &["node", "start", "end", "rows_emitted"]
+    );
+
+    let nodes = profile.column("node")?.str()?;
+    let rows = profile.column("rows_emitted")?.u64()?;
+    let rows_of = |prefix: &str| {
+        nodes
+            .iter()
+            .zip(rows.iter())
+            .find(|(node, _)| node.unwrap().starts_with(prefix))
+            .map(|(_, rows)| rows)
+            .unwrap()
+    };
+    assert_eq!(rows_of("optimization"), None);
+    assert_eq!(rows_of("sort"), Some(3));
+    Ok(())
+}
+
+#[test]
+fn test_filter_fused_into_select() -> PolarsResult<()> {
+    let chunk = df![
+        "a" => (0..100).collect::<Vec<i32>>(),
+        "b" => (0..100).map(|i| i % 7).collect::<Vec<i32>>(),
+    ]?;
+    let mut df = chunk.clone();
+    for _ in 0..3 {
+        df.vstack_mut(&chunk)?;
+    }
+    assert_eq!(df.first_col_n_chunks(), 4);
+
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("b").eq(lit(3)).and(col("a").gt(lit(50))))
+        .select([col("a"), (col("a") * col("b")).alias("ab")]);
+    let (out, profile) = q.profile()?;
+
+    let expected = df
+        .lazy()
+        .filter(col("b").eq(lit(3)).and(col("a").gt(lit(50))))
+        .collect()?
+        .lazy()
+        .select([col("a"), (col("a") * col("b")).alias("ab")])
+        .collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 4 * 7);
+
+    let nodes = profile.column("node")?.str()?;
+    assert!(
+        nodes
+            .iter()
+            .any(|node| node.unwrap().starts_with("filter_select"))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ir_serde")]
+fn test_optimized_plan_json_round_trip() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "c"],
+        "v" => [1, 2, 3, 4],
+    ]?;
+    let q = df
+        .lazy()
+        .filter(col("v").gt(lit(1)))
+        .group_by([col("g")])
+        .agg([col("v").sum()])
+        .sort(["g"], Default::default());
+
+    let mut json = vec![];
+    q.clone().serialize_optimized_plan_json(&mut json)?;
+    let plan: IRPlan = serde_json::from_slice(&json).unwrap();
+    let out = LazyFrame::collect_optimized_plan(plan)?;
+    assert!(out.equals(&q.collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_memory_limit_requires_tracking() -> PolarsResult<()> {
+    let lf = df!["a" => [1, 2, 3]]?.lazy().select([col("a").sum()]);
+    let err = lf
+        .clone()
+        .collect_with_memory_limit(usize::MAX)
+        .unwrap_err();
+    assert!(err.to_string().contains("TrackingAllocator"));
+
+    // The limit is inherited by derived frames and checked by every engine.
+    let lf = lf
+        .with_memory_limit(usize::MAX)
+        .with_column(col("a") * lit(2));
+    let err = lf
+        .clone()
+        .collect_with_engine(Engine::InMemory)
+        .unwrap_err();
+    assert!(err.to_string().contains("TrackingAllocator"));
+    #[cfg(feature = "new_streaming")]
+    {
+        let err = lf.collect_with_engine(Engine::Streaming).unwrap_err();
+        assert!(err.to_string().contains("TrackingAllocator"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_token() -> PolarsResult<()> {
+    use polars_utils::relaxed_cell::RelaxedCell;
+
+    let df = df!["a" => [3, 1, 2]]?;
+
+    let token = Arc::new(RelaxedCell::from(false));
+    let out = df.clone().lazy().sort(["a"], Default::default());
+    assert_eq!(out.collect_with_token(token.clone())?.height(), 3);
+
+    // The token is set while the query is running, the next node aborts.
+    let set = token.clone();
+    let lf = df
+        .clone()
+        .lazy()
+        .with_column(col("a").map(
+            move |c| {
+                set.store(true);
+                Ok(c)
+            },
+            |_, f| Ok(f.clone()),
+        ))
+        .sort(["a"], Default::default());
+    let err = lf.collect_with_token(token.clone()).unwrap_err();
+    assert!(err.to_string().contains("query interrupted"));
+
+    // A join or group-by that is the last node checks the token once its input is computed.
+    let interrupt = |lf: LazyFrame| {
+        let set = token.clone();
+        set.store(false);
+        lf.with_column(col("a").map(
+            move |c| {
+                set.store(true);
+                Ok(c)
+            },
+            |_, f| Ok(f.clone()),
+        ))
+    };
+    let lf = df.clone().lazy().join(
+        interrupt(df.clone().lazy()),
+        [col("a")],
+        [col("a")],
+        JoinType::Inner.into(),
+    );
+    assert!(lf.collect_with_token(token.clone()).is_err());
+    let lf = interrupt(df.lazy())
+        .group_by([col("a")])
+        .agg([col("a").count().alias("n")]);
+    assert!(lf.collect_with_token(token).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_logical_and_or_kleene() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(true), Some(false), None, None],
+        "b" => [None, None, Some(true), Some(false)],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .logical_and_with_kleene(col("b"), true)
+                .alias("and_kleene"),
+            col("a")
+                .logical_or_with_kleene(col("b"), true)
+                .alias("or_kleene"),
+            col("a")
+                .logical_and_with_kleene(col("b"), false)
+                .alias("and"),
+            col("a").logical_or_with_kleene(col("b"), false).alias("or"),
+        ])
+        .collect()?;
+
+    let values = |name: &str| -> PolarsResult<Vec<Option<bool>>> {
+        Ok(Vec::from(out.column(name)?.bool()?))
+    };
+    assert_eq!(
+        values("and_kleene")?,
+        [None, Some(false), None, Some(false)]
+    );
+    assert_eq!(values("or_kleene")?, [Some(true), None, Some(true), None]);
+    assert_eq!(values("and")?, [None, None, None, None]);
+    assert_eq!(values("or")?, [None, None, None, None]);
+    Ok(())
+}
+
+#[test]
+fn test_preview() -> PolarsResult<()> {
+    let df = df!["a" => (0..10).collect::<Vec<i32>>()]?;
+    let values = |out: DataFrame| -> PolarsResult<Vec<Option<i32>>> {
+        Ok(out.column("a")?.i32()?.into_iter().collect())
+    };
+
+    let out = df.clone().lazy().preview(3, 2)?;
+    assert_eq!(values(out)?, [Some(0), Some(1), Some(2), Some(8), Some(9)]);
+
+    // Overlapping head and tail don't duplicate rows.
+    let out = df.clone().lazy().preview(6, 6)?;
+    assert!(out.equals(&df));
+    let out = df.clone().lazy().preview(20, 0)?;
+    assert!(out.equals(&df));
+    let out = df.lazy().filter(col("a").gt(lit(100))).preview(2, 2)?;
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_lazy_rename_with() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2],
+        "b" => [3, 4]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .rename_with(|name| Ok(format!("{name}_right").into()))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a_right", "b_right"]);
+
+    let out = df.lazy().rename_with(|_| Ok("c".into())).collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
@@ -40,7 +40,7 @@ impl Executor for SinkExecutor {
             Cow::Borrowed("")
         };
 
-        state.clone().record(
+        state.clone().record_df(
             || (self.f)(df, state).map(|df| df.unwrap_or_else(DataFrame::empty)),
             profile_name,
         )
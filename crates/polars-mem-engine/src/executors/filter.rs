@@ -1,9 +1,18 @@
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::utils::arrow::bitmap::utils::SlicesIterator;
 
 use super::*;
 
+/// A part of a filter predicate, together with the input columns it depends on.
+pub struct FilterPredicate {
+    pub(crate) expr: Arc<dyn PhysicalExpr>,
+    pub(crate) live_columns: Vec<PlSmallStr>,
+}
+
 pub struct FilterExec {
-    pub(crate) predicate: Arc<dyn PhysicalExpr>,
+    /// The parts of the predicate that are `AND`-ed together. These are applied in order, every
+    /// part is only evaluated on the rows that passed the previous parts.
+    pub(crate) predicates: Vec<FilterPredicate>,
     pub(crate) input: Box<dyn Executor>,
     // if the predicate contains a window function
     has_window: bool,
@@ -18,15 +27,102 @@ fn column_to_mask(c: &Column) -> PolarsResult<&BooleanChunked> {
     })
 }
 
+/// The indices of the rows of a frame of `height` rows that are selected by `mask`.
+fn mask_to_selection(mask: &BooleanChunked, height: usize) -> PolarsResult<IdxCa> {
+    if mask.len() == 1 && height != 1 {
+        let idx = if mask.get(0) == Some(true) {
+            (0..height as IdxSize).collect()
+        } else {
+            vec![]
+        };
+        return Ok(IdxCa::from_vec(PlSmallStr::EMPTY, idx));
+    }
+    polars_ensure!(
+        mask.len() == height,
+        ShapeMismatch: "filter's length: {} differs from that of the series: {}",
+        mask.len(), height
+    );
+
+    let mut idx = Vec::with_capacity(mask.sum().unwrap_or(0) as usize);
+    let mut chunk_offset = 0;
+    for arr in mask.downcast_iter() {
+        let values = match arr.validity() {
+            Some(validity) if validity.unset_bits() > 0 => validity & arr.values(),
+            _ => arr.values().clone(),
+        };
+        for (offset, len) in SlicesIterator::new(&values) {
+            let start = (chunk_offset + offset) as IdxSize;
+            idx.extend(start..start + len as IdxSize);
+        }
+        chunk_offset += arr.len();
+    }
+    Ok(IdxCa::from_vec(PlSmallStr::EMPTY, idx))
+}
+
+/// Filter `df` by the `AND`-ed `predicates`.
+pub(crate) fn apply_filter_predicates(
+    predicates: &[FilterPredicate],
+    df: DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let (first, rest) = predicates.split_first().unwrap();
+    let c = first.expr.evaluate(&df, state)?;
+    if rest.is_empty() {
+        // @scalar-opt
+        // @partition-opt
+        return df.filter(column_to_mask(&c)?);
+    }
+
+    // Keep the surviving rows as a selection vector. The remaining predicates only gather
+    // the columns they need and the full frame is gathered once at the end.
+    let mut selection = mask_to_selection(column_to_mask(&c)?, df.height())?;
+    for predicate in rest {
+        if selection.is_empty() {
+            break;
+        }
+        let selected = df._select_impl_unchecked(&predicate.live_columns)?;
+        // SAFETY: the selection only contains indices of rows in `df`.
+        let selected = unsafe { selected.take_unchecked(&selection) };
+        let c = predicate.expr.evaluate(&selected, state)?;
+        selection = selection.filter(column_to_mask(&c)?)?;
+    }
+    // SAFETY: the selection only contains indices of rows in `df`.
+    Ok(unsafe { df.take_unchecked(&selection) })
+}
+
+/// Filter `df` by elementwise `predicates`, in parallel over the chunks of `df`.
+pub(crate) fn filter_elementwise(
+    predicates: &[FilterPredicate],
+    mut df: DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let n_partitions = POOL.current_num_threads();
+    let chunks = if df.height() == 0 {
+        return apply_filter_predicates(predicates, df, state);
+    } else if df.first_col_n_chunks() > 1 {
+        df.split_chunks().collect::<Vec<_>>()
+    } else if df.width() < n_partitions {
+        return apply_filter_predicates(predicates, df, state);
+    } else {
+        df.split_chunks_by_n(n_partitions, true)
+    };
+    let iter = chunks
+        .into_par_iter()
+        .map(|df| apply_filter_predicates(predicates, df, state));
+    let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
+    Ok(accumulate_dataframes_vertical_unchecked(df))
+}
+
 impl FilterExec {
     pub fn new(
-        predicate: Arc<dyn PhysicalExpr>,
+        predicates: Vec<FilterPredicate>,
         input: Box<dyn Executor>,
         has_window: bool,
         streamable: bool,
     ) -> Self {
+        debug_assert!(!predicates.is_empty());
         Self {
-            predicate,
+            predicates,
             input,
             has_window,
             streamable,
@@ -41,49 +137,20 @@ impl FilterExec {
         if self.has_window {
             state.insert_has_window_function_flag()
         }
-        let c = self.predicate.evaluate(&df, state)?;
+        let out = apply_filter_predicates(&self.predicates, df, state);
         if self.has_window {
             state.clear_window_expr_cache()
         }
-
-        // @scalar-opt
-        // @partition-opt
-        df.filter(column_to_mask(&c)?)
-    }
-
-    fn execute_chunks(
-        &mut self,
-        chunks: Vec<DataFrame>,
-        state: &ExecutionState,
-    ) -> PolarsResult<DataFrame> {
-        let iter = chunks.into_par_iter().map(|df| {
-            let c = self.predicate.evaluate(&df, state)?;
-
-            // @scalar-opt
-            // @partition-opt
-            df.filter(column_to_mask(&c)?)
-        });
-        let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
-        Ok(accumulate_dataframes_vertical_unchecked(df))
+        out
     }
 
     fn execute_impl(
         &mut self,
-        mut df: DataFrame,
+        df: DataFrame,
         state: &mut ExecutionState,
     ) -> PolarsResult<DataFrame> {
-        let n_partitions = POOL.current_num_threads();
-        // Vertical parallelism.
-        if self.streamable && df.height() > 0 {
-            if df.first_col_n_chunks() > 1 {
-                let chunks = df.split_chunks().collect::<Vec<_>>();
-                self.execute_chunks(chunks, state)
-            } else if df.width() < n_partitions {
-                self.execute_hor(df, state)
-            } else {
-                let chunks = df.split_chunks_by_n(n_partitions, true);
-                self.execute_chunks(chunks, state)
-            }
+        if self.streamable {
+            filter_elementwise(&self.predicates, df, state)
         } else {
             self.execute_hor(df, state)
         }
@@ -102,12 +169,17 @@ impl Executor for FilterExec {
         let df = self.input.execute(state)?;
 
         let profile_name = if state.has_node_timer() {
-            Cow::Owned(format!(".filter({})", &self.predicate.as_ref()))
+            let predicates = self
+                .predicates
+                .iter()
+                .map(|p| format!("{}", &p.expr.as_ref()))
+                .collect::<Vec<_>>();
+            Cow::Owned(format!(".filter({})", predicates.join(" & ")))
         } else {
             Cow::Borrowed("")
         };
 
-        state.clone().record(
+        state.clone().record_df(
             || {
                 let df = self.execute_impl(df, state);
                 if state.verbose() {
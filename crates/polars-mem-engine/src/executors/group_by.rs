@@ -11,6 +11,7 @@ pub(super) fn evaluate_aggs(
     POOL.install(|| {
         aggs.par_iter()
             .map(|expr| {
+                state.should_stop()?;
                 let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
                 polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
                 Ok(agg)
@@ -65,6 +66,7 @@ pub(super) fn group_by_helper(
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
+    state.should_stop()?;
 
     if let Some(f) = apply {
         return gb.sliced(slice).apply(move |df| f.call(df));
@@ -125,6 +127,7 @@ impl Executor for GroupByExec {
             eprintln!("keys/aggregates are not partitionable: running default HASH AGGREGATION")
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -140,7 +143,7 @@ impl Executor for GroupByExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            new_state.record_df(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
         }
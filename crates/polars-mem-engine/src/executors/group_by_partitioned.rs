@@ -359,6 +359,7 @@ impl Executor for PartitionGroupByExec {
             }
         }
         let original_df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -373,7 +374,7 @@ impl Executor for PartitionGroupByExec {
         };
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, original_df), profile_name)
+            new_state.record_df(|| self.execute_impl(state, original_df), profile_name)
         } else {
             self.execute_impl(state, original_df)
         }
@@ -65,6 +65,7 @@ impl Executor for JoinExec {
 
         let df_left = df_left?;
         let df_right = df_right?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -78,7 +79,7 @@ impl Executor for JoinExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| {
+        state.record_df(|| {
 
             let left_on_series = self
                 .left_on
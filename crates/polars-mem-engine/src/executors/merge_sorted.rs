@@ -28,7 +28,7 @@ impl Executor for MergeSorted {
         };
 
         let profile_name = Cow::Borrowed("Merge Sorted");
-        state.record(
+        state.record_df(
             || {
                 let lhs = left.column(self.key.as_str())?;
                 let rhs = right.column(self.key.as_str())?;
@@ -14,6 +14,9 @@ pub struct ProjectionExec {
     pub(crate) options: ProjectionOptions,
     // Can run all operations elementwise
     pub(crate) allow_vertical_parallelism: bool,
+    // An elementwise filter on the input that is fused into this projection, so that both are
+    // applied to a chunk before moving on to the next chunk.
+    pub(crate) filter: Option<Vec<FilterPredicate>>,
 }
 
 impl ProjectionExec {
@@ -29,7 +32,11 @@ impl ProjectionExec {
             && self.options.run_parallel
         {
             let chunks = df.split_chunks().collect::<Vec<_>>();
-            let iter = chunks.into_par_iter().map(|mut df| {
+            let iter = chunks.into_par_iter().map(|df| {
+                let mut df = match &self.filter {
+                    Some(predicates) => apply_filter_predicates(predicates, df, state)?,
+                    None => df,
+                };
                 let selected_cols = evaluate_physical_expressions(
                     &mut df,
                     &self.expr,
@@ -45,6 +52,9 @@ impl ProjectionExec {
         }
         // Only horizontal parallelism.
         else {
+            if let Some(predicates) = &self.filter {
+                df = filter_elementwise(predicates, df, state)?;
+            }
             #[allow(clippy::let_and_return)]
             let selected_cols = evaluate_physical_expressions(
                 &mut df,
@@ -87,7 +97,11 @@ impl Executor for ProjectionExec {
                 .iter()
                 .map(|s| profile_name(s.as_ref(), self.input_schema.as_ref()))
                 .collect::<PolarsResult<Vec<_>>>()?;
-            let name = comma_delimited("select".to_string(), &by);
+            let name = if self.filter.is_some() {
+                comma_delimited("filter_select".to_string(), &by)
+            } else {
+                comma_delimited("select".to_string(), &by)
+            };
             Cow::Owned(name)
         } else {
             Cow::Borrowed("")
@@ -95,7 +109,7 @@ impl Executor for ProjectionExec {
 
         if state.has_node_timer() {
             let new_state = state.clone();
-            new_state.record(|| self.execute_impl(state, df), profile_name)
+            new_state.record_df(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
         }
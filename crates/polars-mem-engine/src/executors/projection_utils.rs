@@ -229,6 +229,18 @@ fn execute_projection_cached_window_fns(
     Ok(selected_columns)
 }
 
+/// Evaluate a projection with window functions one expression at a time, in order.
+fn execute_projection_window_fns_seq(
+    df: &DataFrame,
+    exprs: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Column>> {
+    let mut state = state.split();
+    state.insert_has_window_function_flag();
+    state.remove_cache_window_flag();
+    run_exprs_seq(df, exprs, &state)
+}
+
 fn run_exprs_par(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
@@ -257,8 +269,10 @@ pub(super) fn evaluate_physical_expressions(
     has_windows: bool,
     run_parallel: bool,
 ) -> PolarsResult<Vec<Column>> {
-    let expr_runner = if has_windows {
+    let expr_runner = if has_windows && run_parallel {
         execute_projection_cached_window_fns
+    } else if has_windows {
+        execute_projection_window_fns_seq
     } else if run_parallel && exprs.len() > 1 {
         run_exprs_par
     } else {
@@ -59,14 +59,14 @@ impl Executor for AnonymousScanExec {
         }
 
         match (self.function.allows_predicate_pushdown(), &self.predicate) {
-            (true, Some(predicate)) => state.record(
+            (true, Some(predicate)) => state.record_df(
                 || {
                     args.predicate = predicate.predicate.as_expression().cloned();
                     self.function.scan(args)
                 },
                 "anonymous_scan".into(),
             ),
-            (false, Some(predicate)) => state.record(
+            (false, Some(predicate)) => state.record_df(
                 || {
                     let mut df = self.function.scan(args)?;
                     let s = predicate.predicate.evaluate(&df, state)?;
@@ -82,7 +82,7 @@ impl Executor for AnonymousScanExec {
                 },
                 "anonymous_scan".into(),
             ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
+            _ => state.record_df(|| self.function.scan(args), "anonymous_scan".into()),
         }
     }
 }
@@ -115,7 +115,7 @@ impl Executor for PartitionedSinkExecutor {
 
         state
             .clone()
-            .record(|| streaming_exec.execute(state), profile_name)
+            .record_df(|| streaming_exec.execute(state), profile_name)
     }
 }
 
@@ -22,7 +22,7 @@ impl Executor for UniqueExec {
             .map(|v| v.iter().cloned().collect::<Vec<_>>());
         let keep = self.options.keep_strategy;
 
-        state.record(
+        state.record_df(
             || {
                 if df.is_empty() {
                     return Ok(df);
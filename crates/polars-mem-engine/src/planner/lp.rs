@@ -20,6 +20,53 @@ use crate::predicate::PhysicalColumnPredicates;
 pub type StreamingExecutorBuilder =
     fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<Box<dyn Executor>>;
 
+/// A rough estimate of the relative cost of evaluating a predicate. Function calls (e.g.
+/// string functions and UDFs) are considered a lot more expensive than column comparisons.
+fn predicate_cost(node: Node, expr_arena: &Arena<AExpr>) -> usize {
+    expr_arena
+        .iter(node)
+        .map(|(_, ae)| match ae {
+            AExpr::Function { .. } | AExpr::AnonymousFunction { .. } => 10,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Create the physical expressions of the `AND`-ed parts of a filter predicate.
+fn create_filter_predicates(
+    predicate: &ExprIR,
+    streamable: bool,
+    expr_arena: &Arena<AExpr>,
+    input_schema: &SchemaRef,
+    state: &mut ExpressionConversionState,
+) -> PolarsResult<Vec<executors::FilterPredicate>> {
+    // Elementwise predicates can be applied one `AND`-ed part at a time. Evaluate the
+    // cheap parts first, so that the expensive parts only see the remaining rows.
+    let mut parts = if streamable {
+        MintermIter::new(predicate.node(), expr_arena).collect::<Vec<_>>()
+    } else {
+        vec![predicate.node()]
+    };
+    parts.sort_by_cached_key(|node| predicate_cost(*node, expr_arena));
+    parts
+        .into_iter()
+        .map(|node| {
+            let expr = create_physical_expr(
+                &ExprIR::from_node(node, expr_arena),
+                Context::Default,
+                expr_arena,
+                input_schema,
+                state,
+            )?;
+            let live_columns = aexpr_to_leaf_names_iter(node, expr_arena)
+                .collect::<PlIndexSet<_>>()
+                .into_iter()
+                .collect();
+            Ok(executors::FilterPredicate { expr, live_columns })
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+}
+
 fn partitionable_gb(
     keys: &[ExprIR],
     aggs: &[ExprIR],
@@ -288,6 +335,7 @@ fn create_physical_plan_impl(
                                         .with_row_group_size(options.row_group_size)
                                         .with_data_page_size(options.data_page_size)
                                         .with_key_value_metadata(options.key_value_metadata.clone())
+                                        .with_maintain_order(sink_options.maintain_order)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
@@ -409,15 +457,15 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
-            let predicate = create_physical_expr(
+            let predicates = create_filter_predicates(
                 &predicate,
-                Context::Default,
+                streamable,
                 expr_arena,
                 &input_schema,
                 &mut state,
             )?;
             Ok(Box::new(executors::FilterExec::new(
-                predicate,
+                predicates,
                 input,
                 state.has_windows,
                 streamable,
@@ -514,8 +562,35 @@ fn create_physical_plan_impl(
             ..
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+            let all_elementwise = options.should_broadcast
+                && expr
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), expr_arena));
+
+            // Fuse an elementwise filter into the projection, so that every chunk is filtered
+            // and projected in one go instead of materializing the filtered frame first.
+            let (input, filter) = match lp_arena.get(input) {
+                Filter {
+                    input: filter_input,
+                    predicate,
+                } if all_elementwise && is_elementwise_rec(predicate.node(), expr_arena) => {
+                    let mut state = ExpressionConversionState::new(true);
+                    let predicates = create_filter_predicates(
+                        predicate,
+                        true,
+                        expr_arena,
+                        &input_schema,
+                        &mut state,
+                    )?;
+                    (*filter_input, Some(predicates))
+                },
+                _ => (input, None),
+            };
+
             let input = recurse!(input, state)?;
-            let mut state = ExpressionConversionState::new(POOL.current_num_threads() > expr.len());
+            let mut state = ExpressionConversionState::new(
+                options.run_parallel && POOL.current_num_threads() > expr.len(),
+            );
             let phys_expr = create_physical_expressions_from_irs(
                 &expr,
                 Context::Default,
@@ -524,7 +599,7 @@ fn create_physical_plan_impl(
                 &mut state,
             )?;
 
-            let allow_vertical_parallelism = options.should_broadcast && expr.iter().all(|e| is_elementwise_rec(e.node(), expr_arena))
+            let allow_vertical_parallelism = all_elementwise
                 // If all columns are literal we would get a 1 row per thread.
                 && !phys_expr.iter().all(|p| {
                     p.is_literal()
@@ -539,6 +614,7 @@ fn create_physical_plan_impl(
                 schema: _schema,
                 options,
                 allow_vertical_parallelism,
+                filter,
             }))
         },
         DataFrameScan {
@@ -784,8 +860,9 @@ fn create_physical_plan_impl(
                     .iter()
                     .all(|e| is_elementwise_rec(e.node(), expr_arena));
 
-            let mut state =
-                ExpressionConversionState::new(POOL.current_num_threads() > exprs.len());
+            let mut state = ExpressionConversionState::new(
+                options.run_parallel && POOL.current_num_threads() > exprs.len(),
+            );
 
             let phys_exprs = create_physical_expressions_from_irs(
                 &exprs,
@@ -0,0 +1,52 @@
+use polars_core::prelude::*;
+use sha2::Digest;
+
+/// Hash every value with the digest `D` and format the result as a lowercase hex string.
+pub(super) fn hex_digest<D: Digest>(ca: &BinaryChunked) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut buf = vec![0u8; 2 * <D as Digest>::output_size()];
+    for opt_v in ca.iter() {
+        match opt_v {
+            Some(v) => {
+                hex::encode_to_slice(D::digest(v), &mut buf).unwrap();
+                // SAFETY: hex encoding only produces ASCII characters.
+                builder.append_value(unsafe { std::str::from_utf8_unchecked(&buf) });
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::binary::BinaryNameSpaceImpl;
+
+    #[test]
+    fn test_hex_digest() {
+        let ca = BinaryChunked::from_slice_options(
+            "a".into(),
+            &[Some(b"".as_slice()), Some(b"polars".as_slice()), None],
+        );
+
+        let out = ca.md5();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("d41d8cd98f00b204e9800998ecf8427e"),
+                Some("33f83688ff9c10a6eb6a9b34d8fac7d7"),
+                None
+            ]
+        );
+        let out = ca.sha256();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+                Some("e37e7e864c58e1e96380229f66040d1852e73f5e80739aa742488ea869faecc1"),
+                None
+            ]
+        );
+    }
+}
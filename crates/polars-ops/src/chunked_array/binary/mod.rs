@@ -1,4 +1,8 @@
+#[cfg(feature = "binary_hash")]
+mod hash;
 mod namespace;
+#[cfg(feature = "binary_encoding")]
+mod uuid;
 
 pub use namespace::*;
 use polars_core::prelude::*;
@@ -155,6 +155,33 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Parse UUIDs from their text form into their 16-byte binary form.
+    ///
+    /// Both the hyphenated form and 32 hex digits are accepted. Invalid values raise an error
+    /// if `strict`, and become null otherwise.
+    #[cfg(feature = "binary_encoding")]
+    fn uuid_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        super::uuid::uuid_decode(self.as_binary(), strict)
+    }
+
+    /// Format 16-byte values as lowercase hyphenated UUIDs.
+    #[cfg(feature = "binary_encoding")]
+    fn uuid_encode(&self) -> PolarsResult<StringChunked> {
+        super::uuid::uuid_encode(self.as_binary())
+    }
+
+    /// Compute the MD5 digest of each value, formatted as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    fn md5(&self) -> StringChunked {
+        super::hash::hex_digest::<md5::Md5>(self.as_binary())
+    }
+
+    /// Compute the SHA-256 digest of each value, formatted as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    fn sha256(&self) -> StringChunked {
+        super::hash::hex_digest::<sha2::Sha256>(self.as_binary())
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn reinterpret(&self, dtype: &DataType, is_little_endian: bool) -> PolarsResult<Series> {
         unsafe {
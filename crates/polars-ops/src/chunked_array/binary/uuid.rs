@@ -0,0 +1,120 @@
+use polars_core::prelude::*;
+
+/// Byte offsets of the hyphens in the canonical `8-4-4-4-12` text form of a UUID.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Parse a UUID from its canonical hyphenated form or from 32 hex digits without hyphens.
+pub(super) fn parse_uuid(s: &[u8]) -> Option<[u8; 16]> {
+    let mut digits = [0u8; 32];
+    match s.len() {
+        32 => digits.copy_from_slice(s),
+        36 => {
+            if HYPHENS.iter().any(|&i| s[i] != b'-') {
+                return None;
+            }
+            let mut n = 0;
+            for (i, &b) in s.iter().enumerate() {
+                if !HYPHENS.contains(&i) {
+                    digits[n] = b;
+                    n += 1;
+                }
+            }
+        },
+        _ => return None,
+    }
+    let mut out = [0u8; 16];
+    hex::decode_to_slice(digits, &mut out).ok()?;
+    Some(out)
+}
+
+/// Format 16 bytes as a lowercase hyphenated UUID.
+fn format_uuid<'a>(v: &[u8; 16], buf: &'a mut [u8; 36]) -> &'a str {
+    let groups = [(0, 4), (4, 6), (6, 8), (8, 10), (10, 16)];
+    let mut pos = 0;
+    for (i, (start, end)) in groups.into_iter().enumerate() {
+        if i > 0 {
+            buf[pos] = b'-';
+            pos += 1;
+        }
+        let len = 2 * (end - start);
+        hex::encode_to_slice(&v[start..end], &mut buf[pos..pos + len]).unwrap();
+        pos += len;
+    }
+    // SAFETY: hex encoding and hyphens only produce ASCII characters.
+    unsafe { std::str::from_utf8_unchecked(buf) }
+}
+
+pub(super) fn uuid_decode(ca: &BinaryChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut builder = BinaryChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_v in ca.iter() {
+        match opt_v.map(|v| (v, parse_uuid(v))) {
+            Some((_, Some(uuid))) => builder.append_value(uuid),
+            Some((v, None)) if strict => polars_bail!(
+                ComputeError:
+                "invalid UUID {:?} found; try setting `strict=false` to ignore",
+                String::from_utf8_lossy(v)
+            ),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+pub(super) fn uuid_encode(ca: &BinaryChunked) -> PolarsResult<StringChunked> {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut buf = [0u8; 36];
+    for opt_v in ca.iter() {
+        match opt_v {
+            Some(v) => {
+                let v: &[u8; 16] = v.try_into().map_err(|_| {
+                    polars_err!(
+                        ComputeError: "cannot format a value of {} bytes as a UUID; expected 16",
+                        v.len()
+                    )
+                })?;
+                builder.append_value(format_uuid(v, &mut buf));
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::binary::BinaryNameSpaceImpl;
+
+    #[test]
+    fn test_uuid_round_trip() -> PolarsResult<()> {
+        let ca = BinaryChunked::from_slice_options(
+            "a".into(),
+            &[
+                Some(b"67e55044-10b1-426f-9247-bb680e5fe0c8".as_slice()),
+                Some(b"67E5504410B1426F9247BB680E5FE0C8".as_slice()),
+                None,
+                Some(b"not-a-uuid".as_slice()),
+            ],
+        );
+        assert!(ca.uuid_decode(true).is_err());
+
+        let bytes = ca.uuid_decode(false)?;
+        assert_eq!(bytes.get(0).map(<[u8]>::len), Some(16));
+        assert_eq!(bytes.get(0), bytes.get(1));
+        assert_eq!(bytes.null_count(), 2);
+
+        let out = bytes.uuid_encode()?;
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                None,
+                None
+            ]
+        );
+
+        assert!(ca.uuid_encode().is_err());
+        Ok(())
+    }
+}
@@ -169,6 +169,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_path_match() {
+        let s = Series::new(
+            "json".into(),
+            [
+                Some(r#"{"a":1,"b":{"c":"hello"}}"#),
+                Some(r#"{"a":null,"b":{"c":"world"}}"#),
+                None,
+            ],
+        );
+        let ca = s.str().unwrap();
+
+        let path = StringChunked::new("path".into(), ["$.b.c"]);
+        let out = ca.json_path_match(&path).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("hello"), Some("world"), None]);
+
+        // JSON nulls become missing values and every row may use its own path.
+        let paths = StringChunked::new("path".into(), [Some("$.a"), Some("$.a"), Some("$.a")]);
+        let out = ca.json_path_match(&paths).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("1"), None, None]);
+
+        let invalid = StringChunked::new("path".into(), ["$.["]);
+        assert!(ca.json_path_match(&invalid).is_err());
+    }
+
     #[test]
     fn test_json_infer() {
         let s = Series::new(
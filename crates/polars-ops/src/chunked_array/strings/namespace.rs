@@ -12,6 +12,8 @@ use polars_utils::regex_cache::{compile_regex, with_regex_cache};
 use super::*;
 #[cfg(feature = "binary_encoding")]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
+#[cfg(feature = "string_net")]
+use crate::prelude::strings::net::UrlPart;
 #[cfg(feature = "string_normalize")]
 use crate::prelude::strings::normalize::UnicodeForm;
 
@@ -132,6 +134,14 @@ pub trait StringNameSpaceImpl: AsString {
         ca.as_binary().base64_decode(strict)
     }
 
+    /// Parse UUIDs into their 16-byte binary form, which is half the size of the text form and
+    /// compares and hashes faster.
+    #[cfg(feature = "binary_encoding")]
+    fn uuid_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().uuid_decode(strict)
+    }
+
     #[must_use]
     #[cfg(feature = "string_encoding")]
     fn base64_encode(&self) -> StringChunked {
@@ -665,6 +675,37 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Extract a component of the URL values.
+    #[must_use]
+    #[cfg(feature = "string_net")]
+    fn url_extract(&self, part: UrlPart) -> StringChunked {
+        let ca = self.as_string();
+        net::url_extract(ca, part)
+    }
+
+    /// Check if the string values are valid IPv4 or IPv6 addresses.
+    #[must_use]
+    #[cfg(feature = "string_net")]
+    fn ip_is_valid(&self) -> BooleanChunked {
+        let ca = self.as_string();
+        net::ip_is_valid(ca)
+    }
+
+    /// Format the IP address values in their canonical form.
+    #[must_use]
+    #[cfg(feature = "string_net")]
+    fn ip_normalize(&self) -> StringChunked {
+        let ca = self.as_string();
+        net::ip_normalize(ca)
+    }
+
+    /// Check if the IP address values are contained in the network given in CIDR notation.
+    #[cfg(feature = "string_net")]
+    fn ip_in_cidr(&self, cidr: &str) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_string();
+        net::ip_in_cidr(ca, cidr)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
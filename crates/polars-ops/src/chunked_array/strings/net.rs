@@ -0,0 +1,195 @@
+use std::net::IpAddr;
+
+use polars_core::prelude::arity::{unary_elementwise, unary_elementwise_values};
+use polars_core::prelude::*;
+use url::Url;
+
+/// The component of a URL to extract.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UrlPart {
+    Scheme,
+    Host,
+    Path,
+    Query,
+    Fragment,
+}
+
+/// Extract a component of absolute URLs. Values that cannot be parsed as a URL, or that don't
+/// have the requested component, are set to null.
+pub(super) fn url_extract(ca: &StringChunked, part: UrlPart) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        let Some(url) = opt_s.and_then(|s| Url::parse(s).ok()) else {
+            builder.append_null();
+            continue;
+        };
+        let value = match part {
+            UrlPart::Scheme => Some(url.scheme()),
+            UrlPart::Host => url.host_str(),
+            UrlPart::Path => Some(url.path()),
+            UrlPart::Query => url.query(),
+            UrlPart::Fragment => url.fragment(),
+        };
+        builder.append_option(value);
+    }
+    builder.finish()
+}
+
+/// Check whether the values are valid IPv4 or IPv6 addresses.
+pub(super) fn ip_is_valid(ca: &StringChunked) -> BooleanChunked {
+    unary_elementwise_values(ca, |s| s.parse::<IpAddr>().is_ok())
+}
+
+/// Format IP addresses in their canonical form. IPv4-mapped IPv6 addresses are converted to
+/// IPv4 and invalid addresses are set to null.
+pub(super) fn ip_normalize(ca: &StringChunked) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut buf = String::new();
+    for opt_s in ca.iter() {
+        match opt_s.and_then(|s| s.parse::<IpAddr>().ok()) {
+            Some(ip) => {
+                use std::fmt::Write;
+                buf.clear();
+                write!(buf, "{}", ip.to_canonical()).unwrap();
+                builder.append_value(&buf);
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// An IP network given in CIDR notation, e.g. `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Clone, Copy, Debug)]
+struct IpNetwork {
+    addr: IpAddr,
+    prefix_len: u32,
+}
+
+impl IpNetwork {
+    fn parse(cidr: &str) -> PolarsResult<Self> {
+        let err = || polars_err!(InvalidOperation: "invalid CIDR notation: '{}'", cidr);
+        let (addr, prefix_len) = match cidr.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (cidr, None),
+        };
+        let addr = addr.parse::<IpAddr>().map_err(|_| err())?.to_canonical();
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(len) => len.parse::<u32>().map_err(|_| err())?,
+            None => max_len,
+        };
+        polars_ensure!(prefix_len <= max_len, InvalidOperation: "invalid CIDR notation: '{}'", cidr);
+        Ok(Self { addr, prefix_len })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix_len).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            },
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix_len).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Check whether the IP addresses fall within the network given in CIDR notation. Addresses of
+/// the other IP family are never contained, invalid addresses are set to null.
+pub(super) fn ip_in_cidr(ca: &StringChunked, cidr: &str) -> PolarsResult<BooleanChunked> {
+    let network = IpNetwork::parse(cidr)?;
+    Ok(unary_elementwise(ca, |opt_s| {
+        let ip = opt_s?.parse::<IpAddr>().ok()?;
+        Some(network.contains(ip))
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_url_extract() {
+        let ca = StringChunked::new(
+            "url".into(),
+            [
+                Some("https://pola.rs/docs/api?lang=rust#joins"),
+                Some("ftp://example.com"),
+                Some("not a url"),
+                None,
+            ],
+        );
+
+        let out = url_extract(&ca, UrlPart::Scheme);
+        assert_eq!(Vec::from(&out), &[Some("https"), Some("ftp"), None, None]);
+        let out = url_extract(&ca, UrlPart::Host);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("pola.rs"), Some("example.com"), None, None]
+        );
+        let out = url_extract(&ca, UrlPart::Path);
+        assert_eq!(Vec::from(&out), &[Some("/docs/api"), Some("/"), None, None]);
+        let out = url_extract(&ca, UrlPart::Query);
+        assert_eq!(Vec::from(&out), &[Some("lang=rust"), None, None, None]);
+        let out = url_extract(&ca, UrlPart::Fragment);
+        assert_eq!(Vec::from(&out), &[Some("joins"), None, None, None]);
+    }
+
+    #[test]
+    fn test_ip() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "ip".into(),
+            [
+                Some("10.1.2.3"),
+                Some("2001:0DB8:0000:0000:0000:0000:0000:0001"),
+                Some("::ffff:192.168.0.1"),
+                Some("300.1.1.1"),
+                None,
+            ],
+        );
+
+        let out = ip_is_valid(&ca);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(true), Some(true), Some(false), None]
+        );
+
+        let out = ip_normalize(&ca);
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("10.1.2.3"),
+                Some("2001:db8::1"),
+                Some("192.168.0.1"),
+                None,
+                None
+            ]
+        );
+
+        let out = ip_in_cidr(&ca, "10.0.0.0/8")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(false), None, None]
+        );
+        let out = ip_in_cidr(&ca, "2001:db8::/32")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(true), Some(false), None, None]
+        );
+        let out = ip_in_cidr(&ca, "0.0.0.0/0")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), None, None]
+        );
+
+        assert!(ip_in_cidr(&ca, "10.0.0.0/33").is_err());
+        assert!(ip_in_cidr(&ca, "10.0.0/8").is_err());
+        Ok(())
+    }
+}
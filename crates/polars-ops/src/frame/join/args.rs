@@ -30,6 +30,13 @@ pub struct JoinArgs {
     pub nulls_equal: bool,
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
+    /// Force the hash table to be built on this side. By default the engine chooses the side
+    /// with the smaller estimated row count or cardinality.
+    ///
+    /// Only the streaming equi-join supports this. Other joins, and the in-memory engine, raise
+    /// an error if it is set, as does a `maintain_order` that requires the other build side.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub build_side: Option<JoinBuildSide>,
 }
 
 impl JoinArgs {
@@ -106,6 +113,16 @@ pub enum MaintainOrderJoin {
     RightLeft,
 }
 
+/// The input of an equi-join that the hash table is built on.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum JoinBuildSide {
+    Left,
+    Right,
+}
+
 impl MaintainOrderJoin {
     pub(super) fn flip(&self) -> Self {
         match self {
@@ -128,6 +145,7 @@ impl JoinArgs {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            build_side: None,
         }
     }
 
@@ -141,6 +159,12 @@ impl JoinArgs {
         self
     }
 
+    /// Set [`JoinArgs::build_side`], which only the streaming equi-join supports.
+    pub fn with_build_side(mut self, build_side: Option<JoinBuildSide>) -> Self {
+        self.build_side = build_side;
+        self
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         const DEFAULT: &PlSmallStr = &PlSmallStr::from_static("_right");
         self.suffix.as_ref().unwrap_or(DEFAULT)
@@ -335,6 +359,23 @@ impl JoinValidation {
         }
     }
 
+    /// Drop the uniqueness checks for a side whose join keys are already known to be unique.
+    pub fn assume_unique(self, left: bool, right: bool) -> Self {
+        use JoinValidation::*;
+        let (left_one, right_one) = match self {
+            ManyToMany => (false, false),
+            ManyToOne => (false, true),
+            OneToMany => (true, false),
+            OneToOne => (true, true),
+        };
+        match (left_one && !left, right_one && !right) {
+            (false, false) => ManyToMany,
+            (false, true) => ManyToOne,
+            (true, false) => OneToMany,
+            (true, true) => OneToOne,
+        }
+    }
+
     pub fn is_valid_join(&self, join_type: &JoinType) -> PolarsResult<()> {
         if !self.needs_checks() {
             return Ok(());
@@ -13,6 +13,14 @@ use super::*;
 // Use a small element per thread threshold for debugging/testing purposes.
 const MIN_ELEMS_PER_THREAD: usize = if cfg!(debug_assertions) { 1 } else { 128 };
 
+/// Build sides with fewer keys than this are hashed into a single map that is shared by all
+/// probing threads, instead of into a partitioned table. Set with
+/// [`Config::join_broadcast_threshold`](polars_core::config::Config) or
+/// `POLARS_JOIN_BROADCAST_THRESHOLD`.
+fn broadcast_threshold() -> usize {
+    polars_core::config::get_join_broadcast_threshold().unwrap_or(2 * MIN_ELEMS_PER_THREAD)
+}
+
 pub(crate) fn build_tables<T, I>(
     keys: Vec<I>,
     nulls_equal: bool,
@@ -32,7 +40,7 @@ where
         .sum();
 
     // Don't bother parallelizing anything for small inputs.
-    if num_keys_est < 2 * MIN_ELEMS_PER_THREAD {
+    if num_keys_est < broadcast_threshold() {
         let mut hm: PlHashMap<T::TotalOrdItem, IdxVec> = PlHashMap::new();
         let mut offset = 0;
         for it in keys {
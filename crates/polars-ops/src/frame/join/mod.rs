@@ -88,10 +88,19 @@ pub trait DataFrameJoinOps: IntoDf {
         other: &DataFrame,
         left_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
         right_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
-        args: JoinArgs,
+        mut args: JoinArgs,
         options: Option<JoinTypeOptions>,
     ) -> PolarsResult<DataFrame> {
         let df_left = self.to_df();
+        let left_on = left_on.into_iter().map(Into::into).collect::<Vec<_>>();
+        let right_on = right_on.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        // Keys that cover a unique key of their frame don't need to be checked for uniqueness.
+        args.validation = args.validation.assume_unique(
+            df_left.covers_unique_key(&left_on),
+            other.covers_unique_key(&right_on),
+        );
+
         let selected_left = df_left.select_columns(left_on)?;
         let selected_right = other.select_columns(right_on)?;
 
@@ -130,6 +139,11 @@ pub trait DataFrameJoinOps: IntoDf {
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
 
+        polars_ensure!(
+            args.build_side.is_none(),
+            InvalidOperation: "forcing the join build side is only supported by the streaming equi-join"
+        );
+
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = args.how {
             if let Some(JoinTypeOptions::Cross(cross_options)) = &options {
@@ -529,6 +543,62 @@ pub trait DataFrameJoinOps: IntoDf {
             None,
         )
     }
+
+    /// Perform a left semi join on two DataFrames, keeping the rows of `self` that have a match
+    /// in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.semi_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn semi_join(
+        &self,
+        other: &DataFrame,
+        left_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        right_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> PolarsResult<DataFrame> {
+        self.join(
+            other,
+            left_on,
+            right_on,
+            JoinArgs::new(JoinType::Semi),
+            None,
+        )
+    }
+
+    /// Perform a left anti join on two DataFrames, keeping the rows of `self` that have no match
+    /// in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.anti_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn anti_join(
+        &self,
+        other: &DataFrame,
+        left_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        right_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> PolarsResult<DataFrame> {
+        self.join(
+            other,
+            left_on,
+            right_on,
+            JoinArgs::new(JoinType::Anti),
+            None,
+        )
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
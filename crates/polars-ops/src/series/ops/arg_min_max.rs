@@ -14,6 +14,18 @@ pub trait ArgAgg {
     fn arg_min(&self) -> Option<usize>;
     /// Get the index of the maximal value
     fn arg_max(&self) -> Option<usize>;
+    /// Get the index of the minimal value, or of the first NaN if there are NaN values.
+    fn nan_arg_min(&self) -> Option<usize>;
+    /// Get the index of the maximal value, or of the first NaN if there are NaN values.
+    fn nan_arg_max(&self) -> Option<usize>;
+}
+
+/// The index of the first NaN value of a float Series.
+fn first_nan_idx(s: &Series) -> Option<usize> {
+    if !s.dtype().is_float() {
+        return None;
+    }
+    s.is_nan().ok()?.first_true_idx()
 }
 
 macro_rules! with_match_physical_numeric_polars_type {(
@@ -118,6 +130,14 @@ impl ArgAgg for Series {
             _ => None,
         }
     }
+
+    fn nan_arg_min(&self) -> Option<usize> {
+        first_nan_idx(self).or_else(|| self.arg_min())
+    }
+
+    fn nan_arg_max(&self) -> Option<usize> {
+        first_nan_idx(self).or_else(|| self.arg_max())
+    }
 }
 
 fn arg_max_numeric_dispatch<T>(ca: &ChunkedArray<T>) -> Option<usize>
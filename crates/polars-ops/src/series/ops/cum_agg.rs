@@ -1,9 +1,10 @@
-use std::ops::{AddAssign, Mul};
+use std::ops::AddAssign;
 
 use arity::unary_elementwise_values;
 use arrow::array::BooleanArray;
 use arrow::bitmap::BitmapBuilder;
 use num_traits::{Bounded, One, Zero};
+use polars_compute::arithmetic::pl_num::PlNumArithmetic;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
@@ -52,11 +53,11 @@ where
 
 fn det_prod<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
-    T: Copy + Mul<Output = T>,
+    T: PlNumArithmetic,
 {
     match v {
         Some(v) => {
-            *state = *state * v;
+            *state = state.wrapping_mul(v);
             Some(Some(*state))
         },
         None => Some(None),
@@ -177,6 +178,7 @@ where
 fn cum_prod_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
+    T::Native: PlNumArithmetic,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = T::Native::one();
@@ -186,7 +188,8 @@ where
 /// Get an array with the cumulative product computed at every element.
 ///
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16, Int32, UInt32}` the `Series` is
-/// first cast to `Int64` to prevent overflow issues.
+/// first cast to `Int64` to prevent overflow issues. `Int64`, `UInt64` and `Int128` are not
+/// promoted, products that overflow them wrap around.
 pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
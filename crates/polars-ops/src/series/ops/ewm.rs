@@ -5,7 +5,10 @@ use arrow::legacy::kernels::ewm::{
 use polars_core::prelude::*;
 
 fn check_alpha(alpha: f64) -> PolarsResult<()> {
-    polars_ensure!((0.0..=1.0).contains(&alpha), ComputeError: "alpha must be in [0; 1]");
+    polars_ensure!(
+        (0.0..=1.0).contains(&alpha),
+        ComputeError: "require 0 <= `alpha` <= 1 (found {alpha})"
+    );
     Ok(())
 }
 
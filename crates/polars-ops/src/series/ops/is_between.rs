@@ -1,6 +1,8 @@
 use std::ops::BitAnd;
 
 use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
 
 use crate::series::ClosedInterval;
 
@@ -10,6 +12,23 @@ pub fn is_between(
     upper: &Series,
     closed: ClosedInterval,
 ) -> PolarsResult<BooleanChunked> {
+    // Fast path: numeric values with non-null scalar bounds are checked in a single pass.
+    if s.dtype().is_primitive_numeric()
+        && lower.dtype() == s.dtype()
+        && upper.dtype() == s.dtype()
+        && lower.len() == 1
+        && upper.len() == 1
+        && lower.null_count() == 0
+        && upper.null_count() == 0
+    {
+        return Ok(with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            let lower: &ChunkedArray<$T> = lower.as_ref().as_ref().as_ref();
+            let upper: &ChunkedArray<$T> = upper.as_ref().as_ref().as_ref();
+            is_between_scalar(ca, lower.get(0).unwrap(), upper.get(0).unwrap(), closed)
+        }));
+    }
+
     let left_cmp_op = match closed {
         ClosedInterval::None | ClosedInterval::Right => Series::gt,
         ClosedInterval::Both | ClosedInterval::Left => Series::gt_eq,
@@ -22,3 +41,65 @@ pub fn is_between(
     let right = right_cmp_op(s, upper)?;
     Ok(left.bitand(right))
 }
+
+/// Compares with the same total order as the comparison kernels, so NaN is the largest value.
+fn is_between_scalar<T>(
+    ca: &ChunkedArray<T>,
+    lower: T::Native,
+    upper: T::Native,
+    closed: ClosedInterval,
+) -> BooleanChunked
+where
+    T: PolarsNumericType,
+{
+    let (include_lower, include_upper) = match closed {
+        ClosedInterval::Both => (true, true),
+        ClosedInterval::Left => (true, false),
+        ClosedInterval::Right => (false, true),
+        ClosedInterval::None => (false, false),
+    };
+    ca.apply_nonnull_values_generic(DataType::Boolean, |v| {
+        let above = if include_lower {
+            v.tot_ge(&lower)
+        } else {
+            v.tot_gt(&lower)
+        };
+        let below = if include_upper {
+            v.tot_le(&upper)
+        } else {
+            v.tot_lt(&upper)
+        };
+        above && below
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_between_scalar_matches_comparisons() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(1.0), Some(2.0), None, Some(3.0), Some(f64::NAN)],
+        );
+        for (lower, upper) in [(1.0, 3.0), (1.0, f64::NAN)] {
+            let lower = Series::new("lower".into(), &[lower]);
+            let upper = Series::new("upper".into(), &[upper]);
+            for closed in [
+                ClosedInterval::Both,
+                ClosedInterval::Left,
+                ClosedInterval::Right,
+                ClosedInterval::None,
+            ] {
+                let fused = is_between(&s, &lower, &upper, closed)?;
+                // Broadcast the bounds so the comparison path is taken.
+                let lower = lower.new_from_index(0, s.len());
+                let upper = upper.new_from_index(0, s.len());
+                let composed = is_between(&s, &lower, &upper, closed)?;
+                assert_eq!(Vec::from(&fused), Vec::from(&composed), "{closed:?}");
+            }
+        }
+        Ok(())
+    }
+}
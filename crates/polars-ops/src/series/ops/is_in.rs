@@ -450,9 +450,52 @@ where
         _ => polars_bail!(opq = is_in, ca_in.dtype(), other.dtype()),
     };
 
+    // Only use the lookup table if it is not larger than the input itself, as a shared mapping
+    // may contain many more categories than are used by this column.
+    let num_cats = ca_in.get_mapping().num_cats_upper_bound();
+    if other.len() == 1 && !other.has_nulls() && num_cats <= ca_in.len() {
+        let other = other.explode(true)?;
+        let other: &ChunkedArray<T::PolarsPhysical> = other.as_ref().as_ref();
+        return Ok(is_in_cat_lookup(ca_in, other, num_cats, nulls_equal));
+    }
+
     is_in_numeric(ca_in.physical(), &other, nulls_equal)
 }
 
+/// Check the membership of the categories of `ca_in` in a single set of categories `other`.
+///
+/// Instead of hashing every value, this builds a lookup table indexed by the category ids of the
+/// mapping, so that every value is checked with a single bit lookup.
+#[cfg(feature = "dtype-categorical")]
+fn is_in_cat_lookup<T: PolarsCategoricalType>(
+    ca_in: &CategoricalChunked<T>,
+    other: &ChunkedArray<T::PolarsPhysical>,
+    num_cats: usize,
+    nulls_equal: bool,
+) -> BooleanChunked {
+    let mut lookup = arrow::bitmap::MutableBitmap::from_len_zeroed(num_cats);
+    for cat in other.iter().flatten() {
+        let cat = cat.as_cat() as usize;
+        if cat < num_cats {
+            lookup.set(cat, true);
+        }
+    }
+    let lookup = lookup.freeze();
+    let contains = |cat: T::Native| {
+        let cat = cat.as_cat() as usize;
+        cat < num_cats && lookup.get_bit(cat)
+    };
+
+    let ca_in = ca_in.physical();
+    if nulls_equal {
+        // Nulls in the left evaluate to whether the right contains a null.
+        let other_has_nulls = other.has_nulls();
+        unary_elementwise(ca_in, |val| val.map_or(other_has_nulls, contains))
+    } else {
+        unary_elementwise_values(ca_in, contains).with_name(ca_in.name().clone())
+    }
+}
+
 fn is_in_null(s: &Series, other: &Series, nulls_equal: bool) -> PolarsResult<BooleanChunked> {
     if nulls_equal {
         let ca_in = s.null()?;
@@ -26,30 +26,75 @@ where
 }
 
 /// Returns a count of the unique values in the order of appearance.
+///
+/// The output has the name of `s`.
 pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
+    let name = s.name().clone();
     if s.dtype().to_physical().is_primitive_numeric() {
         let s_physical = s.to_physical_repr();
 
         with_match_physical_numeric_polars_type!(s_physical.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s_physical.as_ref().as_ref().as_ref();
-            Ok(unique_counts_helper(ca.iter()).into_series())
+            Ok(unique_counts_helper(ca.iter()).with_name(name).into_series())
         })
     } else {
         match s.dtype() {
-            DataType::String => {
-                Ok(unique_counts_helper(s.str().unwrap().into_iter()).into_series())
-            },
+            DataType::String => Ok(unique_counts_helper(s.str().unwrap().into_iter())
+                .with_name(name)
+                .into_series()),
             DataType::Null => {
                 let ca = if s.is_empty() {
-                    IdxCa::new(s.name().clone(), [] as [IdxSize; 0])
+                    IdxCa::new(name, [] as [IdxSize; 0])
                 } else {
-                    IdxCa::new(s.name().clone(), [s.len() as IdxSize])
+                    IdxCa::new(name, [s.len() as IdxSize])
                 };
                 Ok(ca.into_series())
             },
-            dt => {
+            #[cfg(feature = "object")]
+            dt @ DataType::Object(_) => {
                 polars_bail!(opq = unique_counts, dt)
             },
+            // Fall back to the group-by machinery, which supports all other dtypes. Sorted
+            // groups are ordered by their first occurrence.
+            _ => {
+                let groups = s.group_tuples(true, true)?;
+                Ok(groups.group_count().with_name(name).into_series())
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::unique_counts;
+
+    #[test]
+    fn test_unique_counts_order_of_appearance() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(3i32), None, Some(1), Some(3), None, Some(3)],
+        );
+        let out = unique_counts(&s)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[Some(3 as IdxSize), Some(2), Some(1)]
+        );
+
+        let s = Series::new("c".into(), &["x", "y", "x"]);
+        let out = unique_counts(&s)?;
+        assert_eq!(out.name().as_str(), "c");
+        assert_eq!(Vec::from(out.idx()?), &[Some(2 as IdxSize), Some(1)]);
+
+        let s = Series::new("b".into(), &[Some(false), Some(true), None, Some(true)]);
+        let out = unique_counts(&s)?;
+        assert_eq!(out.name().as_str(), "b");
+        assert_eq!(
+            Vec::from(out.idx()?),
+            &[Some(1 as IdxSize), Some(2), Some(1)]
+        );
+        Ok(())
+    }
+}
@@ -146,6 +146,37 @@ pub trait SeriesMethods: SeriesSealed {
         };
         Ok(cmp_op(&s1, &s2)?.all())
     }
+
+    /// Find the indices where `search_values` should be inserted to maintain the order of this
+    /// [`Series`].
+    ///
+    /// The sort order is taken from the sorted flag. If the flag is not set, the order is
+    /// determined by checking whether the [`Series`] is sorted ascending or descending.
+    #[cfg(feature = "search_sorted")]
+    fn search_sorted(
+        &self,
+        search_values: &Series,
+        side: super::SearchSortedSide,
+    ) -> PolarsResult<IdxCa> {
+        let s = self.as_series();
+        let descending = match s.is_sorted_flag() {
+            IsSorted::Ascending => false,
+            IsSorted::Descending => true,
+            IsSorted::Not => {
+                if s.is_sorted(SortOptions::default())? {
+                    false
+                } else if s.is_sorted(SortOptions::default().with_order_descending(true))? {
+                    true
+                } else {
+                    polars_bail!(
+                        InvalidOperation: "argument in operation 'search_sorted' is not sorted, \
+                        please sort the 'expr/series/column' first"
+                    )
+                }
+            },
+        };
+        super::search_sorted(s, search_values, side, descending)
+    }
 }
 
 fn check_cmp<T: NumericNative, Cmp: Fn(&T, &T) -> bool>(
@@ -1,7 +1,7 @@
 use std::io::Write;
 
-use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{RowGroup, SortingColumn};
 
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
@@ -152,6 +152,21 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_sorting_columns(row_group, None)
+    }
+
+    /// Writes a row group to the file and records the order in which its rows are sorted.
+    ///
+    /// This call is IO-bounded
+    pub fn write_with_sorting_columns<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -160,13 +175,14 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
         )?;
+        group.sorting_columns = sorting_columns;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
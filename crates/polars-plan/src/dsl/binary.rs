@@ -53,6 +53,34 @@ impl BinaryNameSpace {
             .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    /// Parse UUIDs in their hyphenated or 32 hex digit text form into 16-byte binary values.
+    #[cfg(feature = "binary_encoding")]
+    pub fn uuid_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::UuidDecode(strict)))
+    }
+
+    /// Format 16-byte binary values as lowercase hyphenated UUIDs.
+    #[cfg(feature = "binary_encoding")]
+    pub fn uuid_encode(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::UuidEncode))
+    }
+
+    /// Compute the MD5 digest of each value as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    pub fn md5(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Md5))
+    }
+
+    /// Compute the SHA-256 digest of each value as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    pub fn sha256(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Sha256))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn reinterpret(self, to_type: impl Into<DataTypeExpr>, is_little_endian: bool) -> Expr {
         self.0
@@ -18,6 +18,14 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encoding")]
+    UuidDecode(bool),
+    #[cfg(feature = "binary_encoding")]
+    UuidEncode,
+    #[cfg(feature = "binary_hash")]
+    Md5,
+    #[cfg(feature = "binary_hash")]
+    Sha256,
     Size,
     #[cfg(feature = "binary_encoding")]
     /// The parameters are destination type, and whether to use little endian
@@ -40,6 +48,14 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encoding")]
+            UuidDecode(_) => "uuid_decode",
+            #[cfg(feature = "binary_encoding")]
+            UuidEncode => "uuid_encode",
+            #[cfg(feature = "binary_hash")]
+            Md5 => "md5",
+            #[cfg(feature = "binary_hash")]
+            Sha256 => "sha256",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
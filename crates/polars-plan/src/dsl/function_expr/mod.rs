@@ -157,6 +157,8 @@ pub enum FunctionExpr {
     ArgUnique,
     ArgMin,
     ArgMax,
+    NanArgMin,
+    NanArgMax,
     ArgSort {
         descending: bool,
         nulls_last: bool,
@@ -468,7 +470,7 @@ impl Hash for FunctionExpr {
                 ignore_nulls.hash(state)
             },
             MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
-            | ArgMax | Product | Shift | ShiftAndFill => {},
+            | ArgMax | NanArgMin | NanArgMax | Product | Shift | ShiftAndFill => {},
             Append { upcast } => upcast.hash(state),
             ArgSort {
                 descending,
@@ -728,6 +730,8 @@ impl Display for FunctionExpr {
             ArgUnique => "arg_unique",
             ArgMin => "arg_min",
             ArgMax => "arg_max",
+            NanArgMin => "nan_arg_min",
+            NanArgMax => "nan_arg_max",
             ArgSort { .. } => "arg_sort",
             Product => "product",
             Repeat => "repeat",
@@ -64,6 +64,14 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_net")]
+    UrlExtract(UrlPart),
+    #[cfg(feature = "string_net")]
+    IpIsValid,
+    #[cfg(feature = "string_net")]
+    IpNormalize,
+    #[cfg(feature = "string_net")]
+    IpInCidr(PlSmallStr),
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -167,6 +175,14 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_net")]
+            UrlExtract(_) => "url_extract",
+            #[cfg(feature = "string_net")]
+            IpIsValid => "ip_is_valid",
+            #[cfg(feature = "string_net")]
+            IpNormalize => "ip_normalize",
+            #[cfg(feature = "string_net")]
+            IpInCidr(_) => "ip_in_cidr",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -19,3 +19,13 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
     condition.into().map_unary(FunctionExpr::ArgWhere)
 }
+
+/// Get a boolean mask of length `len` that is `true` at the given `indices`.
+///
+/// This is the inverse of [`arg_where`].
+#[cfg(all(feature = "range", feature = "is_in"))]
+pub fn is_in_index<E: Into<Expr>>(indices: E, len: Expr) -> Expr {
+    int_range(lit(0 as IdxSize), len.cast(IDX_DTYPE), 1, IDX_DTYPE)
+        .is_in(indices.into().cast(IDX_DTYPE).implode(), false)
+        .alias(PlSmallStr::from_static("is_in_index"))
+}
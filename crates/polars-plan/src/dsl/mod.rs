@@ -259,6 +259,18 @@ impl Expr {
         self.map_unary(FunctionExpr::ArgMax)
     }
 
+    /// Get the index value that has the minimum value. NaN values are propagated: if there are
+    /// any, the index of the first NaN is returned.
+    pub fn nan_arg_min(self) -> Self {
+        self.map_unary(FunctionExpr::NanArgMin)
+    }
+
+    /// Get the index value that has the maximum value. NaN values are propagated: if there are
+    /// any, the index of the first NaN is returned.
+    pub fn nan_arg_max(self) -> Self {
+        self.map_unary(FunctionExpr::NanArgMax)
+    }
+
     /// Get the index values that would sort this expression.
     pub fn arg_sort(self, descending: bool, nulls_last: bool) -> Self {
         self.map_unary(FunctionExpr::ArgSort {
@@ -950,6 +962,32 @@ impl Expr {
         binary_expr(self, Operator::LogicalAnd, expr.into())
     }
 
+    /// Logical "or" operation with the given null semantics.
+    ///
+    /// With `kleene`, nulls follow three-valued logic as in SQL: `null | true = true`. This is
+    /// what [`Expr::logical_or`] does. Without it, the output is null if either input is null.
+    pub fn logical_or_with_kleene<E: Into<Expr>>(self, expr: E, kleene: bool) -> Self {
+        let expr = expr.into();
+        if kleene {
+            self.logical_or(expr)
+        } else {
+            propagate_nulls(self.clone().logical_or(expr.clone()), self, expr)
+        }
+    }
+
+    /// Logical "and" operation with the given null semantics.
+    ///
+    /// With `kleene`, nulls follow three-valued logic as in SQL: `null & false = false`. This is
+    /// what [`Expr::logical_and`] does. Without it, the output is null if either input is null.
+    pub fn logical_and_with_kleene<E: Into<Expr>>(self, expr: E, kleene: bool) -> Self {
+        let expr = expr.into();
+        if kleene {
+            self.logical_and(expr)
+        } else {
+            propagate_nulls(self.clone().logical_and(expr.clone()), self, expr)
+        }
+    }
+
     /// Filter a single column.
     ///
     /// Should be used in aggregation context. If you want to filter on a
@@ -1267,6 +1305,20 @@ impl Expr {
         self.map_unary(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Assign ranks to data scaled to the interval `(0, 1]`, dealing with ties appropriately.
+    ///
+    /// The ranks are divided by the number of non-null values, or by the number of distinct
+    /// values for [`RankMethod::Dense`]. Use it with [`Expr::over`] to rank within groups.
+    pub fn percent_rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
+        let rank = self.clone().rank(options, seed).cast(DataType::Float64);
+        let n = match options.method {
+            RankMethod::Dense => rank.clone().max(),
+            _ => self.count(),
+        };
+        rank / n
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
@@ -1609,6 +1661,13 @@ impl Expr {
     }
 }
 
+/// Set `out` to null wherever `lhs` or `rhs` is null.
+fn propagate_nulls(out: Expr, lhs: Expr, rhs: Expr) -> Expr {
+    when(lhs.is_null().or(rhs.is_null()))
+        .then(lit(NULL).cast(DataType::Boolean))
+        .otherwise(out)
+}
+
 /// Apply a function/closure over multiple columns once the logical plan get executed.
 ///
 /// This function is very similar to [`apply_multiple`], but differs in how it handles aggregations.
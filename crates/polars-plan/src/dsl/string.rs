@@ -144,6 +144,24 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Base64Decode(strict))
     }
 
+    /// Parse UUIDs in their hyphenated or 32 hex digit text form into 16-byte binary values.
+    #[cfg(feature = "binary_encoding")]
+    pub fn uuid_decode(self, strict: bool) -> Expr {
+        self.0.cast(DataType::Binary).binary().uuid_decode(strict)
+    }
+
+    /// Compute the MD5 digest of the UTF-8 bytes of each string as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    pub fn md5(self) -> Expr {
+        self.0.cast(DataType::Binary).binary().md5()
+    }
+
+    /// Compute the SHA-256 digest of the UTF-8 bytes of each string as a lowercase hex string.
+    #[cfg(feature = "binary_hash")]
+    pub fn sha256(self) -> Expr {
+        self.0.cast(DataType::Binary).binary().sha256()
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_binary(StringFunction::Extract(group_index), pat)
@@ -389,6 +407,32 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_net")]
+    /// Extract a component of each URL. Values that are not valid absolute URLs, or lack the
+    /// requested component, become null.
+    pub fn url_extract(self, part: UrlPart) -> Expr {
+        self.0.map_unary(StringFunction::UrlExtract(part))
+    }
+
+    #[cfg(feature = "string_net")]
+    /// Check whether each string is a valid IPv4 or IPv6 address.
+    pub fn ip_is_valid(self) -> Expr {
+        self.0.map_unary(StringFunction::IpIsValid)
+    }
+
+    #[cfg(feature = "string_net")]
+    /// Format each IP address in its canonical form. Invalid addresses become null.
+    pub fn ip_normalize(self) -> Expr {
+        self.0.map_unary(StringFunction::IpNormalize)
+    }
+
+    #[cfg(feature = "string_net")]
+    /// Check whether each IP address lies within the network given in CIDR notation,
+    /// e.g. `"10.0.0.0/8"`. Invalid addresses become null.
+    pub fn ip_in_cidr(self, cidr: &str) -> Expr {
+        self.0.map_unary(StringFunction::IpInCidr(cidr.into()))
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -39,6 +39,14 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Reorder chains of inner joins so that the relations with the fewest estimated rows
+        /// are joined first. This is not enabled by default as it changes the row order of the
+        /// join output.
+        const REORDER_JOINS = 1 << 17;
+        /// Filter scans that are joined with an in-memory relation on the distinct join keys
+        /// of that relation, so that partitions and row groups without matching keys are
+        /// skipped.
+        const JOIN_PRUNING = 1 << 18;
     }
 }
 
@@ -59,6 +67,14 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn reorder_joins(&self) -> bool {
+        self.contains(OptFlags::REORDER_JOINS)
+    }
+
+    pub fn join_pruning(&self) -> bool {
+        self.contains(OptFlags::JOIN_PRUNING)
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -82,7 +98,11 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::REORDER_JOINS
+            & !Self::JOIN_PRUNING
     }
 }
 
@@ -15,6 +15,14 @@ pub enum IRBinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encoding")]
+    UuidDecode(bool),
+    #[cfg(feature = "binary_encoding")]
+    UuidEncode,
+    #[cfg(feature = "binary_hash")]
+    Md5,
+    #[cfg(feature = "binary_hash")]
+    Sha256,
     Size,
     #[cfg(feature = "binary_encoding")]
     Reinterpret(DataType, bool),
@@ -29,7 +37,11 @@ impl IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
-            HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            UuidDecode(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "binary_encoding")]
+            HexEncode | Base64Encode | UuidEncode => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "binary_hash")]
+            Md5 | Sha256 => mapper.with_dtype(DataType::String),
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
@@ -48,7 +60,11 @@ impl IRBinaryFunction {
             | B::HexEncode
             | B::Base64Decode(_)
             | B::Base64Encode
+            | B::UuidDecode(_)
+            | B::UuidEncode
             | B::Reinterpret(_, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "binary_hash")]
+            B::Md5 | B::Sha256 => FunctionOptions::elementwise(),
         }
     }
 }
@@ -68,6 +84,14 @@ impl Display for IRBinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encoding")]
+            UuidDecode(_) => "uuid_decode",
+            #[cfg(feature = "binary_encoding")]
+            UuidEncode => "uuid_encode",
+            #[cfg(feature = "binary_hash")]
+            Md5 => "md5",
+            #[cfg(feature = "binary_hash")]
+            Sha256 => "sha256",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
@@ -97,6 +121,14 @@ impl From<IRBinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encoding")]
+            UuidDecode(strict) => map!(uuid_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            UuidEncode => map!(uuid_encode),
+            #[cfg(feature = "binary_hash")]
+            Md5 => map!(md5),
+            #[cfg(feature = "binary_hash")]
+            Sha256 => map!(sha256),
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, is_little_endian) => map!(reinterpret, &dtype, is_little_endian),
@@ -162,6 +194,30 @@ pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
     Ok(ca.base64_encode().into())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn uuid_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.uuid_decode(strict).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn uuid_encode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.uuid_encode().map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_hash")]
+pub(super) fn md5(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.md5().into_column())
+}
+
+#[cfg(feature = "binary_hash")]
+pub(super) fn sha256(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.sha256().into_column())
+}
+
 #[cfg(feature = "binary_encoding")]
 pub(super) fn reinterpret(
     s: &Column,
@@ -1,7 +1,7 @@
 use std::ops::{BitAnd, BitOr};
 
 use polars_core::POOL;
-use polars_core::utils::SuperTypeFlags;
+use polars_core::utils::SuperTypeOptions;
 #[cfg(feature = "is_close")]
 use polars_utils::total_ord::TotalOrdWrap;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -86,9 +86,7 @@ impl IRBooleanFunction {
             B::IsDuplicated => FunctionOptions::length_preserving(),
             #[cfg(feature = "is_between")]
             B::IsBetween { .. } => FunctionOptions::elementwise()
-                .with_supertyping(
-                    (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
-                )
+                .with_supertyping(SuperTypeOptions::strict_strings())
                 .with_flags(|f| f | FunctionFlags::PRESERVES_NULL_ALL_INPUTS),
             #[cfg(feature = "is_in")]
             B::IsIn { nulls_equal } => FunctionOptions::elementwise()
@@ -102,9 +100,7 @@ impl IRBooleanFunction {
                 }),
             #[cfg(feature = "is_close")]
             B::IsClose { .. } => FunctionOptions::elementwise()
-                .with_supertyping(
-                    (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
-                )
+                .with_supertyping(SuperTypeOptions::strict_strings())
                 .with_flags(|f| f | FunctionFlags::PRESERVES_NULL_ALL_INPUTS),
             B::AllHorizontal | B::AnyHorizontal => FunctionOptions::elementwise().with_flags(|f| {
                 f | FunctionFlags::INPUT_WILDCARD_EXPANSION | FunctionFlags::ALLOW_EMPTY_INPUTS
@@ -197,6 +197,24 @@ pub(super) fn arg_max(s: &Column) -> PolarsResult<Column> {
         .into_column(s.name().clone()))
 }
 
+pub(super) fn nan_arg_min(s: &Column) -> PolarsResult<Column> {
+    Ok(s.as_materialized_series()
+        .nan_arg_min()
+        .map_or(Scalar::null(IDX_DTYPE), |v| {
+            Scalar::from(IdxSize::try_from(v).expect("idxsize"))
+        })
+        .into_column(s.name().clone()))
+}
+
+pub(super) fn nan_arg_max(s: &Column) -> PolarsResult<Column> {
+    Ok(s.as_materialized_series()
+        .nan_arg_max()
+        .map_or(Scalar::null(IDX_DTYPE), |v| {
+            Scalar::from(IdxSize::try_from(v).expect("idxsize"))
+        })
+        .into_column(s.name().clone()))
+}
+
 pub(super) fn arg_sort(s: &Column, descending: bool, nulls_last: bool) -> PolarsResult<Column> {
     // @scalar-opt
     Ok(s.as_materialized_series()
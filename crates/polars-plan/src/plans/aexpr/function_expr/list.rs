@@ -179,9 +179,7 @@ impl IRListFunction {
             L::GatherEvery => FunctionOptions::elementwise(),
             #[cfg(feature = "list_sets")]
             L::SetOperation(_) => FunctionOptions::elementwise()
-                .with_casting_rules(CastingRules::Supertype(SuperTypeOptions {
-                    flags: SuperTypeFlags::default() | SuperTypeFlags::ALLOW_IMPLODE_LIST,
-                }))
+                .with_casting_rules(CastingRules::Supertype(SuperTypeOptions::implode_list()))
                 .with_flags(|f| f & !FunctionFlags::RETURNS_SCALAR),
             #[cfg(feature = "diff")]
             L::Diff { .. } => FunctionOptions::elementwise(),
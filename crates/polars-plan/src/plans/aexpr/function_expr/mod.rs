@@ -91,7 +91,7 @@ pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::series::ops::NullBehavior;
-use polars_core::utils::SuperTypeFlags;
+use polars_core::utils::SuperTypeOptions;
 #[cfg(feature = "random")]
 pub use random::IRRandomMethod;
 use schema::FieldsMapper;
@@ -207,6 +207,8 @@ pub enum IRFunctionExpr {
     ArgUnique,
     ArgMin,
     ArgMax,
+    NanArgMin,
+    NanArgMax,
     ArgSort {
         descending: bool,
         nulls_last: bool,
@@ -523,7 +525,7 @@ impl Hash for IRFunctionExpr {
                 ignore_nulls.hash(state)
             },
             MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
-            | ArgMax | Product | Shift | ShiftAndFill => {},
+            | ArgMax | NanArgMin | NanArgMax | Product | Shift | ShiftAndFill => {},
             Append { upcast } => {
                 upcast.hash(state);
             },
@@ -785,6 +787,8 @@ impl Display for IRFunctionExpr {
             ArgUnique => "arg_unique",
             ArgMin => "arg_min",
             ArgMax => "arg_max",
+            NanArgMin => "nan_arg_min",
+            NanArgMax => "nan_arg_max",
             ArgSort { .. } => "arg_sort",
             Product => "product",
             Repeat => "repeat",
@@ -1167,6 +1171,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ArgUnique => map!(dispatch::arg_unique),
             ArgMin => map!(dispatch::arg_min),
             ArgMax => map!(dispatch::arg_max),
+            NanArgMin => map!(dispatch::nan_arg_min),
+            NanArgMax => map!(dispatch::nan_arg_max),
             ArgSort {
                 descending,
                 nulls_last,
@@ -1447,9 +1453,9 @@ impl IRFunctionExpr {
                 FunctionOptions::aggregation().with_casting_rules(CastingRules::FirstArgLossless)
             },
             #[cfg(feature = "search_sorted")]
-            F::SearchSorted { .. } => FunctionOptions::groupwise().with_supertyping(
-                (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
-            ),
+            F::SearchSorted { .. } => {
+                FunctionOptions::groupwise().with_supertyping(SuperTypeOptions::strict_strings())
+            },
             #[cfg(feature = "trigonometry")]
             F::Trigonometry(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "trigonometry")]
@@ -1482,7 +1488,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "repeat_by")]
             F::RepeatBy => FunctionOptions::elementwise(),
             F::ArgUnique => FunctionOptions::groupwise(),
-            F::ArgMin | F::ArgMax => FunctionOptions::aggregation(),
+            F::ArgMin | F::ArgMax | F::NanArgMin | F::NanArgMax => FunctionOptions::aggregation(),
             F::ArgSort { .. } => FunctionOptions::length_preserving(),
             F::Product => FunctionOptions::aggregation(),
             #[cfg(feature = "rank")]
@@ -109,7 +109,9 @@ impl IRFunctionExpr {
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
-            ArgUnique | ArgMin | ArgMax | ArgSort { .. } => mapper.with_dtype(IDX_DTYPE),
+            ArgUnique | ArgMin | ArgMax | NanArgMin | NanArgMax | ArgSort { .. } => {
+                mapper.with_dtype(IDX_DTYPE)
+            },
             Product => mapper.map_dtype(|dtype| {
                 use DataType as T;
                 match dtype {
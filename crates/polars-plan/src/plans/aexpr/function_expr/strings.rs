@@ -75,6 +75,14 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_net")]
+    UrlExtract(UrlPart),
+    #[cfg(feature = "string_net")]
+    IpIsValid,
+    #[cfg(feature = "string_net")]
+    IpNormalize,
+    #[cfg(feature = "string_net")]
+    IpInCidr(PlSmallStr),
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -170,6 +178,10 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_net")]
+            UrlExtract(_) | IpNormalize => mapper.with_same_dtype(),
+            #[cfg(feature = "string_net")]
+            IpIsValid | IpInCidr(_) => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -268,6 +280,10 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_net")]
+            S::UrlExtract(_) | S::IpIsValid | S::IpNormalize | S::IpInCidr(_) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
@@ -349,6 +365,14 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_net")]
+            UrlExtract(_) => "url_extract",
+            #[cfg(feature = "string_net")]
+            IpIsValid => "ip_is_valid",
+            #[cfg(feature = "string_net")]
+            IpNormalize => "ip_normalize",
+            #[cfg(feature = "string_net")]
+            IpInCidr(_) => "ip_in_cidr",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -467,6 +491,14 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Normalize { form } => map!(strings::normalize, form.clone()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_net")]
+            UrlExtract(part) => map!(strings::url_extract, part),
+            #[cfg(feature = "string_net")]
+            IpIsValid => map!(strings::ip_is_valid),
+            #[cfg(feature = "string_net")]
+            IpNormalize => map!(strings::ip_normalize),
+            #[cfg(feature = "string_net")]
+            IpInCidr(cidr) => map!(strings::ip_in_cidr, &cidr),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -1128,6 +1160,30 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_net")]
+pub(super) fn url_extract(s: &Column, part: UrlPart) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.url_extract(part).into_column())
+}
+
+#[cfg(feature = "string_net")]
+pub(super) fn ip_is_valid(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.ip_is_valid().into_column())
+}
+
+#[cfg(feature = "string_net")]
+pub(super) fn ip_normalize(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.ip_normalize().into_column())
+}
+
+#[cfg(feature = "string_net")]
+pub(super) fn ip_in_cidr(s: &Column, cidr: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.ip_in_cidr(cidr).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
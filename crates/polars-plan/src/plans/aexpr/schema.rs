@@ -176,6 +176,7 @@ impl AExpr {
                     Sum(expr) => {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
                         let dt = match field.dtype() {
+                            // Summing booleans counts the `true` values.
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
                             _ => None,
@@ -201,6 +202,7 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            // Booleans resolve to Float64: the ratio of `true` values.
                             _ => {
                                 let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
                                 let mapper = FieldsMapper::new(&field);
@@ -240,9 +242,16 @@ impl AExpr {
                         Ok(field)
                     },
                     Quantile { expr, .. } => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
-                        let mapper = FieldsMapper::new(&field);
-                        mapper.map_numeric_to_float_dtype(true)
+                        let mut field = ctx.arena.get(*expr).to_field_impl(ctx)?;
+                        match field.dtype {
+                            Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            _ => {
+                                let field = [field];
+                                let mapper = FieldsMapper::new(&field);
+                                return mapper.map_numeric_to_float_dtype(true);
+                            },
+                        }
+                        Ok(field)
                     },
                 }
             },
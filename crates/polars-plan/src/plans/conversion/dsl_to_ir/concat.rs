@@ -11,6 +11,7 @@ pub(super) fn convert_diagonal_concat(
     mut inputs: Vec<Node>,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
+    to_supertypes: bool,
 ) -> PolarsResult<Vec<Node>> {
     let schemas = nodes_to_schemas(&inputs, lp_arena);
 
@@ -19,11 +20,17 @@ pub(super) fn convert_diagonal_concat(
     let mut total_schema = Schema::with_capacity(upper_bound_width);
 
     for sch in schemas.iter() {
-        sch.iter().for_each(|(name, dtype)| {
-            if !total_schema.contains(name) {
-                total_schema.with_column(name.as_str().into(), dtype.clone());
-            }
-        });
+        if to_supertypes {
+            // The missing columns are filled with nulls of the supertype, so that the union
+            // casts every input to the same type.
+            total_schema.try_merge(sch)?;
+        } else {
+            sch.iter().for_each(|(name, dtype)| {
+                if !total_schema.contains(name) {
+                    total_schema.with_column(name.as_str().into(), dtype.clone());
+                }
+            });
+        }
     }
     if total_schema.is_empty() {
         return Ok(inputs);
@@ -122,3 +129,49 @@ pub(super) fn h_concat_schema(
     let combined_schema = merge_schemas(&schemas)?;
     Ok(Arc::new(combined_schema))
 }
+
+/// Cast the (nested) Categoricals of every input to the categories of the first input, so that
+/// inputs with different categories can be concatenated.
+pub(super) fn remap_categories(
+    inputs: &mut [Node],
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    opt_flags: &OptFlags,
+) -> PolarsResult<()> {
+    let Some((first, others)) = inputs.split_first_mut() else {
+        return Ok(());
+    };
+    let schema = lp_arena.get(*first).schema(lp_arena).into_owned();
+
+    for input in others {
+        let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+        if input_schema.len() != schema.len() {
+            continue;
+        }
+
+        let exprs = input_schema
+            .iter()
+            .zip(schema.iter())
+            .filter(|((name, dtype), (schema_name, schema_dtype))| {
+                name == schema_name
+                    && dtype.matches_schema_type(schema_dtype).is_err()
+                    && dtype
+                        .matches_schema_type_remap_categories(schema_dtype)
+                        .is_ok()
+            })
+            .map(|((name, _), (_, schema_dtype))| col(name.clone()).cast(schema_dtype.clone()))
+            .collect::<Vec<_>>();
+
+        if !exprs.is_empty() {
+            let expr = to_expr_irs(
+                exprs,
+                &mut ExprToIRContext::new_with_opt_eager(expr_arena, &input_schema, opt_flags),
+            )?;
+            let lp = IRBuilder::new(*input, expr_arena, lp_arena)
+                .with_columns(expr, Default::default())
+                .build();
+            *input = lp_arena.add(lp);
+        }
+    }
+    Ok(())
+}
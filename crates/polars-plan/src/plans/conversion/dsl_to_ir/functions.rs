@@ -109,6 +109,14 @@ pub(super) fn convert_functions(
                 B::Base64Decode(v) => IB::Base64Decode(v),
                 #[cfg(feature = "binary_encoding")]
                 B::Base64Encode => IB::Base64Encode,
+                #[cfg(feature = "binary_encoding")]
+                B::UuidDecode(v) => IB::UuidDecode(v),
+                #[cfg(feature = "binary_encoding")]
+                B::UuidEncode => IB::UuidEncode,
+                #[cfg(feature = "binary_hash")]
+                B::Md5 => IB::Md5,
+                #[cfg(feature = "binary_hash")]
+                B::Sha256 => IB::Sha256,
                 B::Size => IB::Size,
                 #[cfg(feature = "binary_encoding")]
                 B::Reinterpret(dtype_expr, v) => {
@@ -256,6 +264,14 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_net")]
+                S::UrlExtract(part) => IS::UrlExtract(part),
+                #[cfg(feature = "string_net")]
+                S::IpIsValid => IS::IpIsValid,
+                #[cfg(feature = "string_net")]
+                S::IpNormalize => IS::IpNormalize,
+                #[cfg(feature = "string_net")]
+                S::IpInCidr(cidr) => IS::IpInCidr(cidr),
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -763,6 +779,8 @@ pub(super) fn convert_functions(
         F::ArgUnique => I::ArgUnique,
         F::ArgMin => I::ArgMin,
         F::ArgMax => I::ArgMax,
+        F::NanArgMin => I::NanArgMin,
+        F::NanArgMax => I::NanArgMax,
         F::ArgSort {
             descending,
             nulls_last,
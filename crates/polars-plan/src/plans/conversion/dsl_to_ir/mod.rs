@@ -135,7 +135,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 .map_err(|e| e.context(failed_here!(vertical concat)))?;
 
             if args.diagonal {
-                inputs = concat::convert_diagonal_concat(inputs, ctxt.lp_arena, ctxt.expr_arena)?;
+                inputs = concat::convert_diagonal_concat(
+                    inputs,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                    args.to_supertypes,
+                )?;
             }
 
             if args.to_supertypes {
@@ -148,6 +153,9 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 .map_err(|e| e.context(failed_here!(vertical concat)))?;
             }
 
+            concat::remap_categories(&mut inputs, ctxt.lp_arena, ctxt.expr_arena, ctxt.opt_flags)
+                .map_err(|e| e.context(failed_here!(vertical concat)))?;
+
             let first = *inputs.first().ok_or_else(
                 || polars_err!(InvalidOperation: "expected at least one input in 'union'/'concat'"),
             )?;
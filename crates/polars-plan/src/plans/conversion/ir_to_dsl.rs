@@ -292,6 +292,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Base64Decode(v) => B::Base64Decode(v),
                 #[cfg(feature = "binary_encoding")]
                 IB::Base64Encode => B::Base64Encode,
+                #[cfg(feature = "binary_encoding")]
+                IB::UuidDecode(v) => B::UuidDecode(v),
+                #[cfg(feature = "binary_encoding")]
+                IB::UuidEncode => B::UuidEncode,
+                #[cfg(feature = "binary_hash")]
+                IB::Md5 => B::Md5,
+                #[cfg(feature = "binary_hash")]
+                IB::Sha256 => B::Sha256,
                 IB::Size => B::Size,
                 #[cfg(feature = "binary_encoding")]
                 IB::Reinterpret(data_type, v) => B::Reinterpret(data_type.into(), v),
@@ -423,6 +431,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_net")]
+                IB::UrlExtract(part) => B::UrlExtract(part),
+                #[cfg(feature = "string_net")]
+                IB::IpIsValid => B::IpIsValid,
+                #[cfg(feature = "string_net")]
+                IB::IpNormalize => B::IpNormalize,
+                #[cfg(feature = "string_net")]
+                IB::IpInCidr(cidr) => B::IpInCidr(cidr),
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -839,6 +855,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::ArgUnique => F::ArgUnique,
         IF::ArgMin => F::ArgMin,
         IF::ArgMax => F::ArgMax,
+        IF::NanArgMin => F::NanArgMin,
+        IF::NanArgMax => F::NanArgMax,
         IF::ArgSort {
             descending,
             nulls_last,
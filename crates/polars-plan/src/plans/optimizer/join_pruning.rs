@@ -0,0 +1,229 @@
+//! Optimization that prunes a scanned relation with the key domain of an in-memory relation it is
+//! joined with.
+//!
+//! For example, in `scan_parquet('data/**/*.parquet').join(df, on='part')` the distinct values of
+//! `df['part']` are known at planning time. The scan can only contribute rows whose `part` is one
+//! of those values, so the join input is rewritten to
+//! `scan_parquet(..).filter(pl.col.part.is_in(df['part'].unique()))`. Predicate pushdown then
+//! moves the filter into the scan, where it prunes hive partitions and row groups based on their
+//! statistics.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+use polars_ops::frame::JoinType;
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptionsIR};
+use crate::plans::{AExprBuilder, ExprIR};
+
+/// The maximum number of distinct keys that are converted into a scan predicate. Larger key
+/// domains rarely prune anything and make the predicate expensive to evaluate.
+const MAX_PRUNING_KEYS: usize = 1024;
+
+fn column_name(e: &ExprIR, expr_arena: &Arena<AExpr>) -> Option<PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// Floats are excluded as the join and `is_in` may disagree on the equality of `NaN` and `-0.0`.
+fn is_supported_key_dtype(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(
+            dtype,
+            DataType::Boolean | DataType::String | DataType::Binary
+        )
+}
+
+/// Get the materialized [`DataFrame`] that is the source of `node`.
+fn materialized_df(mut node: Node, lp_arena: &Arena<IR>) -> Option<&Arc<DataFrame>> {
+    loop {
+        match lp_arena.get(node) {
+            IR::DataFrameScan { df, .. } => return Some(df),
+            IR::SimpleProjection { input, .. } => node = *input,
+            _ => return None,
+        }
+    }
+}
+
+/// Check whether `node` only filters and projects a file scan that produces column `name`.
+fn is_scan_of(mut node: Node, name: &str, lp_arena: &Arena<IR>) -> bool {
+    loop {
+        match lp_arena.get(node) {
+            IR::Scan { file_info, .. } => return file_info.schema.contains(name),
+            IR::Filter { input, .. } | IR::SimpleProjection { input, .. } => node = *input,
+            _ => return false,
+        }
+    }
+}
+
+/// Build the `is_in` predicates for `scan_keys` with the key domain of `df_keys`.
+fn key_domain_predicates(
+    scan: Node,
+    scan_keys: &[ExprIR],
+    df: &DataFrame,
+    df_keys: &[ExprIR],
+    nulls_equal: bool,
+    lp_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Vec<Node>> {
+    let scan_schema = lp_arena.get(scan).schema(lp_arena).into_owned();
+    let mut predicates = vec![];
+    for (scan_key, df_key) in scan_keys.iter().zip(df_keys) {
+        let (Some(scan_name), Some(df_name)) = (
+            column_name(scan_key, expr_arena),
+            column_name(df_key, expr_arena),
+        ) else {
+            continue;
+        };
+        let Ok(column) = df.column(&df_name) else {
+            continue;
+        };
+        let dtype = column.dtype();
+        if scan_schema.get(&scan_name) != Some(dtype)
+            || !is_supported_key_dtype(dtype)
+            || !is_scan_of(scan, &scan_name, lp_arena)
+        {
+            continue;
+        }
+
+        let mut values = column.as_materialized_series().unique()?;
+        if !nulls_equal {
+            values = values.drop_nulls();
+        }
+        if values.len() > MAX_PRUNING_KEYS {
+            continue;
+        }
+
+        let values = AExprBuilder::lit_scalar(Scalar::new_list(values), expr_arena);
+        let predicate =
+            AExprBuilder::col(scan_name, expr_arena).is_in(values, nulls_equal, expr_arena);
+        predicates.push(predicate.node());
+    }
+    Ok(predicates)
+}
+
+/// Returns the new input if `scan` can be pruned with the key domain of `df`.
+#[allow(clippy::too_many_arguments)]
+fn prune_input(
+    scan: Node,
+    scan_keys: &[ExprIR],
+    df: Node,
+    df_keys: &[ExprIR],
+    options: &JoinOptionsIR,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<Node>> {
+    let Some(df) = materialized_df(df, lp_arena).cloned() else {
+        return Ok(None);
+    };
+    let predicates = key_domain_predicates(
+        scan,
+        scan_keys,
+        &df,
+        df_keys,
+        options.args.nulls_equal,
+        lp_arena,
+        expr_arena,
+    )?;
+    let Some(predicate) = predicates.into_iter().reduce(|left, right| {
+        AExprBuilder::new_from_node(left)
+            .logical_and(right, expr_arena)
+            .node()
+    }) else {
+        return Ok(None);
+    };
+
+    Ok(Some(lp_arena.add(IR::Filter {
+        input: scan,
+        predicate: ExprIR::from_node(predicate, expr_arena),
+    })))
+}
+
+fn prune_join(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return Ok(());
+    };
+    if options.options.is_some() {
+        return Ok(());
+    }
+    let (input_left, input_right) = (*input_left, *input_right);
+    let (left_on, right_on, options) = (left_on.clone(), right_on.clone(), options.clone());
+
+    // Only prune the sides of which the rows without a matching key are dropped by the join.
+    let (prune_left, prune_right) = match options.args.how {
+        JoinType::Inner => (true, true),
+        JoinType::Left => (false, true),
+        JoinType::Right => (true, false),
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi => (true, false),
+        _ => (false, false),
+    };
+
+    let mut new_left = None;
+    let mut new_right = None;
+    if prune_left {
+        new_left = prune_input(
+            input_left,
+            &left_on,
+            input_right,
+            &right_on,
+            &options,
+            lp_arena,
+            expr_arena,
+        )?;
+    }
+    if prune_right {
+        new_right = prune_input(
+            input_right,
+            &right_on,
+            input_left,
+            &left_on,
+            &options,
+            lp_arena,
+            expr_arena,
+        )?;
+    }
+
+    if let IR::Join {
+        input_left,
+        input_right,
+        ..
+    } = lp_arena.get_mut(node)
+    {
+        *input_left = new_left.unwrap_or(*input_left);
+        *input_right = new_right.unwrap_or(*input_right);
+    }
+    Ok(())
+}
+
+pub fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        prune_join(current, lp_arena, expr_arena)?;
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+    Ok(())
+}
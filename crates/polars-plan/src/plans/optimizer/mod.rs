@@ -15,6 +15,8 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "is_in")]
+mod join_pruning;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
@@ -22,11 +24,13 @@ mod expand_datasets;
 pub use expand_datasets::ExpandedPythonScan;
 mod predicate_pushdown;
 mod projection_pushdown;
+mod reorder_joins;
 mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+mod trace;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 #[cfg(feature = "cse")]
@@ -37,9 +41,11 @@ use polars_core::config::verbose;
 use polars_io::predicates::PhysicalIoExpr;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
+pub use reorder_joins::estimate_rows;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
+pub use trace::{OptimizerTraceEvent, record_optimizer_trace};
 
 use self::flatten_union::FlattenUnionRule;
 use self::set_order::set_order_flags;
@@ -178,6 +184,12 @@ pub fn optimize(
         }
     }
 
+    // Should be run before predicate pushdown, which moves the key predicates into the scans.
+    #[cfg(feature = "is_in")]
+    if opt_flags.join_pruning() && get_or_init_members!().has_joins_or_unions {
+        join_pruning::optimize(lp_top, lp_arena, expr_arena)?;
+    }
+
     if opt_flags.predicate_pushdown() {
         let mut predicate_pushdown_opt = PredicatePushDown::new(
             expr_eval,
@@ -194,6 +206,10 @@ pub fn optimize(
         collapse_joins::optimize(lp_top, lp_arena, expr_arena, opt_flags.new_streaming());
     }
 
+    if opt_flags.reorder_joins() && get_or_init_members!().has_joins_or_unions {
+        reorder_joins::optimize(lp_top, lp_arena, expr_arena)?;
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -33,7 +33,13 @@ pub(super) fn process_group_by(
             maintain_order,
             options,
         };
-        return opt.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
+        return opt.no_pushdown_restart_opt(
+            lp,
+            acc_predicates,
+            lp_arena,
+            expr_arena,
+            "group-by with a UDF, a dynamic/rolling window or a slice",
+        );
     }
 
     // If the predicate only resolves to the keys we can push it down.
@@ -53,7 +53,13 @@ pub(super) fn process_join(
             options,
         };
 
-        return opt.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
+        return opt.no_pushdown_restart_opt(
+            lp,
+            acc_predicates,
+            lp_arena,
+            expr_arena,
+            "predicates can't be pushed past this join type",
+        );
     }
 
     let should_coalesce = options.args.should_coalesce();
@@ -9,11 +9,16 @@ use polars_utils::idx_vec::UnitVec;
 use recursive::recursive;
 use utils::*;
 
+use super::trace::{node_name, trace_event};
 use super::*;
 use crate::prelude::optimizer::predicate_pushdown::group_by::process_group_by;
 use crate::prelude::optimizer::predicate_pushdown::join::process_join;
 use crate::utils::{check_input_node, has_aexpr};
 
+const PREDICATE_PUSHDOWN: &str = "predicate_pushdown";
+const BLOCKING_EXPR: &str =
+    "an expression blocks pushdown, e.g. a UDF, a fallible or a non-elementwise expression";
+
 pub type ExprEval<'a> =
     Option<&'a dyn Fn(&ExprIR, &Arena<AExpr>, &SchemaRef) -> Option<Arc<dyn PhysicalIoExpr>>>;
 
@@ -140,7 +145,13 @@ impl PredicatePushDown<'_> {
                     out
                 },
                 PushdownEligibility::NoPushdown => {
-                    return self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
+                    return self.no_pushdown_restart_opt(
+                        lp,
+                        acc_predicates,
+                        lp_arena,
+                        expr_arena,
+                        BLOCKING_EXPR,
+                    );
                 },
             };
 
@@ -203,7 +214,15 @@ impl PredicatePushDown<'_> {
         acc_predicates: PlHashMap<PlSmallStr, ExprIR>,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
+        reason: &'static str,
     ) -> PolarsResult<IR> {
+        trace_event(
+            PREDICATE_PUSHDOWN,
+            || node_name(&lp),
+            reason,
+            acc_predicates.values(),
+            expr_arena,
+        );
         let inputs = lp.inputs();
 
         let new_inputs = inputs
@@ -232,7 +251,15 @@ impl PredicatePushDown<'_> {
         acc_predicates: PlHashMap<PlSmallStr, ExprIR>,
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
+        reason: &'static str,
     ) -> PolarsResult<IR> {
+        trace_event(
+            PREDICATE_PUSHDOWN,
+            || node_name(&lp),
+            reason,
+            acc_predicates.values(),
+            expr_arena,
+        );
         // all predicates are done locally
         let local_predicates = acc_predicates.into_values().collect::<Vec<_>>();
         Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
@@ -303,8 +330,14 @@ impl PredicatePushDown<'_> {
                         out
                     },
                     PushdownEligibility::NoPushdown => {
-                        let out = acc_predicates.drain().map(|t| t.1).collect();
-                        acc_predicates.clear();
+                        let out: Vec<_> = acc_predicates.drain().map(|t| t.1).collect();
+                        trace_event(
+                            PREDICATE_PUSHDOWN,
+                            || "filter".to_string(),
+                            BLOCKING_EXPR,
+                            &out,
+                            expr_arena,
+                        );
                         out
                     },
                 };
@@ -388,6 +421,13 @@ impl PredicatePushDown<'_> {
                         blocked_names.contains(&name.as_ref())
                     })
                 };
+                trace_event(
+                    PREDICATE_PUSHDOWN,
+                    || <&str>::from(&*scan_type).to_string(),
+                    "predicates on the row index or file path column are applied after the scan",
+                    &local_predicates,
+                    expr_arena,
+                );
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
                 let mut do_optimization = match &*scan_type {
@@ -414,6 +454,13 @@ impl PredicatePushDown<'_> {
                         scan_type,
                     }
                 } else {
+                    trace_event(
+                        PREDICATE_PUSHDOWN,
+                        || <&str>::from(&*scan_type).to_string(),
+                        "the scan does not support predicates with its current options",
+                        &predicate,
+                        expr_arena,
+                    );
                     let lp = Scan {
                         sources,
                         file_info,
@@ -580,7 +627,13 @@ impl PredicatePushDown<'_> {
                         ),
                     }
                 } else {
-                    self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+                    self.no_pushdown_restart_opt(
+                        lp,
+                        acc_predicates,
+                        lp_arena,
+                        expr_arena,
+                        "the function does not allow predicate pushdown",
+                    )
                 }
             },
             GroupBy {
@@ -622,16 +675,30 @@ impl PredicatePushDown<'_> {
             },
             // NOT Pushed down passed these nodes
             // predicates influence slice sizes
-            lp @ Slice { .. } => {
-                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
-            },
-            lp @ HConcat { .. } => {
-                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
-            },
+            lp @ Slice { .. } => self.no_pushdown_restart_opt(
+                lp,
+                acc_predicates,
+                lp_arena,
+                expr_arena,
+                "predicates would change the rows selected by the slice",
+            ),
+            lp @ HConcat { .. } => self.no_pushdown_restart_opt(
+                lp,
+                acc_predicates,
+                lp_arena,
+                expr_arena,
+                "horizontal concatenation aligns rows by position",
+            ),
             // Caches will run predicate push-down in the `cache_states` run.
             Cache { .. } => {
                 if self.block_at_cache {
-                    self.no_pushdown(lp, acc_predicates, lp_arena, expr_arena)
+                    self.no_pushdown(
+                        lp,
+                        acc_predicates,
+                        lp_arena,
+                        expr_arena,
+                        "predicates are pushed into caches in a separate pass",
+                    )
                 } else {
                     self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                 }
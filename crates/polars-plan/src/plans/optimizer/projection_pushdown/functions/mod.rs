@@ -5,6 +5,7 @@ mod unpivot;
 use unpivot::process_unpivot;
 
 use super::*;
+use crate::plans::optimizer::trace::{node_name, trace_event_with};
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn process_functions(
@@ -87,6 +88,17 @@ pub(super) fn process_functions(
                     input,
                     function: function.clone(),
                 };
+                trace_event_with(
+                    "projection_pushdown",
+                    || node_name(&lp),
+                    "the function does not allow projection pushdown",
+                    || {
+                        ctx.acc_projections
+                            .iter()
+                            .map(|c| column_node_to_name(*c, expr_arena).to_string())
+                            .collect()
+                    },
+                );
                 // restart projection pushdown
                 proj_pd.no_pushdown_restart_opt(lp, ctx, lp_arena, expr_arena)
             }
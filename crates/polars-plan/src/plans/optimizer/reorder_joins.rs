@@ -0,0 +1,198 @@
+//! Optimization that reorders chains of inner joins so that the smallest relations are joined
+//! first.
+//!
+//! For example, `fact.join(big, on='a').join(small, on='b')` is rewritten to
+//! `fact.join(small, on='b').join(big, on='a')` followed by a projection that restores the
+//! original column order. Only left-deep chains in which every join is keyed on columns of the
+//! base relation are reordered, so that every join can be moved independently of the others.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, JoinValidation, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptionsIR};
+use crate::plans::ExprIR;
+use crate::plans::schema::det_join_schema;
+
+struct JoinLink {
+    input_right: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptionsIR>,
+    rows_right: usize,
+}
+
+/// Estimate the number of rows produced by `node` from the row counts of its scan.
+pub fn estimate_rows(mut node: Node, lp_arena: &Arena<IR>) -> Option<usize> {
+    let mut limit = usize::MAX;
+    loop {
+        match lp_arena.get(node) {
+            IR::DataFrameScan { df, .. } => return Some(df.height().min(limit)),
+            IR::Scan { file_info, .. } => {
+                let (known, estimated) = file_info.row_estimation;
+                let rows = known.unwrap_or(estimated);
+                return (rows != usize::MAX).then_some(rows.min(limit));
+            },
+            IR::Slice { input, len, .. } => {
+                limit = limit.min(*len as usize);
+                node = *input;
+            },
+            // These don't add rows, so the estimate of the input is an upper bound.
+            IR::Filter { input, .. }
+            | IR::Select { input, .. }
+            | IR::HStack { input, .. }
+            | IR::SimpleProjection { input, .. }
+            | IR::Sort { input, .. }
+            | IR::Cache { input, .. } => node = *input,
+            _ => return None,
+        }
+    }
+}
+
+fn is_reorderable(options: &JoinOptionsIR) -> bool {
+    let args = &options.args;
+    matches!(args.how, JoinType::Inner)
+        && matches!(args.validation, JoinValidation::ManyToMany)
+        && matches!(args.maintain_order, MaintainOrderJoin::None)
+        && args.slice.is_none()
+        && options.options.is_none()
+}
+
+fn column_name<'a>(e: &ExprIR, expr_arena: &'a Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Collect the chain of inner joins starting at `top`. Returns the base relation and the joins in
+/// the order they are applied.
+fn collect_chain(
+    top: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Node, Vec<JoinLink>)> {
+    let mut links = vec![];
+    let mut current = top;
+    while let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(current)
+    {
+        if !is_reorderable(options) {
+            break;
+        }
+        links.push(JoinLink {
+            input_right: *input_right,
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+            rows_right: estimate_rows(*input_right, lp_arena)?,
+        });
+        current = *input_left;
+    }
+    if links.len() < 2 {
+        return None;
+    }
+    links.reverse();
+    let base = current;
+
+    // Every join must be keyed on columns of the base relation and no join may produce a
+    // suffixed column. Otherwise the output depends on the join order.
+    let base_schema = lp_arena.get(base).schema(lp_arena);
+    let mut names: PlHashSet<PlSmallStr> = base_schema.iter_names().cloned().collect();
+    for link in &links {
+        for e in &link.left_on {
+            if !base_schema.contains(column_name(e, expr_arena)?) {
+                return None;
+            }
+        }
+        let right_keys = link
+            .right_on
+            .iter()
+            .map(|e| column_name(e, expr_arena))
+            .collect::<Option<Vec<_>>>()?;
+        let coalesce = link.options.args.should_coalesce();
+        let right_schema = lp_arena.get(link.input_right).schema(lp_arena);
+        for name in right_schema.iter_names() {
+            if coalesce && right_keys.contains(&name) {
+                continue;
+            }
+            if !names.insert(name.clone()) {
+                return None;
+            }
+        }
+    }
+    Some((base, links))
+}
+
+fn reorder_chain(
+    top: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let Some((base, mut links)) = collect_chain(top, lp_arena, expr_arena) else {
+        return Ok(());
+    };
+    if links.is_sorted_by_key(|link| link.rows_right) {
+        return Ok(());
+    }
+    links.sort_by_key(|link| link.rows_right);
+
+    let columns = lp_arena.get(top).schema(lp_arena).into_owned();
+    let mut input_left = base;
+    for link in links {
+        let schema_left = lp_arena.get(input_left).schema(lp_arena).into_owned();
+        let schema_right = lp_arena.get(link.input_right).schema(lp_arena).into_owned();
+        let schema = det_join_schema(
+            &schema_left,
+            &schema_right,
+            &link.left_on,
+            &link.right_on,
+            &link.options,
+            expr_arena,
+        )?;
+        input_left = lp_arena.add(IR::Join {
+            input_left,
+            input_right: link.input_right,
+            schema,
+            left_on: link.left_on,
+            right_on: link.right_on,
+            options: link.options,
+        });
+    }
+    debug_assert_eq!(
+        lp_arena.get(input_left).schema(lp_arena).len(),
+        columns.len()
+    );
+
+    lp_arena.replace(
+        top,
+        IR::SimpleProjection {
+            input: input_left,
+            columns,
+        },
+    );
+    Ok(())
+}
+
+pub fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        reorder_chain(current, lp_arena, expr_arena)?;
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+    Ok(())
+}
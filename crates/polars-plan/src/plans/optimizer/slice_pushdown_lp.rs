@@ -283,9 +283,28 @@ impl SlicePushDown {
                 right_on,
                 mut options
             }, Some(state)) if !matches!(options.options, Some(JoinTypeOptionsIR::Cross { .. })) => {
+                // A left join that keeps the left order emits at least one row per left row, in
+                // order. So the first `offset + len` rows of the output only depend on as many
+                // rows of the left input.
+                let left_state = (options.options.is_none()
+                    && options.args.how == JoinType::Left
+                    && matches!(
+                        options.args.maintain_order,
+                        MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+                    )
+                    && matches!(
+                        options.args.validation,
+                        JoinValidation::ManyToMany | JoinValidation::ManyToOne
+                    )
+                    && state.offset >= 0)
+                    .then(|| State {
+                        offset: 0,
+                        len: (state.offset as IdxSize).saturating_add(state.len),
+                    });
+
                 // first restart optimization in both inputs and get the updated LP
                 let lp_left = lp_arena.take(input_left);
-                let lp_left = self.pushdown(lp_left, None, lp_arena, expr_arena)?;
+                let lp_left = self.pushdown(lp_left, left_state, lp_arena, expr_arena)?;
                 let input_left = lp_arena.add(lp_left);
 
                 let lp_right = lp_arena.take(input_right);
@@ -0,0 +1,96 @@
+//! Recording of the decisions made by the optimizer.
+//!
+//! When recording is enabled with [`record_optimizer_trace`], optimizations register an
+//! [`OptimizerTraceEvent`] every time they have to stop at a node, together with the reason why.
+
+use std::cell::RefCell;
+use std::fmt;
+
+use polars_utils::arena::Arena;
+
+use crate::plans::{AExpr, ExprIR, IR};
+
+thread_local! {
+    static TRACE: RefCell<Option<Vec<OptimizerTraceEvent>>> = const { RefCell::new(None) };
+}
+
+/// An optimization that could not be applied past a node of the plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizerTraceEvent {
+    /// Name of the optimization, e.g. `predicate_pushdown`.
+    pub optimization: &'static str,
+    /// The node the optimization stopped at.
+    pub node: String,
+    /// Why the optimization stopped at this node.
+    pub reason: &'static str,
+    /// The predicates or projections that are applied at this node instead.
+    pub exprs: Vec<String>,
+}
+
+impl fmt::Display for OptimizerTraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: blocked at {}: {} [{}]",
+            self.optimization,
+            self.node,
+            self.reason,
+            self.exprs.join(", ")
+        )
+    }
+}
+
+/// Run `f` and return the [`OptimizerTraceEvent`]s registered by the optimizations it ran on the
+/// current thread.
+pub fn record_optimizer_trace<T>(f: impl FnOnce() -> T) -> (T, Vec<OptimizerTraceEvent>) {
+    let outer = TRACE.with_borrow_mut(|trace| trace.replace(vec![]));
+    let out = f();
+    let events = TRACE.with_borrow_mut(|trace| std::mem::replace(trace, outer));
+    (out, events.unwrap_or_default())
+}
+
+/// Name of a node used in the trace. Functions are named by their description.
+pub(super) fn node_name(lp: &IR) -> String {
+    match lp {
+        IR::MapFunction { function, .. } => format!("{} ({function})", lp.name()),
+        lp => lp.name().to_string(),
+    }
+}
+
+/// Register an event if recording is enabled. The event is only constructed if needed.
+pub(super) fn trace_event<'a>(
+    optimization: &'static str,
+    node: impl FnOnce() -> String,
+    reason: &'static str,
+    exprs: impl IntoIterator<Item = &'a ExprIR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    trace_event_with(optimization, node, reason, || {
+        exprs
+            .into_iter()
+            .map(|e| e.display(expr_arena).to_string())
+            .collect()
+    })
+}
+
+/// Like [`trace_event`], but the affected expressions are already formatted.
+pub(super) fn trace_event_with(
+    optimization: &'static str,
+    node: impl FnOnce() -> String,
+    reason: &'static str,
+    exprs: impl FnOnce() -> Vec<String>,
+) {
+    TRACE.with_borrow_mut(|trace| {
+        if let Some(trace) = trace {
+            let exprs = exprs();
+            if !exprs.is_empty() {
+                trace.push(OptimizerTraceEvent {
+                    optimization,
+                    node: node(),
+                    reason,
+                    exprs,
+                });
+            }
+        }
+    })
+}
@@ -322,6 +322,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            chunk_size: None,
         };
 
         let sources = sources.0;
@@ -383,6 +384,9 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            chunk_size: None,
+            columns: None,
+            projection: None,
         };
 
         let sources = sources.0;
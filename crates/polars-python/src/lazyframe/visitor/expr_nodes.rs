@@ -836,6 +836,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     )
                         .into_py_any(py),
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    #[cfg(feature = "string_net")]
+                    IRStringFunction::UrlExtract(_)
+                    | IRStringFunction::IpIsValid
+                    | IRStringFunction::IpNormalize
+                    | IRStringFunction::IpInCidr(_) => {
+                        return Err(PyNotImplementedError::new_err("url/ip string functions"));
+                    },
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
                     },
@@ -1197,6 +1204,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ArgUnique => ("arg_unique",).into_py_any(py),
                 IRFunctionExpr::ArgMin => ("arg_min",).into_py_any(py),
                 IRFunctionExpr::ArgMax => ("arg_max",).into_py_any(py),
+                IRFunctionExpr::NanArgMin => ("nan_arg_min",).into_py_any(py),
+                IRFunctionExpr::NanArgMax => ("nan_arg_max",).into_py_any(py),
                 IRFunctionExpr::ArgSort {
                     descending,
                     nulls_last,
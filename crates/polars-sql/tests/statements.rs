@@ -578,6 +578,43 @@ fn test_join_utf8() {
     );
 }
 
+#[test]
+fn test_registered_lazyframes_query() {
+    let trades = df! {
+        "symbol" => ["AAPL", "MSFT", "AAPL", "GOOG", "MSFT", "AAPL"],
+        "qty" => [10, 5, 7, 3, 8, 1],
+    }
+    .unwrap();
+    let symbols = df! {
+        "symbol" => ["AAPL", "MSFT", "GOOG"],
+        "sector" => ["tech", "tech", "ads"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("trades", trades.lazy());
+    ctx.register("symbols", symbols.lazy());
+
+    let sql = r#"
+    SELECT t.symbol, SUM(t.qty) AS total
+    FROM trades t
+    INNER JOIN symbols s ON t.symbol = s.symbol
+    WHERE s.sector = 'tech' AND t.qty > 1
+    GROUP BY t.symbol
+    ORDER BY total DESC
+    LIMIT 1
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "symbol" => ["AAPL"],
+        "total" => [17],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_compound_invalid_1() {
@@ -287,6 +287,7 @@ fn run_subgraph(
 
 pub fn execute_graph(
     graph: &mut Graph,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -295,9 +296,14 @@ pub fn execute_graph(
     let (query_tasks_send, query_tasks_recv) = crossbeam_channel::unbounded();
     let (subphase_tasks_send, subphase_tasks_recv) = crossbeam_channel::unbounded();
 
+    let mut in_memory_exec_state = ExecutionState::default();
+    if memory_limit.is_some() {
+        in_memory_exec_state.set_memory_limit(memory_limit);
+    }
+
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state,
         query_tasks_send,
         subphase_tasks_send,
     };
@@ -343,6 +349,9 @@ pub fn execute_graph(
             break;
         }
 
+        // Abort before starting the next phase if the memory limit is exceeded.
+        state.in_memory_exec_state.should_stop()?;
+
         // Run the subgraph until phase completion.
         run_subgraph(graph, &nodes, &pipes, &mut pipe_seq_offsets, &state)?;
         polars_io::pl_async::get_runtime().block_on(async {
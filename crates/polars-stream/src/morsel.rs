@@ -9,11 +9,7 @@ use crate::async_primitives::wait_group::WaitToken;
 static IDEAL_MORSEL_SIZE: OnceLock<usize> = OnceLock::new();
 
 pub fn get_ideal_morsel_size() -> usize {
-    *IDEAL_MORSEL_SIZE.get_or_init(|| {
-        std::env::var("POLARS_IDEAL_MORSEL_SIZE")
-            .map(|m| m.parse().unwrap())
-            .unwrap_or(100_000)
-    })
+    *IDEAL_MORSEL_SIZE.get_or_init(polars_core::config::get_ideal_morsel_size)
 }
 
 /// A token indicating the order of morsels in a stream.
@@ -23,6 +23,9 @@ use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
 
+#[cfg(feature = "ipc")]
+mod spill;
+
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
@@ -47,6 +50,12 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // The estimated size of the cold morsels, and the cold morsels that were spilled to disk
+    // once that exceeded the memory budget.
+    cold_morsels_size: usize,
+    #[cfg(feature = "ipc")]
+    spill: Option<spill::GroupBySpill>,
 }
 
 impl LocalGroupBySinkState {
@@ -70,7 +79,40 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            cold_morsels_size: 0,
+            #[cfg(feature = "ipc")]
+            spill: None,
+        }
+    }
+
+    /// Write all cold morsels to disk, split by partition.
+    #[cfg(feature = "ipc")]
+    fn spill_cold_morsels(&mut self) -> PolarsResult<()> {
+        let num_partitions = self.morsel_idxs_values_per_p.len();
+        let spill = self
+            .spill
+            .get_or_insert_with(|| spill::GroupBySpill::new(num_partitions));
+        for (i, (seq, _keys, df)) in self.cold_morsels.drain(..).enumerate() {
+            for p in 0..num_partitions {
+                let start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let stop = self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                if start == stop {
+                    continue;
+                }
+                let idxs = &self.morsel_idxs_values_per_p[p][start..stop];
+                let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+                spill.write(p, seq, p_df)?;
+            }
+        }
+
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
         }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.cold_morsels_size = 0;
+        Ok(())
     }
 
     fn flush_evictions(&mut self, partitioner: &HashPartitioner) {
@@ -110,6 +152,8 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    // The memory budget for the cold morsels of each local state.
+    spill_budget: Option<usize>,
 }
 
 impl GroupBySinkState {
@@ -126,6 +170,7 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let spill_budget = self.spill_budget;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -181,6 +226,13 @@ impl GroupBySinkState {
                         unsafe {
                             let cold_keys = hash_keys.gather_unchecked(&cold_idxs);
                             let cold_df = df.take_slice_unchecked_impl(&cold_idxs, false);
+                            #[cfg(feature = "ipc")]
+                            let cold_df = if spill_budget.is_some() {
+                                let cold_key_df = keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                spill::with_key_columns(cold_df, cold_key_df)
+                            } else {
+                                cold_df
+                            };
 
                             cold_keys.gen_idxs_per_partition(
                                 &partitioner,
@@ -191,10 +243,17 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+                            local.cold_morsels_size += cold_df.estimated_size();
                             local.cold_morsels.push((seq, cold_keys, cold_df));
                         }
                     }
 
+                    // If the cold morsels exceed the memory budget, spill them.
+                    if spill_budget.is_some_and(|budget| local.cold_morsels_size > budget) {
+                        #[cfg(feature = "ipc")]
+                        local.spill_cold_morsels()?;
+                    }
+
                     // If we have too many evicted rows, flush them.
                     if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
                         local.flush_evictions(&partitioner);
@@ -212,15 +271,20 @@ impl GroupBySinkState {
                 .as_mut_slice()
                 .into_par_iter()
                 .with_max_len(1)
-                .for_each(|l| {
+                .try_for_each(|l| {
                     if l.hot_grouper.num_evictions() > 0 {
                         l.flush_evictions(&self.partitioner);
                     }
                     let hot_keys = l.hot_grouper.keys();
                     let hot_reductions = core::mem::take(&mut l.hot_grouped_reductions);
                     l.add_pre_agg(hot_keys, hot_reductions, &self.partitioner);
-                });
-        });
+                    #[cfg(feature = "ipc")]
+                    if let Some(spill) = &mut l.spill {
+                        spill.finish()?;
+                    }
+                    PolarsResult::Ok(())
+                })
+        })?;
 
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
@@ -248,6 +312,8 @@ impl GroupBySinkState {
         let grouper_template = &self.grouper;
         let grouped_reductions_template = &self.grouped_reductions;
         let grouped_reduction_cols = &self.grouped_reduction_cols;
+        #[cfg(feature = "ipc")]
+        let random_state = &self.random_state;
 
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -334,6 +400,29 @@ impl GroupBySinkState {
                         }
                     }
 
+                    // Insert morsels that were spilled to disk.
+                    #[cfg(feature = "ipc")]
+                    for l in locals {
+                        let Some(spilled) = l.spill.as_ref().map(|s| s.read_partition(p)) else {
+                            continue;
+                        };
+                        let Some(spilled) = spilled? else {
+                            continue;
+                        };
+                        let keys = HashKeys::from_df(&spilled.keys, *random_state, true, false);
+                        for (seq_id, idxs) in &spilled.morsels {
+                            unsafe {
+                                group_idxs.clear();
+                                p_grouper.insert_keys_subset(&keys, idxs, Some(&mut group_idxs));
+                                for (c, r) in grouped_reduction_cols.iter().zip(&mut p_reductions) {
+                                    let values = spilled.values.column(c.as_str()).unwrap();
+                                    r.resize(p_grouper.num_groups());
+                                    r.update_groups_subset(values, idxs, &group_idxs, *seq_id)?;
+                                }
+                            }
+                        }
+                    }
+
                     // Insert pre-aggregates.
                     for (l, l_pre_aggs) in locals.iter().zip(pre_aggs_per_local) {
                         // Try to help with dropping.
@@ -489,6 +578,10 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
+        #[cfg(feature = "ipc")]
+        let spill_budget = spill::get_spill_budget().map(|budget| budget / num_pipelines);
+        #[cfg(not(feature = "ipc"))]
+        let spill_budget = None;
         Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors,
@@ -499,6 +592,7 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                spill_budget,
             }),
             key_schema,
             output_schema,
@@ -0,0 +1,142 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_error::polars_warn;
+use polars_io::ipc::{BatchedWriter, IpcReader, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_io::{SerReader, SerWriter};
+use polars_utils::{IdxSize, format_pl_smallstr};
+
+const SEQ_COLUMN: &str = "__POLARS_GB_SPILL_SEQ";
+const KEY_COLUMN_PREFIX: &str = "__POLARS_GB_SPILL_KEY_";
+
+/// The memory budget for the cold rows of a streaming group-by, in bytes. Once the cold rows
+/// buffered by the group-by exceed this budget they are written to disk. An invalid budget is
+/// ignored with a warning.
+pub fn get_spill_budget() -> Option<usize> {
+    let budget = std::env::var("POLARS_STREAMING_GROUP_BY_SPILL_SIZE").ok()?;
+    match budget.parse::<usize>() {
+        Ok(budget) => Some(budget),
+        Err(_) => {
+            polars_warn!(
+                "ignoring invalid POLARS_STREAMING_GROUP_BY_SPILL_SIZE '{budget}', expected a number of bytes"
+            );
+            None
+        },
+    }
+}
+
+/// Appends the key columns to the payload of cold rows so they can be rehashed after being read
+/// back from disk.
+pub fn with_key_columns(mut df: DataFrame, keys: DataFrame) -> DataFrame {
+    let key_columns = keys
+        .take_columns()
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| c.with_name(format_pl_smallstr!("{KEY_COLUMN_PREFIX}{i}")));
+    unsafe { df.get_columns_mut().extend(key_columns) };
+    df
+}
+
+struct SpillFile {
+    path: PathBuf,
+    writer: Option<BatchedWriter<File>>,
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        drop(self.writer.take());
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Cold rows of a single group-by sink that were written to disk, one IPC file per partition.
+pub struct GroupBySpill {
+    files: Vec<Option<SpillFile>>,
+}
+
+/// Cold rows of a single partition that were read back from disk.
+pub struct SpilledRows {
+    pub keys: DataFrame,
+    pub values: DataFrame,
+    /// The row indices belonging to each morsel, together with the sequence id of that morsel.
+    pub morsels: Vec<(u64, Vec<IdxSize>)>,
+}
+
+impl GroupBySpill {
+    pub fn new(num_partitions: usize) -> Self {
+        Self {
+            files: (0..num_partitions).map(|_| None).collect(),
+        }
+    }
+
+    /// Write the rows of a morsel which belong to partition `p`.
+    pub fn write(&mut self, p: usize, seq: u64, mut df: DataFrame) -> PolarsResult<()> {
+        let seq = Column::new_scalar(
+            SEQ_COLUMN.into(),
+            Scalar::new(DataType::UInt64, AnyValue::UInt64(seq)),
+            df.height(),
+        );
+        df.with_column(seq)?;
+        df.rechunk_mut();
+
+        let file = match &mut self.files[p] {
+            Some(file) => file,
+            slot @ None => {
+                let dir = POLARS_TEMP_DIR_BASE_PATH.join("group-by-spill");
+                std::fs::create_dir_all(&dir)?;
+                let path = dir.join(format!(
+                    "{}-{:016x}.ipc",
+                    std::process::id(),
+                    rand::random::<u64>()
+                ));
+                let writer = IpcWriter::new(File::create(&path)?).batched(df.schema())?;
+                slot.insert(SpillFile {
+                    path,
+                    writer: Some(writer),
+                })
+            },
+        };
+        file.writer.as_mut().unwrap().write_batch(&df)
+    }
+
+    /// Write the footers of all files, after which they can be read.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        for file in self.files.iter_mut().flatten() {
+            if let Some(mut writer) = file.writer.take() {
+                writer.finish()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Read back the rows spilled for partition `p`.
+    pub fn read_partition(&self, p: usize) -> PolarsResult<Option<SpilledRows>> {
+        let Some(file) = &self.files[p] else {
+            return Ok(None);
+        };
+        let mut df = IpcReader::new(File::open(&file.path)?).finish()?;
+        df.rechunk_mut();
+
+        let seqs = df.drop_in_place(SEQ_COLUMN)?;
+        let mut morsels: Vec<(u64, Vec<IdxSize>)> = Vec::new();
+        for (i, seq) in seqs.u64()?.into_no_null_iter().enumerate() {
+            match morsels.last_mut() {
+                Some((last, idxs)) if *last == seq => idxs.push(i as IdxSize),
+                _ => morsels.push((seq, vec![i as IdxSize])),
+            }
+        }
+
+        let (keys, values): (Vec<_>, Vec<_>) = df
+            .take_columns()
+            .into_iter()
+            .partition(|c| c.name().starts_with(KEY_COLUMN_PREFIX));
+        let height = seqs.len();
+        Ok(Some(SpilledRows {
+            keys: unsafe { DataFrame::new_no_checks(height, keys) },
+            values: unsafe { DataFrame::new_no_checks(height, values) },
+            morsels,
+        }))
+    }
+}
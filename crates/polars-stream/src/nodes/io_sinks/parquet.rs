@@ -7,14 +7,14 @@ use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::write::BatchedWriter;
+use polars_io::parquet::write::{BatchedWriter, get_sorting_column};
 use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, SortingColumn,
+    Version, WriteOptions, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -48,7 +48,7 @@ pub struct ParquetSinkNode {
     file_size: Arc<RelaxedCell<u64>>,
     metrics: Arc<Mutex<Option<WriteMetrics>>>,
 
-    io_tx: Option<crate::async_primitives::connector::Sender<Vec<Vec<CompressedPage>>>>,
+    io_tx: Option<crate::async_primitives::connector::Sender<EncodedRowGroup>>,
     io_task: Option<tokio_util::task::AbortOnDropHandle<PolarsResult<()>>>,
 }
 
@@ -94,6 +94,12 @@ impl ParquetSinkNode {
 // 512 ^ 2
 const DEFAULT_ROW_GROUP_SIZE: usize = 1 << 18;
 
+/// The compressed pages of a row group, together with the order in which its rows are sorted.
+struct EncodedRowGroup {
+    columns: Vec<Vec<CompressedPage>>,
+    sorting_columns: Option<Vec<SortingColumn>>,
+}
+
 impl SinkNode for ParquetSinkNode {
     fn name(&self) -> &str {
         "parquet-sink"
@@ -108,7 +114,7 @@ impl SinkNode for ParquetSinkNode {
 
     fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
         // Collect task -> IO task
-        let (io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (io_tx, mut io_rx) = connector::<EncodedRowGroup>();
 
         // IO task.
         //
@@ -153,8 +159,11 @@ impl SinkNode for ParquetSinkNode {
             while let Ok(current_row_group) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
-                assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                assert_eq!(current_row_group.columns.len(), num_parquet_columns);
+                writer.write_row_group_with_sorting_columns(
+                    &current_row_group.columns,
+                    current_row_group.sorting_columns,
+                )?;
             }
 
             let file_size = writer.finish()?;
@@ -226,6 +235,7 @@ impl SinkNode for ParquetSinkNode {
                         while let Ok((rg_idx, col_idx, column)) = dist_rx.recv().await {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let column_options = &column_options[col_idx];
+                            let sorting_column = get_sorting_column(&column, &parquet_schema);
 
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
@@ -260,7 +270,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, compressed_pages, sorting_column),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -283,16 +296,20 @@ impl SinkNode for ParquetSinkNode {
                 seq: usize,
                 num_columns_seen: usize,
                 columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                sorting_columns: Vec<Option<SortingColumn>>,
             }
 
             let mut current = Current {
                 seq: 0,
                 num_columns_seen: 0,
                 columns: (0..input_schema.len()).map(|_| None).collect(),
+                sorting_columns: vec![None; input_schema.len()],
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, compressed_pages, sorting_column))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
@@ -300,6 +317,7 @@ impl SinkNode for ParquetSinkNode {
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
                 current.columns[i] = Some(compressed_pages);
+                current.sorting_columns[i] = sorting_column;
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -310,8 +328,17 @@ impl SinkNode for ParquetSinkNode {
                     for column in current.columns.iter_mut() {
                         current_row_group.extend(column.take().unwrap());
                     }
-
-                    if io_tx.send(current_row_group).await.is_err() {
+                    let sorting_columns: Vec<SortingColumn> = current
+                        .sorting_columns
+                        .iter_mut()
+                        .filter_map(Option::take)
+                        .collect();
+
+                    let encoded_row_group = EncodedRowGroup {
+                        columns: current_row_group,
+                        sorting_columns: (!sorting_columns.is_empty()).then_some(sorting_columns),
+                    };
+                    if io_tx.send(encoded_row_group).await.is_err() {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -49,6 +49,7 @@ pub mod builder {
     pub struct IpcReaderBuilder {
         #[expect(unused)]
         pub first_metadata: Option<Arc<FileMetadata>>,
+        pub chunk_size: Option<usize>,
     }
 
     #[cfg(feature = "ipc")]
@@ -86,6 +87,7 @@ pub mod builder {
                 scan_source,
                 cloud_options,
                 metadata,
+                chunk_size: self.chunk_size,
                 verbose,
                 init_data: None,
             };
@@ -105,6 +107,7 @@ struct IpcFileReader {
     scan_source: ScanSource,
     cloud_options: Option<Arc<CloudOptions>>,
     metadata: Option<Arc<FileMetadata>>,
+    chunk_size: Option<usize>,
     verbose: bool,
 
     init_data: Option<InitializedState>,
@@ -310,7 +313,9 @@ impl FileReader for IpcFileReader {
             projection_indices.map(|indices| prepare_projection(&file_metadata.schema, indices));
 
         // Split size for morsels.
-        let max_morsel_size = get_max_morsel_size();
+        let max_morsel_size = self
+            .chunk_size
+            .map_or_else(get_max_morsel_size, |chunk_size| chunk_size.max(1));
 
         let metadata = file_metadata;
 
@@ -56,6 +56,7 @@ impl DeletionFilesProvider {
                             parallel: polars_io::prelude::ParallelStrategy::Auto,
                             low_memory: false,
                             use_statistics: false,
+                            chunk_size: None,
                         }),
                     },
                     projected_schema: Arc::new(Schema::from_iter([
@@ -46,6 +46,7 @@ impl ParquetReadImpl {
         let row_group_decoder = Arc::new(row_group_decoder);
 
         let ideal_morsel_size = get_ideal_morsel_size();
+        let max_morsel_size = self.options.chunk_size;
 
         if verbose {
             eprintln!("[ParquetFileReader]: ideal_morsel_size: {ideal_morsel_size}");
@@ -204,6 +205,7 @@ impl ParquetReadImpl {
                 for df in split_to_morsels(
                     &df,
                     ideal_morsel_size,
+                    max_morsel_size,
                     next.is_none(),
                     last_morsel_min_split,
                 ) {
@@ -325,6 +327,7 @@ fn filtered_range(exclude: &[usize], len: usize) -> impl Iterator<Item = usize>
 fn split_to_morsels(
     df: &DataFrame,
     ideal_morsel_size: usize,
+    max_morsel_size: Option<usize>,
     last_morsel: bool,
     last_morsel_min_split: usize,
 ) -> impl Iterator<Item = DataFrame> + '_ {
@@ -339,6 +342,10 @@ fn split_to_morsels(
         n_morsels = n_morsels.max(last_morsel_min_split);
     }
 
+    if let Some(max_morsel_size) = max_morsel_size {
+        n_morsels = n_morsels.max(df.height().div_ceil(max_morsel_size.max(1)));
+    }
+
     let rows_per_morsel = df.height().div_ceil(n_morsels).max(1);
 
     (0..i64::try_from(df.height()).unwrap())
@@ -6,74 +6,13 @@ use arrow::pushable::Pushable;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::_internal::{ColumnStatistics, load_column_statistics, row_group_lengths};
 use polars_io::prelude::FileMetadata;
 use polars_parquet::read::RowGroupMetadata;
-use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
-use polars_utils::format_pl_smallstr;
 
 use crate::async_executor::{self, TaskPriority};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 
-struct StatisticsColumns {
-    min: Column,
-    max: Column,
-    null_count: Column,
-}
-
-impl StatisticsColumns {
-    fn new_null(dtype: &DataType, height: usize) -> Self {
-        Self {
-            min: Column::full_null(PlSmallStr::EMPTY, height, dtype),
-            max: Column::full_null(PlSmallStr::EMPTY, height, dtype),
-            null_count: Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
-        }
-    }
-
-    fn from_arrow_statistics(
-        statistics: ArrowColumnStatisticsArrays,
-        field: &ArrowField,
-    ) -> PolarsResult<Self> {
-        Ok(Self {
-            min: unsafe {
-                Series::_try_from_arrow_unchecked_with_md(
-                    PlSmallStr::EMPTY,
-                    vec![statistics.min_value],
-                    field.dtype(),
-                    field.metadata.as_deref(),
-                )
-            }?
-            .into_column(),
-
-            max: unsafe {
-                Series::_try_from_arrow_unchecked_with_md(
-                    PlSmallStr::EMPTY,
-                    vec![statistics.max_value],
-                    field.dtype(),
-                    field.metadata.as_deref(),
-                )
-            }?
-            .into_column(),
-
-            null_count: Series::from_arrow(PlSmallStr::EMPTY, statistics.null_count.boxed())?
-                .into_column(),
-        })
-    }
-
-    fn with_base_column_name(self, base_column_name: &str) -> Self {
-        let b = base_column_name;
-
-        let min = self.min.with_name(format_pl_smallstr!("{b}_min"));
-        let max = self.max.with_name(format_pl_smallstr!("{b}_max"));
-        let null_count = self.null_count.with_name(format_pl_smallstr!("{b}_nc"));
-
-        Self {
-            min,
-            max,
-            null_count,
-        }
-    }
-}
-
 pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     row_group_slice: Range<usize>,
     use_statistics: bool,
@@ -118,12 +57,10 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
 
         let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
 
-        let lengths: Vec<IdxSize> = row_groups_slice
-            .iter()
-            .map(|rg| rg.num_rows() as IdxSize)
-            .collect();
-
-        columns.push(Column::new("len".into(), lengths));
+        columns.push(Column::new(
+            "len".into(),
+            row_group_lengths(row_groups_slice)?,
+        ));
 
         for projection in projected_arrow_fields.iter() {
             let c = projection.output_name();
@@ -132,23 +69,20 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
                 continue;
             }
 
-            let mut statistics = load_parquet_column_statistics(row_groups_slice, projection)?;
+            let mut statistics =
+                load_column_statistics(row_groups_slice, projection.arrow_field())?;
 
             // Note: Order is important here. We re-use the transform for the output column, meaning
             // that it may set the column name.
             statistics.min = projection.apply_transform(statistics.min)?;
             statistics.max = projection.apply_transform(statistics.max)?;
 
-            let statistics = statistics.with_base_column_name(c);
-
-            columns.extend([statistics.min, statistics.max, statistics.null_count]);
+            columns.extend(statistics.into_named_columns(c));
         }
 
         if let Some(row_index) = row_index {
-            let statistics = build_row_index_statistics(&row_index, row_groups_slice)
-                .with_base_column_name(&row_index.name);
-
-            columns.extend([statistics.min, statistics.max, statistics.null_count]);
+            let statistics = build_row_index_statistics(&row_index, row_groups_slice);
+            columns.extend(statistics.into_named_columns(&row_index.name));
         }
 
         let statistics_df = DataFrame::new_with_height(num_row_groups, columns)?;
@@ -169,45 +103,10 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
-fn load_parquet_column_statistics(
-    row_groups: &[RowGroupMetadata],
-    projection: &ArrowFieldProjection,
-) -> PolarsResult<StatisticsColumns> {
-    let arrow_field = projection.arrow_field();
-
-    let null_statistics = || {
-        Ok(StatisticsColumns::new_null(
-            &DataType::from_arrow_field(arrow_field),
-            row_groups.len(),
-        ))
-    };
-
-    // This can be None in the allow_missing_columns case.
-    let Some(idxs) = row_groups[0].columns_idxs_under_root_iter(&arrow_field.name) else {
-        return null_statistics();
-    };
-
-    // 0 is possible for possible for empty structs.
-    //
-    // 2+ is for structs. We don't support reading nested statistics for now. It does not
-    // really make any sense at the moment with how we structure statistics.
-    if idxs.is_empty() || idxs.len() > 1 {
-        return null_statistics();
-    }
-
-    let idx = idxs[0];
-
-    let Some(statistics) = deserialize_all(arrow_field, row_groups, idx)? else {
-        return null_statistics();
-    };
-
-    StatisticsColumns::from_arrow_statistics(statistics, arrow_field)
-}
-
 fn build_row_index_statistics(
     row_index: &RowIndex,
     row_groups: &[RowGroupMetadata],
-) -> StatisticsColumns {
+) -> ColumnStatistics {
     let mut offset = row_index.offset;
 
     let null_count = PrimitiveArray::<IdxSize>::full(row_groups.len(), 0, ArrowDataType::IDX_DTYPE);
@@ -235,7 +134,7 @@ fn build_row_index_statistics(
         offset = offset.saturating_add(n_rows);
     }
 
-    StatisticsColumns {
+    ColumnStatistics {
         min: Series::from_array(PlSmallStr::EMPTY, min_value.freeze()).into_column(),
         max: Series::from_array(PlSmallStr::EMPTY, max_value.freeze()).into_column(),
         null_count: Series::from_array(PlSmallStr::EMPTY, null_count).into_column(),
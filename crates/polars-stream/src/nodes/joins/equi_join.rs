@@ -11,7 +11,7 @@ use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
 use polars_io::pl_async::get_runtime;
-use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
+use polars_ops::frame::{JoinArgs, JoinBuildSide, JoinType, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
@@ -1182,17 +1182,26 @@ impl EquiJoinNode {
         args: JoinArgs,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
-        let left_is_build = match args.maintain_order {
-            MaintainOrderJoin::None => {
-                if *JOIN_SAMPLE_LIMIT == 0 {
-                    Some(true)
-                } else {
-                    None
-                }
-            },
+        // Maintaining the order of an input requires probing with it.
+        let order_left_is_build = match args.maintain_order {
+            MaintainOrderJoin::None => None,
             MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight => Some(false),
             MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft => Some(true),
         };
+        let left_is_build = match (order_left_is_build, args.build_side) {
+            (Some(left_is_build), Some(side)) => {
+                polars_ensure!(
+                    left_is_build == (side == JoinBuildSide::Left),
+                    InvalidOperation: "cannot build the join on the {} input with maintain_order='{}'",
+                    <&str>::from(side), <&str>::from(args.maintain_order)
+                );
+                Some(left_is_build)
+            },
+            (Some(left_is_build), None) => Some(left_is_build),
+            (None, Some(side)) => Some(side == JoinBuildSide::Left),
+            (None, None) if *JOIN_SAMPLE_LIMIT == 0 => Some(true),
+            (None, None) => None,
+        };
 
         let preserve_order_probe = args.maintain_order != MaintainOrderJoin::None;
         let preserve_order_build = matches!(
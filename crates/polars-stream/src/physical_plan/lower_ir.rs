@@ -6,9 +6,10 @@ use polars_core::frame::{DataFrame, UniqueKeepStrategy};
 use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
+use polars_ops::frame::{JoinBuildSide, MaintainOrderJoin};
 use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
@@ -17,7 +18,9 @@ use polars_plan::dsl::{
     SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
-use polars_plan::plans::{AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive};
+use polars_plan::plans::{
+    AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, estimate_rows, write_ir_non_recursive,
+};
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
@@ -609,10 +612,11 @@ pub fn lower_ir(
 
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc {
-                        options: polars_io::ipc::IpcScanOptions {},
+                        options,
                         metadata: first_metadata,
                     } => Arc::new(crate::nodes::io_sources::ipc::builder::IpcReaderBuilder {
                         first_metadata: first_metadata.clone(),
+                        chunk_size: options.chunk_size,
                     }) as Arc<dyn FileReaderBuilder>,
 
                     #[cfg(feature = "csv")]
@@ -945,8 +949,29 @@ pub fn lower_ir(
             let input_right = *input_right;
             let left_on = left_on.clone();
             let right_on = right_on.clone();
-            let args = options.args.clone();
+            let mut args = options.args.clone();
             let options = options.options.clone();
+
+            // Build the hash table on the side with fewer estimated rows, unless the order to
+            // maintain already determines it.
+            if args.how.is_equi()
+                && !args.validation.needs_checks()
+                && args.build_side.is_none()
+                && args.maintain_order == MaintainOrderJoin::None
+            {
+                let rows_left = estimate_rows(input_left, ir_arena);
+                let rows_right = estimate_rows(input_right, ir_arena);
+                if let (Some(rows_left), Some(rows_right)) = (rows_left, rows_right) {
+                    if rows_left != rows_right {
+                        args.build_side = Some(if rows_left < rows_right {
+                            JoinBuildSide::Left
+                        } else {
+                            JoinBuildSide::Right
+                        });
+                    }
+                }
+            }
+
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
             if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
@@ -995,6 +1020,10 @@ pub fn lower_ir(
                         },
                     ))
                 } else {
+                    polars_ensure!(
+                        args.build_side.is_none(),
+                        InvalidOperation: "forcing the join build side is not supported for semi and anti joins"
+                    );
                     phys_sm.insert(PhysNode::new(
                         output_schema,
                         PhysNodeKind::SemiAntiJoin {
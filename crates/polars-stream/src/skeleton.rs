@@ -24,12 +24,18 @@ use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIR
 ///
 /// Returned `DataFrame`s contain data only for memory sinks,
 /// `DataFrame`s corresponding to file sinks are empty.
+///
+/// If a `memory_limit` is given, the query is aborted with an error once more than that many
+/// bytes are allocated. The limit is checked before every phase of the graph is executed.
 pub fn run_query(
     node: Node,
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<QueryResult> {
-    StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
+    StreamingQuery::build(node, ir_arena, expr_arena)?
+        .with_memory_limit(memory_limit)
+        .execute()
 }
 
 /// Visualizes the physical plan as a dot graph.
@@ -57,6 +63,7 @@ pub struct StreamingQuery {
     root_phys_node: PhysNodeKey,
     phys_sm: SlotMap<PhysNodeKey, PhysNode>,
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
+    memory_limit: Option<usize>,
 }
 
 impl StreamingQuery {
@@ -102,11 +109,18 @@ impl StreamingQuery {
             root_phys_node,
             phys_sm,
             phys_to_graph,
+            memory_limit: None,
         };
 
         Ok(out)
     }
 
+    /// Abort the query once more than `limit` bytes are allocated.
+    pub fn with_memory_limit(mut self, limit: Option<usize>) -> Self {
+        self.memory_limit = limit;
+        self
+    }
+
     pub fn execute(self) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
@@ -114,10 +128,11 @@ impl StreamingQuery {
             root_phys_node,
             phys_sm,
             phys_to_graph,
+            memory_limit,
         } = self;
 
         crate::async_executor::clear_task_wait_statistics();
-        let mut results = crate::execute::execute_graph(&mut graph)?;
+        let mut results = crate::execute::execute_graph(&mut graph, memory_limit)?;
 
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             let mut stats = crate::async_executor::get_task_wait_statistics();
@@ -31,6 +31,14 @@ where
     T: PolarsNumericType,
 {
     polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    if let Some(weights) = &options.weights {
+        polars_ensure!(
+            weights.len() == options.window_size,
+            InvalidOperation: "the length of `weights` ({}) should be equal to `window_size` ({})",
+            weights.len(),
+            options.window_size
+        );
+    }
     if ca.is_empty() {
         return Ok(Series::new_empty(ca.name().clone(), ca.dtype()));
     }
@@ -94,10 +94,7 @@ impl PolarsUpsample for DataFrame {
         time_column: &str,
         every: Duration,
     ) -> PolarsResult<DataFrame> {
-        let by = by.into_vec();
-        let time_type = self.column(time_column)?.dtype();
-        ensure_duration_matches_dtype(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, false)
+        upsample_checked(self, by.into_vec(), time_column, every, false)
     }
 
     fn upsample_stable<I: IntoVec<PlSmallStr>>(
@@ -106,13 +103,33 @@ impl PolarsUpsample for DataFrame {
         time_column: &str,
         every: Duration,
     ) -> PolarsResult<DataFrame> {
-        let by = by.into_vec();
-        let time_type = self.column(time_column)?.dtype();
-        ensure_duration_matches_dtype(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, true)
+        upsample_checked(self, by.into_vec(), time_column, every, true)
     }
 }
 
+fn upsample_checked(
+    source: &DataFrame,
+    by: Vec<PlSmallStr>,
+    time_column: &str,
+    every: Duration,
+    stable: bool,
+) -> PolarsResult<DataFrame> {
+    let time_type = source.column(time_column)?.dtype();
+    // Small integer index columns are upsampled as `Int32` and cast back afterwards.
+    if matches!(
+        time_type,
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16
+    ) {
+        let mut df = source.clone();
+        df.try_apply(time_column, |s| s.cast(&DataType::Int32))?;
+        let mut out = upsample_checked(&df, by, time_column, every, stable)?;
+        out.try_apply(time_column, |s| s.cast(time_type))?;
+        return Ok(out);
+    }
+    ensure_duration_matches_dtype(every, time_type, "every")?;
+    upsample_impl(source, by, time_column, every, stable)
+}
+
 fn upsample_impl(
     source: &DataFrame,
     by: Vec<PlSmallStr>,
@@ -185,6 +185,7 @@
 //! * `strings` - Extra string utilities for [`StringChunked`]
 //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_net` - `url_extract`, `ip_is_valid`, `ip_normalize`, `ip_in_cidr`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!   These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -203,6 +204,7 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `flight` - Scan Arrow Flight streams with a user-provided client
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -395,6 +397,8 @@
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
 //! * `POLARS_VERBOSE` -> print logging info to stderr.
+//! * `POLARS_INFER_SCHEMA_LENGTH` -> number of rows used to infer the schema of a CSV file if the
+//!   reader doesn't set it, `100` by default.
 //! * `POLARS_NO_PARTITION` -> polars may choose to partition the group_by operation, based on data
 //!   cardinality. Setting this env var will turn partitioned group_by's off.
 //! * `POLARS_PARTITION_UNIQUE_COUNT` -> at which (estimated) key count a partitioned group_by should run.
@@ -406,6 +410,12 @@
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_BACKTRACE_IN_ERR` -> include a Rust backtrace in Error messages.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_JOIN_BROADCAST_THRESHOLD` -> build sides of a hash join with fewer keys than this are
+//!   hashed into a single map shared by all threads instead of a partitioned table.
+//!
+//! The formatting limits, the size of the thread pool and the verbosity can also be set from Rust
+//! with [`polars_core::config::set_config`], which takes precedence over the environment
+//! variables.
 //!
 //! ## User guide
 //!
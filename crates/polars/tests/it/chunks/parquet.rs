@@ -1,6 +1,7 @@
 use std::io::{Seek, SeekFrom};
 
 use polars::prelude::*;
+use polars_core::series::IsSorted;
 
 #[test]
 fn test_cast_join_14872() {
@@ -42,3 +43,168 @@ fn test_cast_join_14872() {
 
     assert!(expected.equals(&out));
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_parquet_statistics_df() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(3), None, Some(1), Some(7), Some(5), Some(6)],
+        "b" => ["x", "y", "z", "w", "v", "u"],
+        "c" => vec![Series::new("".into(), [1]); 6],
+    ]?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_statistics(StatisticsOptions::full())
+        .with_row_group_size(Some(3))
+        .finish(&mut df)?;
+
+    let _ = buf.seek(SeekFrom::Start(0));
+    let stats = ParquetReader::new(buf).statistics()?;
+    assert_eq!(stats.get_column_names(), &["len", "columns"]);
+    assert_eq!(Vec::from(stats.column("len")?.idx()?), &[Some(3), Some(3)]);
+
+    let columns = stats.column("columns")?.struct_()?.clone();
+    let field = |column: &str, statistic: &str| -> PolarsResult<Series> {
+        columns
+            .field_by_name(column)?
+            .struct_()?
+            .field_by_name(statistic)
+    };
+    assert_eq!(Vec::from(field("a", "min")?.i32()?), &[Some(1), Some(5)]);
+    assert_eq!(Vec::from(field("a", "max")?.i32()?), &[Some(3), Some(7)]);
+    assert_eq!(
+        Vec::from(field("a", "null_count")?.idx()?),
+        &[Some(1), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(field("b", "min")?.str()?),
+        &[Some("x"), Some("u")]
+    );
+    // Statistics of nested columns are not read.
+    assert_eq!(field("c", "min")?.null_count(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_column_stats() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(3), None, Some(1), Some(3)],
+        "b" => ["y", "x", "z", "x"],
+        "c" => [1.0, f64::NAN, -1.0, 2.0],
+    ]?;
+    let stats = df.column_stats()?;
+    assert_eq!(stats.height(), 1);
+    assert_eq!(
+        stats.get_column_names()[..5],
+        ["len", "a_min", "a_max", "a_nc", "a_nd"]
+    );
+
+    let get = |name: &str| stats.column(name).unwrap().get(0).unwrap().into_static();
+    assert_eq!(get("len"), AnyValue::from(4 as IdxSize));
+    // Null is not counted as a distinct value.
+    assert_eq!(get("a_nd"), AnyValue::from(2 as IdxSize));
+    assert_eq!(get("b_nd"), AnyValue::from(3 as IdxSize));
+    assert!(matches!(get("c_min"), AnyValue::Float64(v) if v.is_nan()));
+
+    // The statistics match the ones written to a parquet file with a single row group.
+    let mut buf = std::io::Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_statistics(StatisticsOptions::full())
+        .finish(&mut df)?;
+    let _ = buf.seek(SeekFrom::Start(0));
+    let written = ParquetReader::new(buf).statistics()?;
+    let columns = written.column("columns")?.struct_()?.clone();
+    for name in ["a", "b", "c"] {
+        let column = columns.field_by_name(name)?;
+        let column = column.struct_()?;
+        for (statistic, suffix) in [("min", "min"), ("max", "max"), ("null_count", "nc")] {
+            let expected = column.field_by_name(statistic)?.get(0)?;
+            let actual = stats.column(&format!("{name}_{suffix}"))?.get(0)?;
+            // Compare the debug output, so that NaN equals NaN.
+            assert_eq!(
+                format!("{actual:?}"),
+                format!("{expected:?}"),
+                "{name}_{suffix}"
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_chunk_size() -> PolarsResult<()> {
+    let mut df = df!["a" => 0..10, "b" => (0..10).map(|i| i * 2).collect::<Vec<_>>()]?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(5))
+        .finish(&mut df)?;
+
+    let _ = buf.seek(SeekFrom::Start(0));
+    let out = ParquetReader::new(buf).with_chunk_size(3).finish()?;
+    let lengths = |c: &Column| {
+        c.as_materialized_series()
+            .chunk_lengths()
+            .collect::<Vec<_>>()
+    };
+    // Row groups are sliced, not merged, so every chunk fits in `chunk_size`.
+    assert_eq!(lengths(out.column("a")?), [3, 2, 3, 2]);
+    assert_eq!(lengths(out.column("b")?), [3, 2, 3, 2]);
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_sorted_flag_roundtrip() -> PolarsResult<()> {
+    let a = Series::new("a".into(), [Some(3i32), Some(2), None]);
+    let s = StructChunked::from_series("s".into(), 3, [a].iter())?.into_series();
+    let mut df = DataFrame::new(vec![
+        s.into_column(),
+        Column::new("sorted".into(), [3i64, 1, 2]),
+        Column::new("unsorted".into(), [2i64, 1, 3]),
+    ])?;
+    // The struct comes first, so the leaf index of `sorted` differs from its column index.
+    df.try_apply("sorted", |s| s.sort(SortOptions::default()))?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let _ = buf.seek(SeekFrom::Start(0));
+    let out = ParquetReader::new(buf).finish()?;
+    assert_eq!(out.column("sorted")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(out.column("unsorted")?.is_sorted_flag(), IsSorted::Not);
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_write_unordered() -> PolarsResult<()> {
+    let mut a = Series::new("a".into(), (0..100).collect::<Vec<i32>>());
+    a.set_sorted_flag(IsSorted::Ascending);
+    let mut df = DataFrame::new(vec![a.into_column()])?;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(10))
+        .with_maintain_order(false)
+        .finish(&mut df)?;
+
+    let _ = buf.seek(SeekFrom::Start(0));
+    let mut reader = ParquetReader::new(buf);
+    let metadata = reader.get_metadata()?;
+    assert_eq!(metadata.row_groups.len(), 10);
+    // Every row group is sorted on its own.
+    assert!(
+        metadata
+            .row_groups
+            .iter()
+            .all(|rg| rg.sorting_columns().is_some_and(|sc| sc.len() == 1))
+    );
+    let out = reader
+        .finish()?
+        .sort(["a"], SortMultipleOptions::default())?;
+    assert!(out.equals(&df));
+    Ok(())
+}
@@ -74,6 +74,30 @@ fn test_inner_join() {
     }
 }
 
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+
+    // Duplicate matches in `rain` must not duplicate rows of `temp`.
+    let out = temp.semi_join(&rain, ["days"], ["days"])?;
+    let expected = df![
+        "days" => [1, 2],
+        "temp" => [19.9, 7.],
+        "rain" => [0.1, 0.3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = temp.anti_join(&rain, ["days"], ["days"])?;
+    let expected = df![
+        "days" => [0],
+        "temp" => [22.1],
+        "rain" => [0.2],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 #[cfg_attr(miri, ignore)]
@@ -368,6 +392,76 @@ fn test_join_categorical() {
     assert!(out.is_err());
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(all(
+    feature = "dtype-categorical",
+    feature = "dtype-date",
+    feature = "dtype-datetime"
+))]
+fn test_full_join_multiple_logical_keys() -> PolarsResult<()> {
+    let cat_dtype = DataType::from_categories(Categories::global());
+    let dt_dtype = DataType::Datetime(TimeUnit::Milliseconds, None);
+    let cast_keys = |mut df: DataFrame| -> PolarsResult<DataFrame> {
+        df.try_apply("cat", |s| s.cast(&cat_dtype))?;
+        df.try_apply("date", |s| s.cast(&DataType::Date))?;
+        df.try_apply("dt", |s| s.cast(&dt_dtype))?;
+        Ok(df)
+    };
+    let df_left = cast_keys(df!(
+        "cat" => ["a", "b", "c"],
+        "date" => [0i32, 1, 2],
+        "dt" => [0i64, 1000, 2000],
+        "x" => [1, 2, 3]
+    )?)?;
+    let df_right = cast_keys(df!(
+        "cat" => ["b", "c", "d"],
+        "date" => [1i32, 5, 3],
+        "dt" => [1000i64, 2000, 3000],
+        "y" => [4, 5, 6]
+    )?)?;
+
+    for coalesce in [JoinCoalesce::CoalesceColumns, JoinCoalesce::KeepColumns] {
+        let out = df_left.join(
+            &df_right,
+            ["cat", "date", "dt"],
+            ["cat", "date", "dt"],
+            JoinArgs::new(JoinType::Full).with_coalesce(coalesce),
+            None,
+        )?;
+        assert_eq!(out.height(), 5);
+        assert_eq!(out.column("cat")?.dtype(), &cat_dtype);
+        assert_eq!(out.column("date")?.dtype(), &DataType::Date);
+        assert_eq!(out.column("dt")?.dtype(), &dt_dtype);
+        if coalesce == JoinCoalesce::KeepColumns {
+            assert_eq!(out.column("cat_right")?.dtype(), &cat_dtype);
+            assert_eq!(out.column("date_right")?.dtype(), &DataType::Date);
+            assert_eq!(out.column("dt_right")?.dtype(), &dt_dtype);
+        }
+    }
+
+    let out = df_left
+        .join(
+            &df_right,
+            ["cat", "date", "dt"],
+            ["cat", "date", "dt"],
+            JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            None,
+        )?
+        .sort(["x"], SortMultipleOptions::default().with_nulls_last(true))?;
+    let cat = out.column("cat")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(cat.str()?),
+        &[Some("a"), Some("b"), Some("c"), Some("c"), Some("d")]
+    );
+    let date = out.column("date")?.to_physical_repr();
+    assert_eq!(
+        Vec::from(date.i32()?),
+        &[Some(0), Some(1), Some(2), Some(5), Some(3)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_empty_df_join() -> PolarsResult<()> {
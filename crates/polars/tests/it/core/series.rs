@@ -41,3 +41,25 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+#[cfg(feature = "search_sorted")]
+fn test_search_sorted_infers_order() -> PolarsResult<()> {
+    let search_values = Series::new("v".into(), &[2, 5]);
+
+    let a = Series::new("a".into(), &[1, 2, 4, 6]);
+    let idx = a.search_sorted(&search_values, SearchSortedSide::Left)?;
+    assert_eq!(Vec::from(&idx), &[Some(1), Some(3)]);
+
+    let mut b = Series::new("b".into(), &[6, 4, 2, 1]);
+    b.set_sorted_flag(IsSorted::Descending);
+    let idx = b.search_sorted(&search_values, SearchSortedSide::Left)?;
+    assert_eq!(Vec::from(&idx), &[Some(2), Some(1)]);
+
+    let c = Series::new("c".into(), &[1, 4, 2]);
+    assert!(
+        c.search_sorted(&search_values, SearchSortedSide::Left)
+            .is_err()
+    );
+    Ok(())
+}
@@ -2,6 +2,7 @@ use std::io::Cursor;
 use std::num::NonZeroUsize;
 
 use polars::io::RowIndex;
+use polars::io::mmap::SpooledReader;
 use polars_core::utils::concat_df;
 
 use super::*;
@@ -146,6 +147,22 @@ fn test_read_csv_file() {
     assert_eq!(df.shape(), (27, 4));
 }
 
+#[test]
+fn test_read_csv_non_seekable() -> PolarsResult<()> {
+    let csv = std::fs::read(FOODS_CSV)?;
+
+    // A byte slice can be read but not seeked, like stdin.
+    let df = CsvReader::new(SpooledReader::new(csv.as_slice())?).finish()?;
+    assert_eq!(df.shape(), (27, 4));
+
+    // Inputs larger than the memory limit are spooled to a temporary file.
+    let reader = SpooledReader::with_memory_limit(csv.as_slice(), 16)?;
+    assert!(matches!(reader, SpooledReader::File(_)));
+    let out = CsvReader::new(reader).finish()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_filter() -> PolarsResult<()> {
     let df = CsvReadOptions::default()
@@ -1053,6 +1070,63 @@ fn test_null_values_infer_schema() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_null_values_pattern() -> PolarsResult<()> {
+    let csv = r#"a,b,c
+1,NA,x
+NA_1,2.5,NA_
+3,NA_23,NAB"#;
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::Pattern(r"^NA_?\d*$".into())))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let expected = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [None, Some(2.5), None],
+        "c" => [Some("x"), None, Some("NAB")],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    let file = Cursor::new(csv);
+    let err = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::Pattern("(".into())))
+        })
+        .into_reader_with_file_handle(file)
+        .finish();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_strict_schema() -> PolarsResult<()> {
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let read = |csv: &'static str| {
+        CsvReadOptions::default()
+            .with_strict_schema(schema.clone())
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish()
+    };
+
+    let df = read("a,b\n1,x\n2,y\n")?;
+    assert_eq!(df.schema().as_ref(), schema.as_ref());
+    assert_eq!(df.shape(), (2, 2));
+
+    // Extra, missing and reordered columns.
+    assert!(read("a,b,c\n1,x,1\n").is_err());
+    assert!(read("a\n1\n").is_err());
+    assert!(read("b,a\nx,1\n").is_err());
+    // Values that don't parse as the declared dtype.
+    assert!(read("a,b\n1,x\nfoo,y\n").is_err());
+    Ok(())
+}
+
 #[test]
 fn test_comma_separated_field_in_tsv() -> PolarsResult<()> {
     let csv = "first\tsecond\n1\t2.3,2.4\n3\t4.5,4.6\n";
@@ -22,6 +22,178 @@ fn test_ipc_compression_variadic_buffers() {
     assert_eq!(out.shape(), (3, 1));
 }
 
+#[test]
+fn test_read_ipc_chunk_size() {
+    let mut df = df!["a" => 0..10].unwrap();
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df).unwrap();
+    buf.set_position(0);
+
+    let out = IpcReader::new(buf).with_chunk_size(4).finish().unwrap();
+    let a = out.column("a").unwrap().as_materialized_series();
+    assert_eq!(a.chunk_lengths().collect::<Vec<_>>(), [4, 4, 2]);
+    assert!(out.equals(&df));
+}
+
+/// A field of a flatbuffers table, to write Feather V1 metadata by hand.
+enum Fb {
+    Absent,
+    I8(i8),
+    I64(i64),
+    Str(&'static str),
+    Table(Vec<Fb>),
+    Tables(Vec<Vec<Fb>>),
+}
+
+/// Write a vtable followed by its table and return the position of the table.
+fn write_fb_table(buf: &mut Vec<u8>, fields: &[Fb]) -> usize {
+    let sizes = fields.iter().map(|field| match field {
+        Fb::Absent => 0,
+        Fb::I8(_) => 1,
+        Fb::I64(_) => 8,
+        _ => 4,
+    });
+
+    let vtable = buf.len();
+    buf.extend((4 + 2 * fields.len() as u16).to_le_bytes());
+    buf.extend((4 + sizes.clone().sum::<usize>() as u16).to_le_bytes());
+    let mut offset = 4;
+    for size in sizes {
+        buf.extend((if size == 0 { 0 } else { offset }).to_le_bytes());
+        offset += size as u16;
+    }
+
+    let table = buf.len();
+    buf.extend(((table - vtable) as i32).to_le_bytes());
+    let mut children = vec![];
+    for field in fields {
+        match field {
+            Fb::Absent => {},
+            Fb::I8(v) => buf.extend(v.to_le_bytes()),
+            Fb::I64(v) => buf.extend(v.to_le_bytes()),
+            _ => {
+                children.push((buf.len(), field));
+                buf.extend([0; 4]);
+            },
+        }
+    }
+
+    for (position, field) in children {
+        let target = match field {
+            Fb::Str(s) => {
+                let target = buf.len();
+                buf.extend((s.len() as u32).to_le_bytes());
+                buf.extend(s.as_bytes());
+                target
+            },
+            Fb::Table(fields) => write_fb_table(buf, fields),
+            Fb::Tables(tables) => {
+                let target = buf.len();
+                buf.extend((tables.len() as u32).to_le_bytes());
+                buf.resize(buf.len() + 4 * tables.len(), 0);
+                for (i, fields) in tables.iter().enumerate() {
+                    let slot = target + 4 + 4 * i;
+                    let table = write_fb_table(buf, fields);
+                    buf[slot..slot + 4].copy_from_slice(&((table - slot) as u32).to_le_bytes());
+                }
+                target
+            },
+            _ => unreachable!(),
+        };
+        buf[position..position + 4].copy_from_slice(&((target - position) as u32).to_le_bytes());
+    }
+    table
+}
+
+/// Append the buffers of an array to `file` and return its `PrimitiveArray` metadata.
+fn feather_v1_array(file: &mut Vec<u8>, ty: i8, length: i64, null_count: i64, data: &[u8]) -> Fb {
+    let offset = file.len() as i64;
+    file.extend(data);
+    file.resize(file.len().next_multiple_of(8), 0);
+    Fb::Table(vec![
+        Fb::I8(ty),
+        Fb::Absent,
+        Fb::I64(offset),
+        Fb::I64(length),
+        Fb::I64(null_count),
+        Fb::I64(data.len() as i64),
+    ])
+}
+
+/// A Feather V1 file with an int32 column `a` with a null, a utf8 column `b` and a categorical
+/// column `c`.
+fn feather_v1_file() -> Vec<u8> {
+    let mut file = b"FEA1\0\0\0\0".to_vec();
+
+    let mut a = vec![0b101, 0, 0, 0, 0, 0, 0, 0];
+    a.extend([1i32, 0, 3].map(i32::to_le_bytes).concat());
+    let a = feather_v1_array(&mut file, 3, 3, 1, &a);
+    let mut b = [0i32, 1, 3, 3].map(i32::to_le_bytes).concat();
+    b.extend(b"xyz");
+    let b = feather_v1_array(&mut file, 11, 3, 0, &b);
+    let codes = feather_v1_array(&mut file, 1, 3, 0, &[0, 1, 0]);
+    // The offsets are padded to 8 bytes.
+    let mut levels = [0i32, 2, 4, 0].map(i32::to_le_bytes).concat();
+    levels.extend(b"lohi");
+    let levels = feather_v1_array(&mut file, 11, 2, 0, &levels);
+
+    let columns = vec![
+        vec![Fb::Str("a"), a],
+        vec![Fb::Str("b"), b],
+        vec![
+            Fb::Str("c"),
+            codes,
+            Fb::I8(1),
+            Fb::Table(vec![levels, Fb::Absent]),
+        ],
+    ];
+    let mut metadata = vec![0; 4];
+    let root = write_fb_table(
+        &mut metadata,
+        &[Fb::Absent, Fb::I64(3), Fb::Tables(columns)],
+    );
+    metadata[..4].copy_from_slice(&(root as u32).to_le_bytes());
+
+    file.extend(&metadata);
+    file.extend((metadata.len() as i32).to_le_bytes());
+    file.extend(b"FEA1");
+    file
+}
+
+#[test]
+fn test_read_feather_v1() {
+    let out = IpcReader::new(Cursor::new(feather_v1_file()))
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        Vec::from(out.column("a").unwrap().i32().unwrap()),
+        &[Some(1), None, Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b").unwrap().str().unwrap()),
+        &[Some("x"), Some("yz"), Some("")]
+    );
+    let c = out.column("c").unwrap();
+    assert!(matches!(c.dtype(), DataType::Categorical(_, _)));
+    let c = c.cast(&DataType::String).unwrap();
+    assert_eq!(
+        Vec::from(c.str().unwrap()),
+        &[Some("lo"), Some("hi"), Some("lo")]
+    );
+
+    let out = IpcReader::new(Cursor::new(feather_v1_file()))
+        .with_columns(Some(vec!["b".into()]))
+        .with_n_rows(Some(2))
+        .finish()
+        .unwrap();
+    assert_eq!(out.get_column_names(), &["b"]);
+    assert_eq!(
+        Vec::from(out.column("b").unwrap().str().unwrap()),
+        &[Some("x"), Some("yz")]
+    );
+}
+
 #[cfg(test)]
 pub(crate) fn create_df() -> DataFrame {
     let s0 = Column::new("days".into(), [0, 1, 2, 3, 4].as_ref());
@@ -137,6 +309,47 @@ fn test_write_with_compression() {
     }
 }
 
+#[test]
+fn test_write_with_compression_incompressible_buffers() {
+    // Pseudo-random values don't compress, so they are stored uncompressed in the compressed file.
+    let mut state = 0x2545F4914F6CDD1Du64;
+    let random = (0..1000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+        .collect::<Vec<_>>();
+    let mut df = df![
+        "random" => random,
+        "repeated" => std::iter::repeat_n("Home delivery vat 24 %", 1000).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+
+    let write = |df: &mut DataFrame, compression: Option<IpcCompression>| {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(df)
+            .unwrap();
+        buf.set_position(0);
+        buf
+    };
+
+    let uncompressed_size = write(&mut df.select(["random"]).unwrap(), None)
+        .get_ref()
+        .len();
+    for compression in [IpcCompression::LZ4, IpcCompression::ZSTD] {
+        let buf = write(&mut df.select(["random"]).unwrap(), Some(compression));
+        assert!(buf.get_ref().len() <= uncompressed_size + 128);
+
+        let buf = write(&mut df, Some(compression));
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df.equals(&df_read));
+    }
+}
+
 #[test]
 fn write_and_read_ipc_empty_series() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
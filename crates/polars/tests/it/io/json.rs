@@ -185,3 +185,80 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn read_json_strict_schema() {
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let read = |json: &'static str| {
+        JsonReader::new(Cursor::new(json))
+            .with_strict_schema(schema.clone())
+            .finish()
+    };
+
+    let df = read(r#"[{"a": 1, "b": "x"}, {"a": 2, "b": null}]"#).unwrap();
+    assert_eq!(df.shape(), (2, 2));
+
+    assert!(read(r#"[{"a": 1, "b": "x", "c": 1}]"#).is_err());
+    assert!(read(r#"[{"a": 1}]"#).is_err());
+    assert!(read(r#"[{"a": "x", "b": "x"}]"#).is_err());
+
+    let read_ndjson = |json: &'static str| {
+        JsonReader::new(Cursor::new(json))
+            .with_json_format(JsonFormat::JsonLines)
+            .with_strict_schema(schema.clone())
+            .finish()
+    };
+
+    let df = read_ndjson(
+        r#"{"a": 1, "b": "x"}
+{"a": 2, "b": null}
+"#,
+    )
+    .unwrap();
+    assert_eq!(df.shape(), (2, 2));
+
+    assert!(read_ndjson(r#"{"a": 1, "b": "x", "c": 1}"#).is_err());
+    assert!(
+        read_ndjson(
+            r#"{"a": 1, "b": "x"}
+{"a": 2}
+"#
+        )
+        .is_err()
+    );
+    assert!(read_ndjson(r#"{"a": "x", "b": "x"}"#).is_err());
+}
+
+#[test]
+fn read_json_columns_and_projection() -> PolarsResult<()> {
+    let json = r#"[{"a": 1, "b": "x", "c": true}, {"a": 2, "b": "y", "c": false}]"#;
+    let df = JsonReader::new(Cursor::new(json))
+        .with_columns(Some(vec!["c".into(), "a".into()]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["c", "a"]);
+    let df = JsonReader::new(Cursor::new(json))
+        .with_column_indices(Some(vec![2, 0]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["c", "a"]);
+    #[allow(deprecated)]
+    let df = JsonReader::new(Cursor::new(json))
+        .with_projection(Some(vec!["b".into()]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["b"]);
+
+    let ndjson = "{\"a\": 1, \"b\": \"x\", \"c\": true}\n{\"a\": 2, \"b\": \"y\", \"c\": false}\n";
+    let df = JsonLineReader::new(Cursor::new(ndjson))
+        .with_column_indices(Some(vec![1]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["b"]);
+    assert!(
+        JsonLineReader::new(Cursor::new(ndjson))
+            .with_column_indices(Some(vec![3]))
+            .finish()
+            .is_err()
+    );
+    Ok(())
+}
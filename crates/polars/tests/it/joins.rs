@@ -56,3 +56,126 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn join_maintain_order() -> PolarsResult<()> {
+    let left = df! {
+        "key" => [3, 1, 2, 1, 4],
+        "l" => [0, 1, 2, 3, 4],
+    }?;
+    let right = df! {
+        "key" => [1, 4, 3, 5, 1],
+        "r" => [0, 1, 2, 3, 4],
+    }?;
+    let join = |how: JoinType, maintain_order: MaintainOrderJoin| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("key")])
+            .right_on([col("key")])
+            .how(how)
+            .maintain_order(maintain_order)
+            .finish()
+            .collect()
+    };
+    let column = |df: &DataFrame, name: &str| -> PolarsResult<Vec<Option<i32>>> {
+        Ok(df.column(name)?.i32()?.into_iter().collect())
+    };
+
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Full] {
+        let out = join(how.clone(), MaintainOrderJoin::Left)?;
+        let l = column(&out, "l")?;
+        let mut sorted = l.clone();
+        sorted.sort_by_key(|v| v.is_none());
+        assert_eq!(l, sorted, "{how:?}");
+        assert!(l.iter().flatten().is_sorted(), "{how:?}");
+
+        let out = join(how.clone(), MaintainOrderJoin::RightLeft)?;
+        let r = column(&out, "r")?;
+        let mut sorted = r.clone();
+        sorted.sort_by_key(|v| v.is_none());
+        assert_eq!(r, sorted, "{how:?}");
+        assert!(r.iter().flatten().is_sorted(), "{how:?}");
+    }
+
+    // Within a group of matches the other side keeps its order as well.
+    let out = join(JoinType::Inner, MaintainOrderJoin::LeftRight)?;
+    assert_eq!(
+        column(&out, "l")?,
+        &[Some(0), Some(1), Some(1), Some(3), Some(3), Some(4)]
+    );
+    assert_eq!(
+        column(&out, "r")?,
+        &[Some(2), Some(0), Some(4), Some(0), Some(4), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+fn join_with_unique_key() -> PolarsResult<()> {
+    let facts = df![
+        "id" => [1, 2, 2, 3],
+        "v" => [10, 20, 30, 40],
+    ]?;
+    let dim = df![
+        "id" => [1, 2, 3],
+        "name" => ["a", "b", "c"],
+    ]?
+    .with_unique_key(["id"])?;
+
+    let mut args = JoinArgs::new(JoinType::Left);
+    args.validation = JoinValidation::ManyToOne;
+    let out = facts.join(&dim, ["id"], ["id"], args.clone(), None)?;
+    assert_eq!(
+        out.column("name")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["a", "b", "b", "c"]
+    );
+
+    // The key only vouches for its own frame.
+    args.validation = JoinValidation::OneToOne;
+    assert!(facts.join(&dim, ["id"], ["id"], args, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn join_broadcast_threshold() -> PolarsResult<()> {
+    use polars_core::SINGLE_LOCK;
+    use polars_core::config::{Config, get_config, set_config};
+
+    let left = df! {
+        "k" => (0..10_000).map(|i| (i * 7) % 1500).collect::<Vec<i32>>(),
+        "a" => (0..10_000).collect::<Vec<i32>>(),
+    }?;
+    let right = df! {
+        "k" => (0..1000).collect::<Vec<i32>>(),
+        "b" => (0..1000).map(|i| i * 2).collect::<Vec<i32>>(),
+    }?;
+    let join = |how: JoinType| -> PolarsResult<DataFrame> {
+        left.join(&right, ["k"], ["k"], how.into(), None)?
+            .sort(["a"], Default::default())
+    };
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let old = get_config();
+    let mut results = vec![];
+    // Force a partitioned build table, then a single broadcast table.
+    for threshold in [0, 1_000_000] {
+        set_config(Config {
+            join_broadcast_threshold: Some(threshold),
+            ..old.clone()
+        });
+        let out = (join(JoinType::Inner), join(JoinType::Left));
+        set_config(old.clone());
+        results.push((out.0?, out.1?));
+    }
+
+    assert_eq!(results[0].0.height(), 6714);
+    assert!(results[0].0.equals(&results[1].0));
+    assert!(results[0].1.equals_missing(&results[1].1));
+    Ok(())
+}
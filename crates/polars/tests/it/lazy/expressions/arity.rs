@@ -367,3 +367,30 @@ fn test_binary_group_consistency() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_chained_when_then_supertype() -> PolarsResult<()> {
+    let df = df![
+        "x" => [1, 2, 3, 4],
+        "small" => [10i32, 20, 30, 40],
+        "big" => [100i64, 200, 300, 400],
+    ]?;
+
+    let expr = when(col("x").eq(lit(1)))
+        .then(col("small"))
+        .when(col("x").eq(lit(2)))
+        .then(col("big"))
+        .when(col("x").eq(lit(3)))
+        .then(lit(0.5))
+        .otherwise(lit(NULL))
+        .alias("out");
+
+    let mut lf = df.lazy().select([expr]);
+    assert_eq!(lf.collect_schema()?.get("out"), Some(&DataType::Float64));
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("out")?.f64()?),
+        &[Some(10.0), Some(200.0), Some(0.5), None]
+    );
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "is_in")]
+fn test_list_namespace() -> PolarsResult<()> {
+    let df = df![
+        "tags" => ["x,y,x", "z", ""],
+        "n" => [1, 2, 3],
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_columns([
+            col("tags").str().split(lit(",")),
+            concat_list([col("n"), col("n") * lit(2), col("n")])?.alias("nums"),
+        ])
+        .select([
+            col("tags").list().len().alias("len"),
+            col("tags")
+                .list()
+                .contains(lit("x"), false)
+                .alias("contains"),
+            col("tags").list().join(lit("|"), false).alias("join"),
+            col("tags")
+                .list()
+                .unique_stable()
+                .list()
+                .len()
+                .alias("n_unique"),
+            col("nums").list().sum().alias("sum"),
+            col("nums").list().mean().alias("mean"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("len")?.idx()?),
+        &[Some(3), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("contains")?.bool()?),
+        &[Some(true), Some(false), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("join")?.str()?),
+        &[Some("x|y|x"), Some("z"), Some("")]
+    );
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.idx()?),
+        &[Some(2), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(4), Some(8), Some(12)]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(4.0 / 3.0), Some(8.0 / 3.0), Some(4.0)]
+    );
+    Ok(())
+}
@@ -4,8 +4,12 @@ mod expand;
 mod filter;
 #[cfg(feature = "is_in")]
 mod is_in;
+#[cfg(feature = "strings")]
+mod lists;
 mod literals;
 mod slice;
+#[cfg(all(feature = "strings", feature = "regex"))]
+mod strings;
 mod window;
 
 use super::*;
@@ -0,0 +1,112 @@
+use super::*;
+
+fn str_column(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<String>>> {
+    Ok(df
+        .column(name)?
+        .str()?
+        .into_iter()
+        .map(|v| v.map(str::to_string))
+        .collect())
+}
+
+fn list_column(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<Vec<String>>>> {
+    let ca = df.column(name)?.list()?;
+    ca.amortized_iter()
+        .map(|v| {
+            v.map(|s| {
+                Ok(s.as_ref()
+                    .str()?
+                    .into_no_null_iter()
+                    .map(str::to_string)
+                    .collect())
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+#[test]
+fn test_str_regex_and_split() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("a1-b22-c333"), Some("none"), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().extract_all(lit(r"\d+")).alias("digits"),
+            col("s")
+                .str()
+                .replace_all(lit(r"\d"), lit("#"), false)
+                .alias("masked"),
+            col("s").str().split(lit("-")).alias("split"),
+            col("s")
+                .str()
+                .split_inclusive(lit("-"))
+                .alias("split_inclusive"),
+        ])
+        .collect()?;
+
+    let owned = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        list_column(&out, "digits")?,
+        [Some(owned(&["1", "22", "333"])), Some(vec![]), None]
+    );
+    assert_eq!(
+        str_column(&out, "masked")?,
+        [
+            Some("a#-b##-c###".to_string()),
+            Some("none".to_string()),
+            None
+        ]
+    );
+    assert_eq!(
+        list_column(&out, "split")?,
+        [
+            Some(owned(&["a1", "b22", "c333"])),
+            Some(owned(&["none"])),
+            None
+        ]
+    );
+    assert_eq!(
+        list_column(&out, "split_inclusive")?,
+        [
+            Some(owned(&["a1-", "b22-", "c333"])),
+            Some(owned(&["none"])),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_pad")]
+fn test_str_pad() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("7"), Some("-12"), Some("12345"), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().zfill(lit(4)).alias("zfill"),
+            col("s").str().pad_start(lit(4), '*').alias("pad_start"),
+            col("s").str().pad_end(lit(4), '*').alias("pad_end"),
+        ])
+        .collect()?;
+
+    let owned = |v: [Option<&str>; 4]| v.map(|s| s.map(str::to_string)).to_vec();
+    assert_eq!(
+        str_column(&out, "zfill")?,
+        owned([Some("0007"), Some("-012"), Some("12345"), None])
+    );
+    assert_eq!(
+        str_column(&out, "pad_start")?,
+        owned([Some("***7"), Some("*-12"), Some("12345"), None])
+    );
+    assert_eq!(
+        str_column(&out, "pad_end")?,
+        owned([Some("7***"), Some("-12*"), Some("12345"), None])
+    );
+    Ok(())
+}
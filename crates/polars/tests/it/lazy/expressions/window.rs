@@ -184,6 +184,62 @@ fn test_literal_window_fn() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "cum_agg", feature = "rank"))]
+fn test_rank_and_cum_aggs_over_partitions() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 1, 2, 2, 1, 2],
+        "values" => [3, 1, 4, 1, 5, 9]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("values")
+                .rank(
+                    RankOptions {
+                        method: RankMethod::Dense,
+                        descending: false,
+                    },
+                    None,
+                )
+                .over([col("groups")])
+                .alias("rank"),
+            col("values")
+                .cum_sum(false)
+                .over([col("groups")])
+                .alias("cum_sum"),
+            col("values")
+                .cum_min(false)
+                .over([col("groups")])
+                .alias("cum_min"),
+            col("values")
+                .cum_max(true)
+                .over([col("groups")])
+                .alias("cum_max_rev"),
+        ])
+        .collect()?;
+
+    // Results are mapped back to the original row order.
+    assert_eq!(
+        Vec::from(out.column("rank")?.idx()?),
+        &[Some(2), Some(1), Some(2), Some(1), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cum_sum")?.i32()?),
+        &[Some(3), Some(4), Some(4), Some(5), Some(9), Some(14)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cum_min")?.i32()?),
+        &[Some(3), Some(1), Some(4), Some(1), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cum_max_rev")?.i32()?),
+        &[Some(5), Some(5), Some(9), Some(9), Some(5), Some(9)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_window_mapping() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -34,3 +34,46 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+fn test_horizontal_aggregations() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, Some(3)],
+        "b" => [Some(4), Some(2), None],
+        "c" => [Some(0), Some(5), Some(1)]
+    ]?;
+
+    let values =
+        |c: Option<Column>| -> PolarsResult<Vec<Option<i32>>> { Ok(Vec::from(c.unwrap().i32()?)) };
+    assert_eq!(
+        values(df.sum_horizontal(NullStrategy::Ignore)?)?,
+        &[Some(5), Some(7), Some(4)]
+    );
+    assert_eq!(
+        values(df.sum_horizontal(NullStrategy::Propagate)?)?,
+        &[Some(5), None, None]
+    );
+    assert_eq!(values(df.min_horizontal()?)?, &[Some(0), Some(2), Some(1)]);
+    assert_eq!(values(df.max_horizontal()?)?, &[Some(4), Some(5), Some(3)]);
+
+    // A fold with a null-propagating `+` agrees with `sum_horizontal` that propagates nulls.
+    let out = df
+        .lazy()
+        .select([
+            polars::lazy::dsl::sum_horizontal([col("*")], false)?.alias("sum"),
+            fold_exprs(
+                lit(0),
+                PlanCallback::new(|(a, b)| &a + &b),
+                [col("*")],
+                false,
+                Some(DataType::Int32.into()),
+            )
+            .alias("fold"),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.column("sum")?,
+        &out.column("fold")?.clone().with_name("sum".into())
+    );
+    Ok(())
+}
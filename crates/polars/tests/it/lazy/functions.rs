@@ -24,3 +24,30 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(all(feature = "concat_str", feature = "strings"))]
+fn test_concat_str() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("x"), None, Some("z")],
+        "b" => [1, 2, 3]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            concat_str([col("a"), lit("-"), col("b")], "", false).alias("propagate"),
+            concat_str([col("a"), col("b")], "_", true).alias("ignore"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("propagate")?.str()?),
+        &[Some("x-1"), None, Some("z-3")]
+    );
+    assert_eq!(
+        Vec::from(out.column("ignore")?.str()?),
+        &[Some("x_1"), Some("2"), Some("z_3")]
+    );
+    Ok(())
+}
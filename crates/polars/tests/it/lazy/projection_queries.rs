@@ -163,6 +163,33 @@ fn test_unnest_pushdown() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_unnest_schema() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 2],
+        "a" => [10, 20],
+        "b" => ["x", "y"],
+    ]?;
+    let df = df
+        .lazy()
+        .select([col("id"), as_struct(vec![col("a"), col("b")]).alias("s")])
+        .collect()?;
+
+    let mut lf = df.clone().lazy().unnest(by_name(["s"], true));
+    let schema = lf.collect_schema()?;
+    assert_eq!(schema.iter_names().collect::<Vec<_>>(), &["id", "a", "b"]);
+    assert_eq!(schema.get("b"), Some(&DataType::String));
+
+    let out = lf.collect()?;
+    assert!(out.equals(&df.unnest(["s"])?));
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(10), Some(20)]);
+
+    assert!(df.unnest(["id"]).is_err());
+    assert!(df.unnest(["missing"]).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_join_duplicate_7314() -> PolarsResult<()> {
     let df_a: DataFrame = df![
@@ -97,6 +97,44 @@ fn test_special_group_by_schemas() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "rolling_window_by", feature = "dtype-date"))]
+fn test_rolling_by_temporal_window() -> PolarsResult<()> {
+    let df = df![
+        "date" => [0i32, 1, 2, 5, 6],
+        "price" => [10.0, 20.0, 30.0, 40.0, 50.0],
+    ]?;
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse("3d"),
+        min_periods: 1,
+        closed_window: ClosedWindow::Right,
+        fn_params: None,
+    };
+
+    let out = df
+        .lazy()
+        .with_column(col("date").cast(DataType::Date))
+        .select([
+            col("price")
+                .rolling_mean_by(col("date"), options.clone())
+                .alias("mean"),
+            col("price")
+                .rolling_sum_by(col("date"), options)
+                .alias("sum"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(10.0), Some(15.0), Some(20.0), Some(40.0), Some(45.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[Some(10.0), Some(30.0), Some(60.0), Some(40.0), Some(90.0)]
+    );
+    Ok(())
+}
+
 #[test]
 fn max_on_empty_df_3027() -> PolarsResult<()> {
     let df = df! {
@@ -1 +1,5 @@
 mod date_range;
+#[cfg(feature = "timezones")]
+mod time_zones;
+#[cfg(all(feature = "dtype-datetime", feature = "offset_by"))]
+mod truncate_round;
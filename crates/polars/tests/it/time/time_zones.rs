@@ -0,0 +1,66 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+
+fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+}
+
+fn ms(v: NaiveDateTime) -> i64 {
+    v.and_utc().timestamp_millis()
+}
+
+#[test]
+fn test_replace_and_convert_time_zone_across_dst() -> PolarsResult<()> {
+    let amsterdam = TimeZone::opt_try_new(Some("Europe/Amsterdam"))?;
+    let new_york = TimeZone::opt_try_new(Some("America/New_York"))?;
+
+    let df = df![
+        "t" => [
+            // Winter time, UTC+1.
+            ms(dt(2024, 3, 31, 1, 30)),
+            // Skipped by the spring DST transition.
+            ms(dt(2024, 3, 31, 2, 30)),
+            // Occurs twice at the autumn DST transition.
+            ms(dt(2024, 10, 27, 2, 30)),
+        ],
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_column(col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+        .select([col("t")
+            .dt()
+            .replace_time_zone(amsterdam.clone(), lit("earliest"), NonExistent::Null)
+            .alias("local")])
+        .with_column(
+            col("local")
+                .dt()
+                .convert_time_zone(new_york.clone().unwrap())
+                .alias("new_york"),
+        )
+        .collect()?;
+
+    let local = out.column("local")?;
+    assert_eq!(
+        local.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, amsterdam)
+    );
+    let expected = [
+        Some(ms(dt(2024, 3, 31, 0, 30))),
+        None,
+        Some(ms(dt(2024, 10, 27, 0, 30))),
+    ];
+    assert_eq!(Vec::from(local.datetime()?.physical()), expected);
+
+    // Converting only changes how the instants are displayed.
+    let new_york_col = out.column("new_york")?;
+    assert_eq!(
+        new_york_col.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, new_york)
+    );
+    assert_eq!(Vec::from(new_york_col.datetime()?.physical()), expected);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+
+fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+}
+
+fn ms(values: &[NaiveDateTime]) -> Vec<Option<i64>> {
+    values
+        .iter()
+        .map(|v| Some(v.and_utc().timestamp_millis()))
+        .collect()
+}
+
+#[test]
+fn test_truncate_round_offset_by_calendar_durations() -> PolarsResult<()> {
+    let values = [dt(2024, 1, 31, 10, 7), dt(2024, 2, 29, 23, 53)];
+    let df = df![
+        "t" => ms(&values),
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_column(col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+        .select([
+            col("t").dt().truncate(lit("1mo")).alias("truncate"),
+            col("t").dt().round(lit("15m")).alias("round"),
+            col("t").dt().offset_by(lit("1y1mo")).alias("offset_by"),
+        ])
+        .collect()?;
+
+    let get = |name: &str| -> PolarsResult<Vec<Option<i64>>> {
+        Ok(Vec::from(out.column(name)?.datetime()?.physical()))
+    };
+    assert_eq!(
+        get("truncate")?,
+        ms(&[dt(2024, 1, 1, 0, 0), dt(2024, 2, 1, 0, 0)])
+    );
+    assert_eq!(
+        get("round")?,
+        ms(&[dt(2024, 1, 31, 10, 0), dt(2024, 3, 1, 0, 0)])
+    );
+    // Month arithmetic clamps to the last day of the target month.
+    assert_eq!(
+        get("offset_by")?,
+        ms(&[dt(2025, 2, 28, 10, 7), dt(2025, 3, 29, 23, 53)])
+    );
+    Ok(())
+}
@@ -56,7 +56,8 @@ def read_ipc(
     Read into a DataFrame from Arrow IPC (Feather v2) file.
 
     See "File or Random Access format" on https://arrow.apache.org/docs/python/ipc.html.
-    Arrow IPC files are also known as Feather (v2) files.
+    Arrow IPC files are also known as Feather (v2) files. Legacy Feather v1 files are
+    read as well, but can't be scanned with `scan_ipc`.
 
     .. versionchanged:: 0.20.4
         * The `row_count_name` parameter was renamed `row_index_name`.
@@ -9,9 +9,11 @@ import polars._reexport as pl
 from polars._utils.unstable import unstable
 
 if TYPE_CHECKING:
-    from collections.abc import Iterator
+    from collections.abc import Iterable, Iterator
     from typing import Callable
 
+    import pyarrow as pa
+
     from polars import DataFrame, Expr, LazyFrame
     from polars._typing import SchemaDict
 
@@ -96,6 +98,95 @@ def register_io_source(
     )
 
 
+@unstable()
+def scan_generator(
+    generator: Callable[[], Iterable[DataFrame | pa.RecordBatch | pa.Table]],
+    *,
+    schema: Callable[[], SchemaDict] | SchemaDict,
+    validate_schema: bool = False,
+) -> LazyFrame:
+    """
+    Use a Python generator as a lazy source.
+
+    The generator function is called every time the `LazyFrame` is collected and
+    may yield Polars `DataFrame`s, PyArrow `RecordBatch`es or PyArrow `Table`s.
+    Projections, predicates and row limits are applied to the yielded batches by
+    Polars, so the generator does not have to handle them itself.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    generator
+        Function that takes no arguments and returns an iterable of batches.
+    schema
+        Schema or function that when called produces the schema of the batches
+        yielded by `generator`.
+    validate_schema
+        Whether the engine should validate if the batches generated match
+        the given schema. It's an implementation error if this isn't
+        the case and can lead to bugs that are hard to solve.
+
+    Returns
+    -------
+    LazyFrame
+
+    Examples
+    --------
+    >>> from polars.io.plugins import scan_generator
+    >>> def batches():
+    ...     for i in range(3):
+    ...         yield pl.DataFrame({"a": [i, i + 10]})
+    >>> lf = scan_generator(batches, schema={"a": pl.Int64})
+    >>> lf.filter(pl.col("a") > 1).collect()
+    shape: (4, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 10  │
+    │ 11  │
+    │ 2   │
+    │ 12  │
+    └─────┘
+    """
+    from polars.convert import from_arrow
+
+    def source(
+        with_columns: list[str] | None,
+        predicate: Expr | None,
+        n_rows: int | None,
+        batch_size: int | None,
+    ) -> Iterator[DataFrame]:
+        remaining = n_rows
+        for batch in generator():
+            if remaining is not None and remaining <= 0:
+                return
+
+            df = batch if isinstance(batch, pl.DataFrame) else from_arrow(batch)
+            assert isinstance(df, pl.DataFrame)
+
+            lf = df.lazy()
+            if with_columns is not None:
+                lf = lf.select(with_columns)
+            if predicate is not None:
+                lf = lf.filter(predicate)
+            if remaining is not None:
+                lf = lf.limit(remaining)
+            df = lf.collect()
+
+            if remaining is not None:
+                remaining -= df.height
+            yield df
+
+    return register_io_source(
+        io_source=source, schema=schema, validate_schema=validate_schema
+    )
+
+
 @unstable()
 def _defer(
     function: Callable[[], DataFrame],
@@ -1875,7 +1875,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         containing the materialized DataFrame and a DataFrame that
         contains profiling information of each node that is executed.
 
-        The units of the timings are microseconds.
+        The units of the timings are microseconds. The number of rows that
+        each node produced is reported in the `rows_emitted` column, if known.
 
         Parameters
         ----------
@@ -1989,16 +1990,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
          │ b   ┆ 11  ┆ 10  │
          │ c   ┆ 6   ┆ 1   │
          └─────┴─────┴─────┘,
-         shape: (3, 3)
-         ┌─────────────────────────┬───────┬──────┐
-         │ node                    ┆ start ┆ end  │
-         │ ---                     ┆ ---   ┆ ---  │
-         │ str                     ┆ u64   ┆ u64  │
-         ╞═════════════════════════╪═══════╪══════╡
-         │ optimization            ┆ 0     ┆ 5    │
-         │ group_by_partitioned(a) ┆ 5     ┆ 470  │
-         │ sort(a)                 ┆ 475   ┆ 1964 │
-         └─────────────────────────┴───────┴──────┘)
+         shape: (3, 4)
+         ┌─────────────────────────┬───────┬──────┬──────────────┐
+         │ node                    ┆ start ┆ end  ┆ rows_emitted │
+         │ ---                     ┆ ---   ┆ ---  ┆ ---          │
+         │ str                     ┆ u64   ┆ u64  ┆ u64          │
+         ╞═════════════════════════╪═══════╪══════╪══════════════╡
+         │ optimization            ┆ 0     ┆ 5    ┆ null         │
+         │ group_by_partitioned(a) ┆ 5     ┆ 470  ┆ 3            │
+         │ sort(a)                 ┆ 475   ┆ 1964 ┆ 3            │
+         └─────────────────────────┴───────┴──────┴──────────────┘)
         """
         for k in _kwargs:
             if k not in (  # except "private" kwargs
@@ -2954,8 +2954,10 @@ class Series:
 
         Notes
         -----
-        Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
-        Int64 before summing to prevent overflow issues.
+        Dtypes in {Int8, UInt8, Int16, UInt16, Int32, UInt32} are cast to
+        Int64 before multiplying to prevent overflow issues. Dtypes in
+        {Int64, UInt64, Int128} are not promoted, products that overflow them wrap
+        around.
 
         Examples
         --------
@@ -163,7 +163,10 @@ def test_upsample_time_zones(
         ),
     ],
 )
-@pytest.mark.parametrize("dtype", [pl.Int32, pl.Int64, pl.UInt32, pl.UInt64])
+@pytest.mark.parametrize(
+    "dtype",
+    [pl.Int8, pl.Int16, pl.Int32, pl.Int64, pl.UInt8, pl.UInt16, pl.UInt32, pl.UInt64],
+)
 def test_upsample_index(
     every: str,
     fill: FillNullStrategy | None,
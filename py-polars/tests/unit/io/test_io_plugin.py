@@ -10,7 +10,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.io.plugins import register_io_source
+from polars.io.plugins import register_io_source, scan_generator
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -45,6 +45,27 @@ def test_io_plugin_predicate_no_serialization_21130() -> None:
     ).collect().to_dict(as_series=False) == {"json_val": ['{"a":"1"}']}
 
 
+def test_scan_generator() -> None:
+    pa = pytest.importorskip("pyarrow")
+
+    def batches() -> Iterator[pl.DataFrame | pa.RecordBatch | pa.Table]:
+        yield pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+        yield pa.record_batch({"a": [3, 4], "b": ["z", "x"]})
+        yield pa.table({"a": [5, 6], "b": ["y", "z"]})
+
+    lf = scan_generator(batches, schema={"a": pl.Int64, "b": pl.String})
+    assert lf.collect_schema() == pl.Schema({"a": pl.Int64, "b": pl.String})
+    assert_frame_equal(
+        lf.collect(),
+        pl.DataFrame({"a": [1, 2, 3, 4, 5, 6], "b": ["x", "y", "z", "x", "y", "z"]}),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("b") == "x").select("a").collect(),
+        pl.DataFrame({"a": [1, 4]}),
+    )
+    assert_frame_equal(lf.head(3).collect(), lf.collect().head(3))
+
+
 def test_defer_validate_true() -> None:
     lf = pl.defer(
         lambda: pl.DataFrame({"a": np.ones(3)}),
@@ -249,6 +249,31 @@ def test_from_float16() -> None:
     assert pl.read_ipc(f, use_pyarrow=False).dtypes == [pl.Float32]
 
 
+@pytest.mark.write_disk
+def test_read_feather_v1(tmp_path: Path) -> None:
+    pandas_df = pd.DataFrame(
+        {
+            "a": [1.0, None, 3.0],
+            "b": ["x", "yz", None],
+            "c": pd.Categorical(["lo", "hi", "lo"]),
+        }
+    )
+    file_path = tmp_path / "v1.feather"
+    pandas_df.to_feather(file_path, version=1)
+
+    expected = pl.DataFrame(
+        {"a": [1.0, None, 3.0], "b": ["x", "yz", None], "c": ["lo", "hi", "lo"]},
+        schema_overrides={"c": pl.Categorical},
+    )
+    assert_frame_equal(pl.read_ipc(file_path), expected, categorical_as_str=True)
+    assert_frame_equal(
+        pl.read_ipc(file_path, columns=["b"], n_rows=2), expected.select("b").head(2)
+    )
+
+    with pytest.raises(pl.exceptions.ComputeError, match="Feather V1"):
+        pl.scan_ipc(file_path).collect()
+
+
 @pytest.mark.write_disk
 def test_binview_ipc_mmap(tmp_path: Path) -> None:
     df = pl.DataFrame({"foo": ["aa" * 10, "bb", None, "small", "big" * 20]})
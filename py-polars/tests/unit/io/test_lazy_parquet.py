@@ -69,6 +69,29 @@ def test_row_index_len_16543(foods_parquet_path: Path) -> None:
     assert q.select(pl.all()).select(pl.len()).collect().item() == 27
 
 
+def test_scan_parquet_row_index_projection_low_memory(
+    foods_parquet_path: Path, io_files_path: Path
+) -> None:
+    kwargs: dict[str, Any] = {
+        "n_rows": 10,
+        "row_index_name": "idx",
+        "row_index_offset": 5,
+        "low_memory": True,
+    }
+    expected = (
+        pl.scan_csv(io_files_path / "foods1.csv", **kwargs)
+        .select("idx", "calories")
+        .collect()
+    )
+    result = (
+        pl.scan_parquet(foods_parquet_path, **kwargs)
+        .select("idx", "calories")
+        .collect()
+    )
+    assert_frame_equal(result, expected)
+    assert result["idx"].to_list() == list(range(5, 15))
+
+
 @pytest.mark.write_disk
 def test_categorical_parquet_statistics(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -31,6 +31,7 @@ if TYPE_CHECKING:
     from pathlib import Path
 
     from polars._typing import (
+        EngineType,
         ParallelStrategy,
         ParquetCompression,
         ParquetMetadata,
@@ -2180,6 +2181,19 @@ def test_conserve_sortedness(
         )
 
 
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sink_parquet_sorting_columns(engine: EngineType) -> None:
+    f = io.BytesIO()
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [3, 1, 2]}).with_columns(
+        pl.col("a").set_sorted()
+    )
+    df.lazy().sink_parquet(f, engine=engine)
+
+    f.seek(0)
+    row_group = pq.ParquetFile(f).metadata.row_group(0)
+    assert row_group.sorting_columns == (pq.SortingColumn(0),)
+
+
 @pytest.mark.parametrize("use_dictionary", [True, False])
 @pytest.mark.parametrize(
     "values",
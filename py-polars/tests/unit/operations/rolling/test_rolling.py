@@ -905,6 +905,18 @@ def test_rolling_ints(dtype: PolarsDataType) -> None:
     assert s.rolling_skew(4).null_count() == 3
 
 
+@pytest.mark.parametrize(
+    "method",
+    ["rolling_sum", "rolling_mean", "rolling_min", "rolling_max", "rolling_std"],
+)
+def test_rolling_weights_length_mismatch(method: str) -> None:
+    s = pl.Series([1.0, 2.0, 3.0])
+    with pytest.raises(
+        InvalidOperationError, match=r"length of `weights` \(3\) should be equal"
+    ):
+        getattr(s, method)(2, weights=[0.1, 0.2, 0.3])
+
+
 def test_rolling_floats() -> None:
     # 3099
     # test if we maintain proper dtype
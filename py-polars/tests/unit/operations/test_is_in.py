@@ -471,6 +471,21 @@ def test_enum_is_in_series_non_existent(nulls_equal: bool) -> None:
     assert_series_equal(out, expected)
 
 
+@pytest.mark.parametrize("dtype", [pl.Categorical, pl.Enum(["a", "b", "c"])])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+@pytest.mark.parametrize("other", [["b", "c"], ["b", None], []])
+def test_cat_is_in_long_series(
+    dtype: pl.DataType, nulls_equal: bool, other: list[str | None]
+) -> None:
+    # Long enough for the membership to be looked up by category.
+    s = pl.Series(["a", "b", "c", None] * 1000)
+    expected = s.is_in(pl.Series(other, dtype=pl.String), nulls_equal=nulls_equal)
+    out = s.cast(dtype).is_in(
+        pl.Series(other, dtype=pl.String).cast(dtype), nulls_equal=nulls_equal
+    )
+    assert_series_equal(out, expected)
+
+
 @pytest.mark.parametrize("dtype", [pl.Categorical, pl.Enum(["a", "b", "c"])])
 @pytest.mark.parametrize("nulls_equal", [False, True])
 def test_cat_is_in_with_lit_str(dtype: pl.DataType, nulls_equal: bool) -> None:
@@ -41,3 +41,17 @@ def test_unique_counts_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([3], dtype=pl.UInt32)
     assert_series_equal(s.unique_counts(), expected)
+
+
+def test_unique_counts_nested_and_boolean() -> None:
+    s = pl.Series("b", [True, None, False, True, None])
+    expected = pl.Series("b", [2, 2, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
+
+    s = pl.Series("s", [{"a": 1}, {"a": 2}, {"a": 1}])
+    expected = pl.Series("s", [2, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
+
+    s = pl.Series("l", [[1, 2], [3], [1, 2], [1, 2]])
+    expected = pl.Series("l", [3, 1], dtype=pl.UInt32)
+    assert_series_equal(s.unique_counts(), expected)
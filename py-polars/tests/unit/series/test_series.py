@@ -57,6 +57,17 @@ def test_cum_agg_with_nulls() -> None:
     assert_series_equal(s.cum_prod(), pl.Series("a", [None, 2, None, 14, 112, None]))
 
 
+def test_cum_prod_overflow() -> None:
+    s = pl.Series("a", [2**31, 2**31, 2**31], dtype=pl.UInt32)
+    expected = pl.Series("a", [2**31, 2**62, 0], dtype=pl.Int64)
+    assert_series_equal(s.cum_prod(), expected)
+    assert_series_equal(s.cum_prod(reverse=True), expected.reverse())
+
+    # Int64 is not promoted and wraps around.
+    s = pl.Series("a", [2**62, 4], dtype=pl.Int64)
+    assert_series_equal(s.cum_prod(), pl.Series("a", [2**62, 0], dtype=pl.Int64))
+
+
 def test_cum_agg_with_infs() -> None:
     # confirm that inf values are handled correctly
     s = pl.Series([float("inf"), 0.0, 1.0])
@@ -7,7 +7,7 @@ import sys
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, SchemaError
+from polars.exceptions import ComputeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 CATS = [
@@ -86,8 +86,13 @@ def test_concat_cat_mismatch() -> None:
 
     for left in [df1, df2]:
         for right in [df3, df4]:
-            with pytest.raises(SchemaError):
-                pl.concat([left, right])
+            expected = pl.DataFrame(
+                {"x": left["x"].to_list() + right["x"].to_list()}, schema={"x": dt1}
+            )
+            assert_frame_equal(pl.concat([left, right]), expected)
+            assert_frame_equal(
+                pl.concat([left.lazy(), right.lazy()]).collect(), expected
+            )
 
     for li in range(len(CATS)):
         for ri in range(len(CATS)):
@@ -96,8 +101,38 @@ def test_concat_cat_mismatch() -> None:
 
             ldf = pl.DataFrame({"x": []}, schema={"x": pl.Categorical(CATS[li])})
             rdf = pl.DataFrame({"x": []}, schema={"x": pl.Categorical(CATS[ri])})
-            with pytest.raises(SchemaError):
-                pl.concat([ldf, rdf])
+            assert_frame_equal(pl.concat([ldf, rdf]), ldf)
+            assert_frame_equal(pl.concat([ldf.lazy(), rdf.lazy()]).collect(), ldf)
+
+
+def test_concat_nested_cat_remap() -> None:
+    dt1 = pl.Categorical(pl.Categories.random())
+    dt2 = pl.Categorical(pl.Categories.random())
+    schema1 = {"l": pl.List(dt1), "s": pl.Struct({"c": dt1})}
+    schema2 = {"l": pl.List(dt2), "s": pl.Struct({"c": dt2})}
+    df1 = pl.DataFrame({"l": [["a", "b"]], "s": [{"c": "a"}]}, schema=schema1)
+    df2 = pl.DataFrame({"l": [["c"]], "s": [{"c": "d"}]}, schema=schema2)
+    expected = pl.DataFrame(
+        {"l": [["a", "b"], ["c"]], "s": [{"c": "a"}, {"c": "d"}]}, schema=schema1
+    )
+
+    assert_frame_equal(df1.vstack(df2), expected)
+    assert_frame_equal(pl.concat([df1.lazy(), df2.lazy()]).collect(), expected)
+
+
+def test_vstack_cat_remap() -> None:
+    dt1 = pl.Categorical(pl.Categories.random())
+    dt2 = pl.Categorical(pl.Categories.random(physical=pl.UInt8))
+    df1 = pl.DataFrame({"x": ["a", "b", None]}, schema={"x": dt1})
+    df2 = pl.DataFrame({"x": ["c", None, "a"]}, schema={"x": dt2})
+    expected = pl.DataFrame({"x": ["a", "b", None, "c", None, "a"]}, schema={"x": dt1})
+
+    # The categories of the right frame are remapped to the ones of the left frame.
+    assert_frame_equal(df1.vstack(df2), expected)
+    assert_frame_equal(pl.concat([df1, df2]), expected)
+    assert_series_equal(df1["x"].append(df2["x"]), expected["x"])
+    assert_frame_equal(df1.clone().extend(df2), expected)
+    assert set(df1["x"].cat.get_categories()) == {"a", "b", "c"}
 
 
 def test_cat_overflow() -> None: